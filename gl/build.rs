@@ -14,7 +14,7 @@
 
 extern crate gl_generator;
 
-use gl_generator::{Api, Fallbacks, GlobalGenerator, Profile, Registry};
+use gl_generator::{Api, EnumNamesGenerator, Fallbacks, GlobalGenerator, Profile, Registry};
 use std::env;
 use std::fs::File;
 use std::path::Path;
@@ -23,7 +23,11 @@ fn main() {
     let dest = env::var("OUT_DIR").unwrap();
     let mut file = File::create(Path::new(&dest).join("bindings.rs")).unwrap();
 
-    Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, [])
-        .write_bindings(GlobalGenerator, &mut file)
-        .unwrap();
+    let registry = Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, []);
+    registry.write_bindings(GlobalGenerator, &mut file).unwrap();
+    if env::var_os("CARGO_FEATURE_ENUM_NAMES").is_some() {
+        registry
+            .write_bindings(EnumNamesGenerator, &mut file)
+            .unwrap();
+    }
 }
@@ -40,10 +40,20 @@
 //! ~~~
 //!
 //! Calling a function that has not been loaded will result in a failure like:
-//! `panic!("gl::Viewport was not loaded")`, which avoids a segfault. This feature
+//! ``panic!("gl function `glViewport` was not loaded")``, which avoids a segfault.
+//! The message also lists the fallback symbols that were tried. This feature
 //! does not cause any run time overhead because the failing functions are
 //! assigned only when `load_with` is called.
 //!
+//! The panic can be replaced with an abort or a custom handler:
+//!
+//! ~~~no_run
+//! # extern crate gl;
+//! # fn main() {
+//! gl::set_missing_fn_policy(gl::MissingFnPolicy::Abort);
+//! # }
+//! ~~~
+//!
 //! All OpenGL function calls are `unsafe`.
 //!
 //! ~~~no_run
@@ -69,15 +79,17 @@
 //! }
 //! ~~~
 //!
+//! With the `enum_names` feature, the name of an enum value can be looked up at
+//! run time, for example to print the value returned by `glGetError`:
+//!
+//! ~~~
+//! # #[cfg(feature = "enum_names")]
+//! assert_eq!(gl::enum_name(gl::INVALID_OPERATION), Some("INVALID_OPERATION"));
+//! ~~~
+//!
 
 #![crate_name = "gl"]
 #![crate_type = "lib"]
-#![allow(
-    clippy::missing_safety_doc,
-    clippy::missing_transmute_annotations,
-    clippy::too_many_arguments,
-    clippy::unused_unit
-)]
 
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
@@ -0,0 +1,536 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::{Cmd, Registry};
+use std::io;
+
+use super::trace_gen::{gen_byte_len, param_kind, ParamKind};
+
+/// Generates the struct of `StructGenerator`, plus a `Recorder` that writes every call into a
+/// trace in the format of [apitrace](https://apitrace.github.io/).
+#[allow(missing_copy_implementations)]
+pub struct ApitraceGenerator;
+
+impl super::Generator for ApitraceGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        super::struct_gen::write_fnptr_struct_def(dest)?;
+        super::gen_missing_fns(registry, dest)?;
+        super::gen_load_report(registry, dest)?;
+        super::struct_gen::write_struct(&ctx, dest)?;
+        super::struct_gen::write_impl(&ctx, dest)?;
+        write_trace_writer(dest)?;
+        write_recorder(registry, dest)?;
+        Ok(())
+    }
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::cell::RefCell",
+    "std::fmt",
+    "std::io",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::process",
+    "std::slice",
+    "std::sync::RwLock",
+];
+
+/// Scalar types that are written as arrays of values when a pointer to them has a known length.
+/// Pointers to other types are written as blobs.
+const ARRAY_ELEM_TYPES: &[&str] = &[
+    "GLenum",
+    "GLboolean",
+    "GLbitfield",
+    "GLbyte",
+    "GLubyte",
+    "GLshort",
+    "GLushort",
+    "GLint",
+    "GLuint",
+    "GLsizei",
+    "GLfixed",
+    "GLfloat",
+    "GLclampf",
+    "GLdouble",
+    "GLclampd",
+    "GLint64",
+    "GLuint64",
+    "GLint64EXT",
+    "GLuint64EXT",
+    "GLintptr",
+    "GLsizeiptr",
+    "GLhalf",
+    "GLhalfARB",
+    "GLhalfNV",
+    "EGLint",
+    "EGLBoolean",
+    "EGLenum",
+    "EGLAttrib",
+    "EGLAttribKHR",
+];
+
+/// Returns the statement that writes the data that a pointer of known length points to.
+fn gen_write_data(ident: &str, elem_ty: &str, len: &str) -> String {
+    let name = elem_ty.trim_start_matches("types::");
+    if elem_ty.contains("GLchar") || elem_ty.ends_with("c_char") {
+        format!("trace.string({ident}.cast::<u8>(), {len});")
+    } else if ARRAY_ELEM_TYPES.contains(&name) {
+        format!("trace.array({ident}.cast::<{elem_ty}>(), {len});")
+    } else {
+        format!(
+            "trace.blob({ident}.cast::<u8>(), {});",
+            gen_byte_len(elem_ty, len)
+        )
+    }
+}
+
+/// Returns the statement that writes the value returned by `cmd`, stored in `r`.
+fn gen_write_return(cmd: &Cmd) -> String {
+    let ty = &cmd.proto.ty;
+    if ty == "*const types::GLubyte" || (ty.starts_with("*const ") && ty.ends_with("c_char")) {
+        "trace.c_str(r.cast::<u8>());".to_string()
+    } else if ty.contains("PROC") {
+        "trace.opaque(__gl_imports::mem::transmute_copy::<_, usize>(&r));".to_string()
+    } else {
+        "trace.value(&r);".to_string()
+    }
+}
+
+/// Creates the types that write the apitrace format.
+fn write_trace_writer<W>(dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// The version of the apitrace format written by `Recorder`.
+        pub const APITRACE_VERSION: u64 = 5;
+
+        /// The maximum amount of uncompressed data in each snappy chunk of the trace.
+        const APITRACE_CHUNK_SIZE: usize = 1024 * 1024;
+
+        const EVENT_ENTER: u8 = 0;
+        const EVENT_LEAVE: u8 = 1;
+
+        const CALL_END: u8 = 0;
+        const CALL_ARG: u8 = 1;
+        const CALL_RET: u8 = 2;
+
+        const TYPE_NULL: u8 = 0;
+        const TYPE_SINT: u8 = 3;
+        const TYPE_UINT: u8 = 4;
+        const TYPE_FLOAT: u8 = 5;
+        const TYPE_DOUBLE: u8 = 6;
+        const TYPE_STRING: u8 = 7;
+        const TYPE_BLOB: u8 = 8;
+        const TYPE_ARRAY: u8 = 11;
+        const TYPE_OPAQUE: u8 = 13;
+
+        struct ApitraceWriter<W> {{
+            out: W,
+            /// The uncompressed data that hasn't been written to `out` yet.
+            chunk: Vec<u8>,
+            defined: Vec<bool>,
+            call_no: u64,
+            error: Option<__gl_imports::io::Error>,
+        }}
+
+        #[allow(dead_code)]
+        impl<W: __gl_imports::io::Write> ApitraceWriter<W> {{
+            fn byte(&mut self, byte: u8) {{
+                self.chunk.push(byte);
+            }}
+
+            fn varuint(&mut self, mut value: u64) {{
+                loop {{
+                    let byte = (value & 0x7f) as u8;
+                    value >>= 7;
+                    if value == 0 {{
+                        self.byte(byte);
+                        break;
+                    }}
+                    self.byte(byte | 0x80);
+                }}
+            }}
+
+            fn bytes(&mut self, bytes: &[u8]) {{
+                self.varuint(bytes.len() as u64);
+                self.chunk.extend_from_slice(bytes);
+            }}
+
+            fn value<T: ApitraceValue>(&mut self, value: &T) {{
+                value.write_to(self);
+            }}
+
+            fn uint(&mut self, value: u64) {{
+                self.byte(TYPE_UINT);
+                self.varuint(value);
+            }}
+
+            fn sint(&mut self, value: i64) {{
+                if value < 0 {{
+                    self.byte(TYPE_SINT);
+                    self.varuint(value.unsigned_abs());
+                }} else {{
+                    self.uint(value as u64);
+                }}
+            }}
+
+            fn float(&mut self, value: f32) {{
+                self.byte(TYPE_FLOAT);
+                self.chunk.extend_from_slice(&value.to_le_bytes());
+            }}
+
+            fn double(&mut self, value: f64) {{
+                self.byte(TYPE_DOUBLE);
+                self.chunk.extend_from_slice(&value.to_le_bytes());
+            }}
+
+            fn opaque(&mut self, address: usize) {{
+                if address == 0 {{
+                    self.byte(TYPE_NULL);
+                }} else {{
+                    self.byte(TYPE_OPAQUE);
+                    self.varuint(address as u64);
+                }}
+            }}
+
+            unsafe fn string(&mut self, ptr: *const u8, len: usize) {{
+                if ptr.is_null() {{
+                    self.byte(TYPE_NULL);
+                }} else {{
+                    self.byte(TYPE_STRING);
+                    self.bytes(__gl_imports::slice::from_raw_parts(ptr, len));
+                }}
+            }}
+
+            unsafe fn c_str(&mut self, ptr: *const u8) {{
+                let mut len = 0;
+                if !ptr.is_null() {{
+                    while *ptr.add(len) != 0 {{
+                        len += 1;
+                    }}
+                }}
+                self.string(ptr, len);
+            }}
+
+            unsafe fn blob(&mut self, ptr: *const u8, len: usize) {{
+                if ptr.is_null() {{
+                    self.byte(TYPE_NULL);
+                }} else {{
+                    self.byte(TYPE_BLOB);
+                    self.bytes(__gl_imports::slice::from_raw_parts(ptr, len));
+                }}
+            }}
+
+            unsafe fn array<T: ApitraceValue>(&mut self, ptr: *const T, len: usize) {{
+                if ptr.is_null() {{
+                    self.byte(TYPE_NULL);
+                }} else {{
+                    self.byte(TYPE_ARRAY);
+                    self.varuint(len as u64);
+                    for value in __gl_imports::slice::from_raw_parts(ptr, len) {{
+                        value.write_to(self);
+                    }}
+                }}
+            }}
+
+            /// Starts the enter event of a call, and returns its number. The signature of the
+            /// command is written the first time it is called.
+            fn begin_enter(&mut self, id: usize, symbol: &str, arg_names: &[&str]) -> u64 {{
+                self.byte(EVENT_ENTER);
+                self.varuint(0);
+                self.varuint(id as u64);
+                if !self.defined[id] {{
+                    self.defined[id] = true;
+                    self.bytes(symbol.as_bytes());
+                    self.varuint(arg_names.len() as u64);
+                    for name in arg_names {{
+                        self.bytes(name.as_bytes());
+                    }}
+                }}
+                self.call_no += 1;
+                self.call_no - 1
+            }}
+
+            fn begin_leave(&mut self, call_no: u64) {{
+                self.byte(EVENT_LEAVE);
+                self.varuint(call_no);
+            }}
+
+            fn arg(&mut self, index: u64) {{
+                self.byte(CALL_ARG);
+                self.varuint(index);
+            }}
+
+            fn ret(&mut self) {{
+                self.byte(CALL_RET);
+            }}
+
+            fn end(&mut self) {{
+                self.byte(CALL_END);
+            }}
+
+            /// Ends a call, writing the data to the output once a chunk is full.
+            fn end_call(&mut self) {{
+                if self.chunk.len() >= APITRACE_CHUNK_SIZE {{
+                    self.flush_chunks();
+                }}
+            }}
+
+            /// Compresses and writes the pending data, as snappy chunks made of literals only.
+            fn flush_chunks(&mut self) {{
+                let chunk = __gl_imports::mem::take(&mut self.chunk);
+                for data in chunk.chunks(APITRACE_CHUNK_SIZE) {{
+                    let mut compressed = Vec::with_capacity(data.len() + 10);
+                    let mut len = data.len();
+                    while len >= 0x80 {{
+                        compressed.push((len & 0x7f) as u8 | 0x80);
+                        len >>= 7;
+                    }}
+                    compressed.push(len as u8);
+                    let tag = data.len() - 1;
+                    match tag {{
+                        0..=59 => compressed.push((tag as u8) << 2),
+                        60..=0xff => compressed.extend_from_slice(&[60 << 2, tag as u8]),
+                        0x100..=0xffff => {{
+                            compressed.push(61 << 2);
+                            compressed.extend_from_slice(&(tag as u16).to_le_bytes());
+                        }},
+                        _ => {{
+                            compressed.push(62 << 2);
+                            compressed.extend_from_slice(&(tag as u32).to_le_bytes()[..3]);
+                        }},
+                    }}
+                    compressed.extend_from_slice(data);
+                    self.write_out(&(compressed.len() as u32).to_le_bytes());
+                    self.write_out(&compressed);
+                }}
+            }}
+
+            fn write_out(&mut self, bytes: &[u8]) {{
+                if self.error.is_none() {{
+                    if let Err(error) = self.out.write_all(bytes) {{
+                        self.error = Some(error);
+                    }}
+                }}
+            }}
+        }}
+
+        /// A value that can be written in an apitrace trace.
+        trait ApitraceValue {{
+            fn write_to<W: __gl_imports::io::Write>(&self, trace: &mut ApitraceWriter<W>);
+        }}
+
+        impl<T> ApitraceValue for *const T {{
+            fn write_to<W: __gl_imports::io::Write>(&self, trace: &mut ApitraceWriter<W>) {{
+                trace.opaque(*self as usize);
+            }}
+        }}
+
+        impl<T> ApitraceValue for *mut T {{
+            fn write_to<W: __gl_imports::io::Write>(&self, trace: &mut ApitraceWriter<W>) {{
+                trace.opaque(*self as usize);
+            }}
+        }}
+
+        impl ApitraceValue for f32 {{
+            fn write_to<W: __gl_imports::io::Write>(&self, trace: &mut ApitraceWriter<W>) {{
+                trace.float(*self);
+            }}
+        }}
+
+        impl ApitraceValue for f64 {{
+            fn write_to<W: __gl_imports::io::Write>(&self, trace: &mut ApitraceWriter<W>) {{
+                trace.double(*self);
+            }}
+        }}
+    "#
+    )?;
+
+    for (ty, method, cast) in &[
+        ("u8", "uint", "u64"),
+        ("u16", "uint", "u64"),
+        ("u32", "uint", "u64"),
+        ("u64", "uint", ""),
+        ("usize", "uint", "u64"),
+        ("i8", "sint", "i64"),
+        ("i16", "sint", "i64"),
+        ("i32", "sint", "i64"),
+        ("i64", "sint", ""),
+        ("isize", "sint", "i64"),
+    ] {
+        let value = if cast.is_empty() {
+            "*self".to_string()
+        } else {
+            format!("*self as {cast}")
+        };
+        writeln!(
+            dest,
+            "impl ApitraceValue for {ty} {{
+                fn write_to<W: __gl_imports::io::Write>(&self, trace: &mut ApitraceWriter<W>) {{
+                    trace.{method}({value});
+                }}
+            }}"
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Creates the `Recorder` structure, with one method per command that writes the enter event
+/// of the call, forwards it to the wrapped struct, then writes the leave event.
+fn write_recorder<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// Records every call made through it into an apitrace trace, while forwarding it to the
+        /// wrapped `{api}`.
+        ///
+        /// Array and string parameters are recorded when their size is known from the registry,
+        /// and other pointers are recorded as opaque addresses. Errors that happen while writing
+        /// are returned by `finish`.
+        pub struct Recorder<W: __gl_imports::io::Write> {{
+            gl: {api},
+            trace: __gl_imports::RefCell<ApitraceWriter<W>>,
+        }}
+
+        #[allow(dead_code)]
+        impl<W: __gl_imports::io::Write> Recorder<W> {{
+            /// Starts recording the calls made to `gl` into `out`.
+            pub fn new(gl: {api}, out: W) -> Recorder<W> {{
+                let mut trace = ApitraceWriter {{
+                    out,
+                    chunk: Vec::new(),
+                    defined: vec![false; {len}],
+                    call_no: 0,
+                    error: None,
+                }};
+                trace.write_out(b"at");
+                trace.varuint(APITRACE_VERSION);
+                Recorder {{ gl, trace: __gl_imports::RefCell::new(trace) }}
+            }}
+
+            /// Returns the wrapped `{api}`. Calls made through it are not recorded.
+            pub fn gl(&self) -> &{api} {{
+                &self.gl
+            }}
+
+            /// Stops recording and returns the output, or the first error that happened while
+            /// writing to it.
+            pub fn finish(self) -> __gl_imports::io::Result<W> {{
+                let mut trace = self.trace.into_inner();
+                trace.flush_chunks();
+                match trace.error {{
+                    Some(error) => Err(error),
+                    None => {{
+                        trace.out.flush()?;
+                        Ok(trace.out)
+                    }},
+                }}
+            }}
+        "#,
+        api = super::gen_struct_name(registry.api),
+        len = registry.cmds.len(),
+    )?;
+
+    for (id, cmd) in registry.cmds.iter().enumerate() {
+        let idents = super::gen_parameters(cmd, true, false).join(", ");
+        let returns = cmd.proto.ty != "()";
+        let arg_names = cmd
+            .params
+            .iter()
+            .map(|param| format!("\"{}\"", param.ident.trim_end_matches('_')))
+            .collect::<Vec<_>>();
+
+        let mut enter = String::new();
+        let mut leave = String::new();
+        for (index, param) in cmd.params.iter().enumerate() {
+            let ident = &param.ident;
+            let value = match param_kind(param, cmd) {
+                ParamKind::Scalar => format!("trace.value(&{ident});"),
+                ParamKind::Callback { .. } => {
+                    format!(
+                        "trace.opaque(__gl_imports::mem::transmute_copy::<_, usize>(&{ident}));"
+                    )
+                },
+                ParamKind::Input { elem_ty, len } => gen_write_data(ident, &elem_ty, &len),
+                ParamKind::Output { elem_ty, len } => {
+                    leave.push_str(&format!(
+                        "trace.arg({index}); {}",
+                        gen_write_data(ident, &elem_ty, &len)
+                    ));
+                    continue;
+                },
+                ParamKind::CStr => format!("trace.c_str({ident}.cast::<u8>());"),
+                ParamKind::CStrArray { .. } | ParamKind::Unknown => {
+                    format!("trace.value(&{ident});")
+                },
+            };
+            enter.push_str(&format!("trace.arg({index}); {value}"));
+        }
+        if returns {
+            leave.push_str(&format!("trace.ret(); {}", gen_write_return(cmd)));
+        }
+
+        write!(dest, "{}", super::gen_cmd_doc(registry, cmd))?;
+        writeln!(
+            dest,
+            "#[allow(non_snake_case, unused_variables, dead_code)]
+            pub unsafe fn {name}(&self, {params}) -> {return_suffix} {{
+                let call_no = {{
+                    let mut trace = self.trace.borrow_mut();
+                    let call_no = trace.begin_enter({id}, \"{symbol}\", &[{arg_names}]);
+                    {enter}
+                    trace.end();
+                    call_no
+                }};
+                {call}
+                let mut trace = self.trace.borrow_mut();
+                trace.begin_leave(call_no);
+                {leave}
+                trace.end();
+                trace.end_call();
+                {result}
+            }}",
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
+            arg_names = arg_names.join(", "),
+            call = if returns {
+                format!("let r = self.gl.{}({idents});", cmd.proto.ident)
+            } else {
+                format!("self.gl.{}({idents});", cmd.proto.ident)
+            },
+            result = if returns { "r" } else { "" },
+        )?;
+    }
+
+    writeln!(dest, "}}")
+}
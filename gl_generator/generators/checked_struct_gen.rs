@@ -0,0 +1,276 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::{Api, Registry};
+use std::io;
+
+/// Generates the struct of `StructGenerator`, where each command calls `glGetError` after it
+/// runs and returns a `Result<T, GlError>`.
+#[allow(missing_copy_implementations)]
+pub struct CheckedStructGenerator;
+
+/// Generates the same struct as `CheckedStructGenerator`, but its commands never check for
+/// errors and always return `Ok`. This allows release builds to skip the checks without changing
+/// the calling code.
+#[allow(missing_copy_implementations)]
+pub struct UncheckedStructGenerator;
+
+impl super::Generator for CheckedStructGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write(registry, true, dest)
+    }
+}
+
+impl super::Generator for UncheckedStructGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write(registry, false, dest)
+    }
+}
+
+fn write<W>(registry: &Registry, check: bool, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+    super::sections::write_header(&ctx, dest)?;
+    super::sections::write_type_aliases(&ctx, dest)?;
+    super::sections::write_enums(&ctx, dest)?;
+    write_error_type(registry, dest)?;
+    super::struct_gen::write_fnptr_struct_def(dest)?;
+    super::gen_missing_fns(registry, dest)?;
+    super::gen_load_report(registry, dest)?;
+    super::struct_gen::write_struct(&ctx, dest)?;
+    write_impl(&ctx, check, dest)?;
+    Ok(())
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::error",
+    "std::fmt",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::process",
+    "std::sync::RwLock",
+];
+
+/// Converts the name of an enum to the name of a variant, for example `INVALID_ENUM` to
+/// `InvalidEnum`.
+fn variant_name(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| word[..1].to_string() + &word[1..].to_lowercase())
+        .collect()
+}
+
+/// Creates the `GlError` enum, with a variant for each value of the group of the return value
+/// of `GetError`, apart from the value that means there is no error.
+fn write_error_type<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    let enums = super::enums_by_group_name(registry);
+    let errors = registry
+        .cmds
+        .iter()
+        .find(|cmd| cmd.proto.ident == "GetError")
+        .and_then(|cmd| cmd.proto.group.as_ref())
+        .and_then(|group| registry.groups.get(group))
+        .map(|group| super::group_values(registry, group, &enums))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|&(value, _)| value != success_value(registry.api))
+        .map(|(value, name)| (value, name, variant_name(name)))
+        .collect::<Vec<_>>();
+
+    // a `match` with only the arm of `Other` would be linted by clippy
+    let (from_raw, to_raw, name) = if errors.is_empty() {
+        (
+            "GlError::Other(error)".to_string(),
+            "let GlError::Other(error) = self; error".to_string(),
+            "None".to_string(),
+        )
+    } else {
+        let mut from_raw = "match error {".to_string();
+        let mut to_raw = "match self {".to_string();
+        let mut name = "match self {".to_string();
+        for &(value, ident, ref variant) in &errors {
+            from_raw += &format!("{value:#x} => GlError::{variant},");
+            to_raw += &format!("GlError::{variant} => {value:#x},");
+            name += &format!("GlError::{variant} => Some(\"{ident}\"),");
+        }
+        from_raw += "error => GlError::Other(error) }";
+        to_raw += "GlError::Other(error) => error }";
+        name += "GlError::Other(_) => None }";
+        (from_raw, to_raw, name)
+    };
+
+    writeln!(
+        dest,
+        "/// An error returned by `{symbol}`.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum GlError {{",
+        symbol = super::gen_symbol_name(registry.api, "GetError"),
+    )?;
+    for &(_, name, ref variant) in &errors {
+        writeln!(dest, "/// `{name}`\n{variant},")?;
+    }
+    writeln!(
+        dest,
+        r#"/// An error that is not listed in the registry.
+            Other(u32),
+        }}
+
+        #[allow(dead_code)]
+        impl GlError {{
+            /// Converts a value returned by `{symbol}`, other than the one that means there is
+            /// no error.
+            pub fn from_raw(error: u32) -> GlError {{
+                {from_raw}
+            }}
+
+            /// Returns the value returned by `{symbol}` for this error.
+            pub fn to_raw(self) -> u32 {{
+                {to_raw}
+            }}
+
+            /// Returns the name of the error, for example `"INVALID_OPERATION"`, or `None` for
+            /// errors that are not listed in the registry.
+            pub fn name(self) -> Option<&'static str> {{
+                {name}
+            }}
+        }}
+
+        impl __gl_imports::fmt::Display for GlError {{
+            fn fmt(&self, f: &mut __gl_imports::fmt::Formatter) -> __gl_imports::fmt::Result {{
+                match self.name() {{
+                    Some(name) => f.write_str(name),
+                    None => write!(f, "{{:#06x}}", self.to_raw()),
+                }}
+            }}
+        }}
+
+        impl __gl_imports::error::Error for GlError {{}}
+    "#,
+        symbol = super::gen_symbol_name(registry.api, "GetError"),
+    )
+}
+
+/// Returns the value returned by `GetError` when there is no error.
+fn success_value(api: Api) -> u32 {
+    match api {
+        Api::Egl => 0x3000,
+        _ => 0,
+    }
+}
+
+/// Creates the `impl` of the structure created by `write_struct`, where each command returns a
+/// `Result`. `GetError` itself returns its value as it is.
+fn write_impl<W>(ctx: &super::GenContext, check: bool, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    let registry = ctx.registry();
+    writeln!(
+        dest,
+        "impl {api} {{",
+        api = super::gen_struct_name(registry.api)
+    )?;
+
+    super::struct_gen::write_load_fns(ctx, dest)?;
+
+    let has_get_error = registry
+        .cmds
+        .iter()
+        .any(|cmd| cmd.proto.ident == "GetError");
+    if has_get_error {
+        writeln!(
+            dest,
+            "/// Returns the error reported by `{symbol}`, if there is one and the function is
+            /// loaded. Only the oldest error is returned if several were recorded.
+            #[allow(dead_code)]
+            #[inline] pub unsafe fn check_error(&self) -> Result<(), GlError> {{
+                if !self.GetError.is_loaded {{
+                    return Ok(());
+                }}
+                match (self.GetError.f)() {{
+                    {success:#x} => Ok(()),
+                    error => Err(GlError::from_raw(error{cast})),
+                }}
+            }}",
+            symbol = super::gen_symbol_name(registry.api, "GetError"),
+            success = success_value(registry.api),
+            cast = if registry.api == Api::Egl {
+                " as u32"
+            } else {
+                ""
+            },
+        )?;
+    } else {
+        writeln!(
+            dest,
+            "/// Always returns `Ok`, because there is no command to query errors.
+            #[allow(dead_code)]
+            #[inline] pub unsafe fn check_error(&self) -> Result<(), GlError> {{
+                Ok(())
+            }}"
+        )?;
+    }
+
+    for cmd in &registry.cmds {
+        let call = format!(
+            "(self.{name}.f)({idents})",
+            name = cmd.proto.ident,
+            idents = super::gen_parameters(cmd, true, false).join(", "),
+        );
+        let (return_suffix, body) = if cmd.proto.ident == "GetError" {
+            (cmd.proto.ty.to_string(), call)
+        } else {
+            let body = match (check, cmd.proto.ty == "()") {
+                (true, true) => format!("{call}; self.check_error()"),
+                (true, false) => format!("let r = {call}; self.check_error().map(|()| r)"),
+                (false, true) => format!("{call}; Ok(())"),
+                (false, false) => format!("Ok({call})"),
+            };
+            (format!("Result<{}, GlError>", cmd.proto.ty), body)
+        };
+
+        write!(dest, "{}", super::gen_cmd_doc(registry, cmd))?;
+        writeln!(
+            dest,
+            "#[allow(non_snake_case, unused_variables, dead_code)]
+            #[inline] pub unsafe fn {name}(&self, {params}) -> {return_suffix} {{ \
+                {body} \
+            }}",
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+        )?
+    }
+
+    writeln!(
+        dest,
+        "}}
+
+        unsafe impl __gl_imports::Send for {api} {{}}",
+        api = super::gen_struct_name(registry.api)
+    )
+}
@@ -12,76 +12,136 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use registry::Registry;
+use registry::{Api, Registry};
 use std::io;
 
+use super::{GenContext, GeneratorOptions};
+
 #[allow(missing_copy_implementations)]
 pub struct DebugStructGenerator;
 
+/// Generates the same struct as `DebugStructGenerator`, plus a `LogHook` that logs the calls
+/// with the `log` crate. The crate including the bindings must depend on `log`.
+///
+/// Created with `DebugStructGenerator::with_log_hook`.
+#[allow(missing_copy_implementations)]
+pub struct LogDebugStructGenerator;
+
+impl DebugStructGenerator {
+    /// Returns a generator that also writes `LogHook`.
+    pub fn with_log_hook(self) -> LogDebugStructGenerator {
+        LogDebugStructGenerator
+    }
+}
+
 impl super::Generator for DebugStructGenerator {
     fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
     where
         W: io::Write,
     {
-        write_header(dest)?;
-        write_type_aliases(registry, dest)?;
-        write_enums(registry, dest)?;
-        write_fnptr_struct_def(dest)?;
-        write_panicking_fns(registry, dest)?;
-        write_struct(registry, dest)?;
-        write_impl(registry, dest)?;
-        Ok(())
+        self.write_with_options(registry, &GeneratorOptions::default(), dest)
+    }
+
+    fn write_with_options<W>(
+        &self,
+        registry: &Registry,
+        options: &GeneratorOptions,
+        dest: &mut W,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write_debug_struct(registry, options, false, dest)
     }
 }
 
-/// Creates a `__gl_imports` module which contains all the external symbols that we need for the
-///  bindings.
-fn write_header<W>(dest: &mut W) -> io::Result<()>
+impl super::Generator for LogDebugStructGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        self.write_with_options(registry, &GeneratorOptions::default(), dest)
+    }
+
+    fn write_with_options<W>(
+        &self,
+        registry: &Registry,
+        options: &GeneratorOptions,
+        dest: &mut W,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write_debug_struct(registry, options, true, dest)
+    }
+}
+
+fn write_debug_struct<W>(
+    registry: &Registry,
+    options: &GeneratorOptions,
+    log_hook: bool,
+    dest: &mut W,
+) -> io::Result<()>
 where
     W: io::Write,
 {
-    writeln!(
-        dest,
-        r#"
-        mod __gl_imports {{
-            pub use std::mem;
-            pub use std::marker::Send;
-            pub use std::os::raw;
-        }}
-    "#
-    )
+    let ctx = GenContext::new(registry)
+        .with_imports(IMPORTS.iter().copied())
+        .with_options(options.clone());
+    super::sections::write_header(&ctx, dest)?;
+    super::sections::write_type_aliases(&ctx, dest)?;
+    super::sections::write_enums(&ctx, dest)?;
+    write_fnptr_struct_def(dest)?;
+    super::gen_enum_groups(registry, dest)?;
+    write_call_hooks(registry, dest)?;
+    if log_hook {
+        write_log_hook(dest)?;
+    }
+    super::gen_missing_fns(registry, dest)?;
+    super::gen_load_report(registry, dest)?;
+    write_struct(&ctx, dest)?;
+    write_impl(&ctx, dest)?;
+    Ok(())
 }
 
-/// Creates a `types` module which contains all the type aliases.
-///
-/// See also `generators::gen_types`.
-fn write_type_aliases<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::fmt",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::process",
+    "std::sync::Arc",
+    "std::sync::RwLock",
+];
+
+/// Creates the `LogHook` structure, which logs with the `log` crate.
+fn write_log_hook<W>(dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
     writeln!(
         dest,
         r#"
-        pub mod types {{
-            #![allow(non_camel_case_types, non_snake_case, dead_code, missing_copy_implementations)]
-    "#
-    )?;
-
-    super::gen_types(registry.api, dest)?;
-
-    writeln!(dest, "}}")
-}
+        /// Logs every call at the `trace` level and errors at the `error` level, with the `log`
+        /// crate.
+        #[allow(missing_copy_implementations, dead_code)]
+        pub struct LogHook;
 
-/// Creates all the `<enum>` elements at the root of the bindings.
-fn write_enums<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    for enm in &registry.enums {
-        super::gen_enum_item(enm, "types::", dest)?;
-    }
+        impl CallHook for LogHook {{
+            fn before_call(&self, name: &str, args: &[&dyn __gl_imports::fmt::Debug]) {{
+                ::log::trace!("{{name}}({{}})", CallArgs(args));
+            }}
 
-    Ok(())
+            fn after_call(&self, name: &str, _: &dyn __gl_imports::fmt::Debug, error: Option<u32>) {{
+                if let Some(error) = error.map(error_arg) {{
+                    ::log::error!("{{name}} triggered GL error {{error:?}}");
+                }}
+            }}
+        }}
+    "#
+    )
 }
 
 /// Creates a `FnPtr` structure which contains the store for a single binding.
@@ -94,23 +154,25 @@ where
         "
         #[allow(dead_code, missing_copy_implementations)]
         #[derive(Clone)]
-        pub struct FnPtr {{
+        pub struct FnPtr<F> {{
             /// The function pointer that will be used when calling the function.
-            f: *const __gl_imports::raw::c_void,
+            f: F,
             /// True if the pointer points to a real function, false if points to a `panic!` fn.
             is_loaded: bool,
         }}
 
-        impl FnPtr {{
-            /// Creates a `FnPtr` from a load attempt.
-            fn new(ptr: *const __gl_imports::raw::c_void) -> FnPtr {{
+        impl<F: Copy> FnPtr<F> {{
+            /// Creates a `FnPtr` from a load attempt, using `missing` if the symbol was not found.
+            ///
+            /// `F` must be the function pointer type matching the signature of the loaded symbol.
+            unsafe fn new(ptr: *const __gl_imports::raw::c_void, missing: F) -> FnPtr<F> {{
                 if ptr.is_null() {{
+                    FnPtr {{ f: missing, is_loaded: false }}
+                }} else {{
                     FnPtr {{
-                        f: missing_fn_panic as *const __gl_imports::raw::c_void,
-                        is_loaded: false
+                        f: __gl_imports::mem::transmute_copy::<*const __gl_imports::raw::c_void, F>(&ptr),
+                        is_loaded: true,
                     }}
-                }} else {{
-                    FnPtr {{ f: ptr, is_loaded: true }}
                 }}
             }}
 
@@ -127,55 +189,175 @@ where
     )
 }
 
-/// Creates a `panicking` module which contains one function per GL command.
-///
-/// These functions are the mocks that are called if the real function could not be loaded.
-fn write_panicking_fns<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+/// Creates the `CallHook` trait, which is called around every command, and its built-in
+/// implementations.
+fn write_call_hooks<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
+    let groups = super::gen_enum_group_names(registry);
+    let error_group = registry
+        .cmds
+        .iter()
+        .find(|cmd| cmd.proto.ident == "GetError")
+        .and_then(|cmd| cmd.proto.group.as_ref())
+        .filter(|group| groups.contains(&group[..]));
+
     writeln!(
         dest,
-        "#[inline(never)]
-        fn missing_fn_panic() -> ! {{
-            panic!(\"{api} function was not loaded\")
-        }}",
-        api = registry.api
+        r#"
+        /// Called around every command made through `{api}`.
+        ///
+        /// Install a hook with `{api}::set_call_hook`.
+        pub trait CallHook: __gl_imports::Send + Sync {{
+            /// Called before the command `name` runs, with its arguments.
+            #[allow(unused_variables)]
+            fn before_call(&self, name: &str, args: &[&dyn __gl_imports::fmt::Debug]) {{}}
+
+            /// Called after the command `name` ran, with its result and the error reported by
+            /// `glGetError`, if any. `glGetError` is not called for commands of APIs that don't
+            /// have it.
+            #[allow(unused_variables)]
+            fn after_call(&self, name: &str, result: &dyn __gl_imports::fmt::Debug, error: Option<u32>) {{}}
+        }}
+
+        /// Formats the arguments of a call, separated by commas.
+        struct CallArgs<'a>(&'a [&'a dyn __gl_imports::fmt::Debug]);
+
+        impl<'a> __gl_imports::fmt::Display for CallArgs<'a> {{
+            fn fmt(&self, f: &mut __gl_imports::fmt::Formatter) -> __gl_imports::fmt::Result {{
+                for (i, arg) in self.0.iter().enumerate() {{
+                    if i > 0 {{
+                        f.write_str(", ")?;
+                    }}
+                    write!(f, "{{arg:?}}")?;
+                }}
+                Ok(())
+            }}
+        }}
+
+        /// Stands for callback arguments, which are not formatted.
+        #[allow(dead_code)]
+        struct CallbackArg;
+
+        impl __gl_imports::fmt::Debug for CallbackArg {{
+            fn fmt(&self, f: &mut __gl_imports::fmt::Formatter) -> __gl_imports::fmt::Result {{
+                f.write_str("<callback>")
+            }}
+        }}
+
+        /// Returns the name of an error returned by `{get_error}`, for example
+        /// `"INVALID_OPERATION"`.
+        #[allow(dead_code)]
+        pub fn error_name(error: u32) -> Option<&'static str> {{
+            let names: &[(u32, &str)] = {error_names};
+            names.iter().find(|&&(value, _)| value == error).map(|&(_, name)| name)
+        }}
+
+        /// Formats an error returned by `{get_error}` as its name.
+        #[allow(dead_code)]
+        fn error_arg(error: u32) -> EnumArg {{
+            EnumArg::new(error, {error_names})
+        }}
+
+        /// Prints every call and error to stdout. This is the default hook.
+        #[allow(missing_copy_implementations)]
+        pub struct StdoutHook;
+
+        impl CallHook for StdoutHook {{
+            fn before_call(&self, name: &str, args: &[&dyn __gl_imports::fmt::Debug]) {{
+                println!("[OpenGL] {{name}}({{}})", CallArgs(args));
+            }}
+
+            fn after_call(&self, _: &str, _: &dyn __gl_imports::fmt::Debug, error: Option<u32>) {{
+                if let Some(error) = error.map(error_arg) {{
+                    println!("[OpenGL] ^ GL error triggered: {{error:?}}");
+                }}
+            }}
+        }}
+
+        /// Prints every call and error to stderr.
+        #[allow(missing_copy_implementations, dead_code)]
+        pub struct StderrHook;
+
+        impl CallHook for StderrHook {{
+            fn before_call(&self, name: &str, args: &[&dyn __gl_imports::fmt::Debug]) {{
+                eprintln!("[OpenGL] {{name}}({{}})", CallArgs(args));
+            }}
+
+            fn after_call(&self, _: &str, _: &dyn __gl_imports::fmt::Debug, error: Option<u32>) {{
+                if let Some(error) = error.map(error_arg) {{
+                    eprintln!("[OpenGL] ^ GL error triggered: {{error:?}}");
+                }}
+            }}
+        }}
+
+        /// Only prints the calls that trigger an error, to stderr.
+        #[allow(missing_copy_implementations, dead_code)]
+        pub struct ErrorHook;
+
+        impl CallHook for ErrorHook {{
+            fn after_call(&self, name: &str, _: &dyn __gl_imports::fmt::Debug, error: Option<u32>) {{
+                if let Some(error) = error.map(error_arg) {{
+                    eprintln!("[OpenGL] {{name}} triggered GL error {{error:?}}");
+                }}
+            }}
+        }}
+    "#,
+        api = super::gen_struct_name(registry.api),
+        get_error = super::gen_symbol_name(registry.api, "GetError"),
+        error_names = match error_group {
+            Some(group) => format!("enum_groups::{group}"),
+            None => "&[]".to_string(),
+        },
     )
 }
 
 /// Creates a structure which stores all the `FnPtr` of the bindings.
 ///
 /// The name of the struct corresponds to the namespace.
-fn write_struct<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+fn write_struct<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
     writeln!(
         dest,
         "
-        #[allow(non_camel_case_types, non_snake_case, dead_code)]
+        {allow}
         #[derive(Clone)]
-        pub struct {api} {{",
-        api = super::gen_struct_name(registry.api)
+        {vis} struct {api} {{",
+        allow = ctx
+            .options()
+            .allow("non_camel_case_types, non_snake_case, dead_code, clippy::type_complexity"),
+        vis = ctx.options().vis(),
+        api = ctx.struct_name(),
     )?;
 
-    for cmd in &registry.cmds {
-        if let Some(v) = registry.aliases.get(&cmd.proto.ident) {
-            writeln!(dest, "/// Fallbacks: {}", v.join(", "))?;
+    for cmd in &ctx.registry().cmds {
+        let fallbacks = ctx.fallbacks(cmd);
+        if !fallbacks.is_empty() {
+            writeln!(dest, "/// Fallbacks: {}", fallbacks.join(", "))?;
         }
-        writeln!(dest, "pub {name}: FnPtr,", name = cmd.proto.ident)?;
+        writeln!(
+            dest,
+            "{vis} {name}: FnPtr<{fn_ty}>,",
+            vis = ctx.options().vis(),
+            name = cmd.proto.ident,
+            fn_ty = super::gen_fn_ptr_type(cmd),
+        )?;
     }
+    writeln!(dest, "call_hook: __gl_imports::Arc<dyn CallHook>,")?;
     writeln!(dest, "_priv: ()")?;
 
     writeln!(dest, "}}")
 }
 
 /// Creates the `impl` of the structure created by `write_struct`.
-fn write_impl<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+fn write_impl<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
+    let registry = ctx.registry();
     writeln!(dest,
                   "impl {api} {{
             /// Load each OpenGL symbol using a custom load function. This allows for the
@@ -184,95 +366,162 @@ where
             /// ~~~ignore
             /// let gl = Gl::load_with(|s| glfw.get_proc_address(s));
             /// ~~~
-            #[allow(dead_code, unused_variables)]
-            pub fn load_with<F>(mut loadfn: F) -> {api} where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
+            {allow} {vis} fn load_with<F>(loadfn: F) -> {api} where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
+                {api}::load_with_report(loadfn).0
+            }}
+
+            /// Same as `load_with`, but also returns a report of the symbol that was resolved
+            /// for each command.
+            ///
+            /// ~~~ignore
+            /// let (gl, report) = Gl::load_with_report(|s| glfw.get_proc_address(s));
+            /// println!(\"{{}}\", report);
+            /// ~~~
+            {allow} {vis} fn load_with_report<F>(mut loadfn: F) -> ({api}, LoadReport)
+                where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
                 #[inline(never)]
                 fn do_metaloadfn(loadfn: &mut dyn FnMut(&'static str) -> *const __gl_imports::raw::c_void,
-                                 symbol: &'static str,
-                                 symbols: &[&'static str])
+                                 report: &mut LoadReport,
+                                 info: &MissingFn)
                                  -> *const __gl_imports::raw::c_void {{
-                    let mut ptr = loadfn(symbol);
+                    let mut resolved = None;
+                    let mut ptr = loadfn(info.symbol);
                     if ptr.is_null() {{
-                        for &sym in symbols {{
+                        for &sym in info.fallbacks {{
                             ptr = loadfn(sym);
-                            if !ptr.is_null() {{ break; }}
+                            if !ptr.is_null() {{
+                                resolved = Some(sym);
+                                break;
+                            }}
                         }}
+                    }} else {{
+                        resolved = Some(info.symbol);
                     }}
+                    report.push(info, resolved);
                     ptr
                 }}
-                let mut metaloadfn = |symbol: &'static str, symbols: &[&'static str]| {{
-                    do_metaloadfn(&mut loadfn, symbol, symbols)
+                let mut report = LoadReport::with_capacity({len});
+                let mut metaloadfn = |info: &MissingFn| {{
+                    do_metaloadfn(&mut loadfn, &mut report, info)
                 }};
-                {api} {{",
-                  api = super::gen_struct_name(registry.api))?;
+                let gl = unsafe {{
+                    {api} {{",
+                  allow = ctx.options().allow("dead_code, unused_variables"),
+                  vis = ctx.options().vis(),
+                  api = ctx.struct_name(),
+                  len = registry.cmds.len())?;
 
     for cmd in &registry.cmds {
         writeln!(
             dest,
-            "{name}: FnPtr::new(metaloadfn(\"{symbol}\", &[{fallbacks}])),",
+            "{name}: FnPtr::new(metaloadfn(&missing::info::{name}), missing::{name}),",
             name = cmd.proto.ident,
-            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
-            fallbacks = match registry.aliases.get(&cmd.proto.ident) {
-                Some(fbs) => fbs
-                    .iter()
-                    .map(|name| format!("\"{}\"", super::gen_symbol_name(registry.api, name)))
-                    .collect::<Vec<_>>()
-                    .join(", "),
-                None => String::new(),
-            },
         )?
     }
+    writeln!(dest, "call_hook: __gl_imports::Arc::new(StdoutHook),")?;
     writeln!(dest, "_priv: ()")?;
 
     writeln!(
         dest,
         "}}
-        }}"
+                }};
+                (gl, report)
+            }}
+
+            /// Replaces the hook that is called around every command. The default hook is
+            /// `StdoutHook`.
+            #[allow(dead_code)]
+            pub fn set_call_hook<H: CallHook + 'static>(&mut self, hook: H) {{
+                self.call_hook = __gl_imports::Arc::new(hook);
+            }}"
     )?;
 
+    let has_get_error = registry
+        .cmds
+        .iter()
+        .any(|cmd| cmd.proto.ident == "GetError");
+    if has_get_error {
+        writeln!(
+            dest,
+            "/// Returns the value of `{symbol}`, if it is loaded and there is an error.
+            #[allow(dead_code)]
+            unsafe fn call_error(&self) -> Option<u32> {{
+                if !self.GetError.is_loaded {{
+                    return None;
+                }}
+                match (self.GetError.f)() {{
+                    {success} => None,
+                    error => Some({error}),
+                }}
+            }}",
+            symbol = super::gen_symbol_name(registry.api, "GetError"),
+            success = if registry.api == Api::Egl {
+                "0x3000"
+            } else {
+                "0"
+            },
+            error = if registry.api == Api::Egl {
+                "error as u32"
+            } else {
+                "error"
+            },
+        )?;
+    }
+
+    let groups = super::gen_enum_group_names(registry);
     for cmd in &registry.cmds {
         let idents = super::gen_parameters(cmd, true, false);
         let typed_params = super::gen_parameters(cmd, false, true);
-        let println = format!(
-            "println!(\"[OpenGL] {}({})\");",
-            cmd.proto.ident,
-            idents
-                .iter()
-                .zip(typed_params.iter())
-                .map(|(name, ty)| if ty.contains("GLDEBUGPROC") {
-                    "<callback>".to_string()
+        let args = idents
+            .iter()
+            .zip(typed_params.iter())
+            .zip(cmd.params.iter())
+            .map(|((name, ty), param)| {
+                if ty.contains("GLDEBUGPROC") {
+                    "&CallbackArg".to_string()
+                } else if let Some(arg) = super::gen_enum_arg(param, name, &groups) {
+                    format!("&{arg}")
                 } else {
-                    format!("{{{name}:?}}")
-                })
-                .collect::<Vec<_>>()
-                .join(", "),
-        );
-
-        writeln!(dest,
-                      "#[allow(non_snake_case, unused_variables, dead_code)]
-            #[inline] pub unsafe fn {name}(&self, {params}) -> {return_suffix} {{ \
-                {println}
-                let r = __gl_imports::mem::transmute::<_, extern \"system\" fn({typed_params}) -> {return_suffix}>\
-                    (self.{name}.f)({idents});
-                {print_err}
-                r
+                    format!("&{name}")
+                }
+            })
+            .collect::<Vec<_>>();
+
+        write!(dest, "{}", super::gen_cmd_doc(registry, cmd))?;
+        writeln!(
+            dest,
+            "{attrs} {inline} #[track_caller]
+            {vis} unsafe fn {fn_name}(&self, {params}) -> {return_suffix} {{ \
+                if !self.{name}.is_loaded {{ missing_fn_called(&missing::info::{name}) }}
+                self.call_hook.before_call(\"{name}\", &[{args}]);
+                {call}
+                self.call_hook.after_call(\"{name}\", &{result}, {error});
+                {result}
             }}",
-                      name = cmd.proto.ident,
-                      params = super::gen_parameters(cmd, true, true).join(", "),
-                      typed_params = typed_params.join(", "),
-                      return_suffix = cmd.proto.ty,
-                      idents = idents.join(", "),
-                      println = println,
-                      print_err = if cmd.proto.ident != "GetError" &&
-                                     registry
-                                         .cmds
-                                         .iter()
-                                         .any(|cmd| cmd.proto.ident == "GetError") {
-                          r#"match __gl_imports::mem::transmute::<_, extern "system" fn() -> u32>
-                    (self.GetError.f)() { 0 => (), r => println!("[OpenGL] ^ GL error triggered: {r}") }"#.to_string()
-                      } else {
-                          String::new()
-                      })?
+            attrs = ctx.options().fn_attrs(&ctx.symbol_name(cmd)),
+            inline = ctx.options().inline_attr(),
+            vis = ctx.options().vis(),
+            fn_name = ctx.fn_name(cmd),
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+            args = args.join(", "),
+            call = if cmd.proto.ty == "()" {
+                format!("(self.{}.f)({});", cmd.proto.ident, idents.join(", "))
+            } else {
+                format!(
+                    "let r = (self.{}.f)({});",
+                    cmd.proto.ident,
+                    idents.join(", ")
+                )
+            },
+            result = if cmd.proto.ty == "()" { "()" } else { "r" },
+            error = if has_get_error && cmd.proto.ident != "GetError" {
+                "self.call_error()"
+            } else {
+                "None"
+            },
+        )?
     }
 
     writeln!(
@@ -0,0 +1,125 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::Registry;
+use std::collections::BTreeMap;
+use std::io;
+
+/// Generates the `enum_name` and `group_enum_name` functions, which look up the names of enum
+/// values.
+///
+/// Only these functions are generated, so that they can be written next to the bindings of
+/// another generator.
+#[allow(missing_copy_implementations)]
+pub struct EnumNamesGenerator;
+
+impl super::Generator for EnumNamesGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write_lookup_fns(dest)?;
+        write_enum_names(registry, dest)?;
+        write_group_enum_names(registry, dest)?;
+        Ok(())
+    }
+}
+
+/// Creates the functions that look up the generated tables.
+fn write_lookup_fns<W>(dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// Returns the name of an enum value, for example `Some("ARRAY_BUFFER")` for `0x8892`.
+        ///
+        /// When several enums have the same value, names required by a version of the API are
+        /// preferred over names that only extensions require, and then the first name in
+        /// alphabetical order.
+        #[allow(dead_code)]
+        pub fn enum_name(value: u32) -> Option<&'static str> {{
+            let i = ENUM_NAMES.binary_search_by_key(&value, |&(v, _)| v).ok()?;
+            Some(ENUM_NAMES[i].1)
+        }}
+
+        /// Returns the name of a value in an enum group of the registry, for example
+        /// `Some("ARRAY_BUFFER")` for `group_enum_name("BufferTargetARB", 0x8892)`.
+        ///
+        /// When several enums of the group have the same value, core names are preferred.
+        #[allow(dead_code)]
+        pub fn group_enum_name(group: &str, value: u32) -> Option<&'static str> {{
+            let i = GROUP_ENUM_NAMES.binary_search_by_key(&group, |&(g, _)| g).ok()?;
+            let names = GROUP_ENUM_NAMES[i].1;
+            let i = names.binary_search_by_key(&value, |&(v, _)| v).ok()?;
+            Some(names[i].1)
+        }}
+    "#
+    )
+}
+
+/// Creates the `ENUM_NAMES` table, sorted by value.
+fn write_enum_names<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    let mut names = BTreeMap::new();
+    for enm in super::enums_by_group_name(registry).into_values() {
+        let value = match super::parse_enum_value(enm) {
+            Some(value) => value,
+            None => continue,
+        };
+        let name = &enm.ident[..];
+        let preferred = names.entry(value).or_insert(name);
+        if (!super::is_core_enum(registry, name), name)
+            < (!super::is_core_enum(registry, preferred), *preferred)
+        {
+            *preferred = name;
+        }
+    }
+
+    writeln!(dest, "static ENUM_NAMES: &[(u32, &str)] = &[")?;
+    for (value, name) in names {
+        writeln!(dest, "({value:#x}, \"{name}\"),")?;
+    }
+    writeln!(dest, "];")
+}
+
+/// Creates the `GROUP_ENUM_NAMES` table, sorted by group, with the values of each group sorted.
+/// Groups without any value in the registry are left out.
+fn write_group_enum_names<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    let enums = super::enums_by_group_name(registry);
+
+    writeln!(
+        dest,
+        "static GROUP_ENUM_NAMES: &[(&str, &[(u32, &str)])] = &["
+    )?;
+    for (ident, group) in &registry.groups {
+        let mut names = super::group_values(registry, group, &enums);
+        if names.is_empty() {
+            continue;
+        }
+        names.sort();
+        writeln!(dest, "(\"{ident}\", &[")?;
+        for (value, name) in names {
+            writeln!(dest, "({value:#x}, \"{name}\"),")?;
+        }
+        writeln!(dest, "]),")?;
+    }
+    writeln!(dest, "];")
+}
@@ -15,6 +15,8 @@
 use registry::Registry;
 use std::io;
 
+use super::{GenContext, GeneratorOptions};
+
 #[allow(missing_copy_implementations)]
 pub struct GlobalGenerator;
 
@@ -23,39 +25,50 @@ impl super::Generator for GlobalGenerator {
     where
         W: io::Write,
     {
-        write_header(dest)?;
+        self.write_with_options(registry, &GeneratorOptions::default(), dest)
+    }
+
+    fn write_with_options<W>(
+        &self,
+        registry: &Registry,
+        options: &GeneratorOptions,
+        dest: &mut W,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = GenContext::new(registry)
+            .with_imports(IMPORTS.iter().copied())
+            .with_options(options.clone());
+        super::sections::write_header(&ctx, dest)?;
         write_metaloadfn(dest)?;
-        write_type_aliases(registry, dest)?;
-        write_enums(registry, dest)?;
-        write_fns(registry, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        write_fns(&ctx, dest)?;
         write_fnptr_struct_def(dest)?;
         write_ptrs(registry, dest)?;
-        write_fn_mods(registry, dest)?;
-        write_panicking_fns(registry, dest)?;
-        write_load_fn(registry, dest)?;
+        write_fn_mods(&ctx, dest)?;
+        super::gen_missing_fns(registry, dest)?;
+        super::gen_load_report(registry, dest)?;
+        write_load_fn(&ctx, dest)?;
         Ok(())
     }
 }
 
-/// Creates a `__gl_imports` module which contains all the external symbols that we need for the
-///  bindings.
-fn write_header<W>(dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    writeln!(
-        dest,
-        r#"
-        mod __gl_imports {{
-            pub use std::mem;
-            pub use std::os::raw;
-        }}
-    "#
-    )
-}
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::fmt",
+    "std::mem",
+    "std::os::raw",
+    "std::process",
+    "std::sync::RwLock",
+];
 
 /// Creates the metaloadfn function for fallbacks
-fn write_metaloadfn<W>(dest: &mut W) -> io::Result<()>
+///
+/// Besides the pointer, it returns the symbol that was found, if any.
+pub(super) fn write_metaloadfn<W>(dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
@@ -65,79 +78,46 @@ where
         #[inline(never)]
         fn metaloadfn(loadfn: &mut dyn FnMut(&'static str) -> *const __gl_imports::raw::c_void,
                       symbol: &'static str,
-                      fallbacks: &[&'static str]) -> *const __gl_imports::raw::c_void {{
-            let mut ptr = loadfn(symbol);
-            if ptr.is_null() {{
-                for &sym in fallbacks {{
-                    ptr = loadfn(sym);
-                    if !ptr.is_null() {{ break; }}
+                      fallbacks: &[&'static str])
+                      -> (*const __gl_imports::raw::c_void, Option<&'static str>) {{
+            let ptr = loadfn(symbol);
+            if !ptr.is_null() {{
+                return (ptr, Some(symbol));
+            }}
+            for &sym in fallbacks {{
+                let ptr = loadfn(sym);
+                if !ptr.is_null() {{
+                    return (ptr, Some(sym));
                 }}
             }}
-            ptr
+            (ptr, None)
         }}
     "#
     )
 }
 
-/// Creates a `types` module which contains all the type aliases.
-///
-/// See also `generators::gen_types`.
-fn write_type_aliases<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    writeln!(
-        dest,
-        r#"
-        pub mod types {{
-            #![allow(non_camel_case_types, non_snake_case, dead_code, missing_copy_implementations)]
-    "#
-    )?;
-
-    super::gen_types(registry.api, dest)?;
-
-    writeln!(
-        dest,
-        "
-        }}
-    "
-    )
-}
-
-/// Creates all the `<enum>` elements at the root of the bindings.
-fn write_enums<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    for enm in &registry.enums {
-        super::gen_enum_item(enm, "types::", dest)?;
-    }
-
-    Ok(())
-}
-
 /// Creates the functions corresponding to the GL commands.
 ///
 /// The function calls the corresponding function pointer stored in the `storage` module created
 ///  by `write_ptrs`.
-fn write_fns<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+fn write_fns<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
-    for cmd in &registry.cmds {
-        if let Some(v) = registry.aliases.get(&cmd.proto.ident) {
-            writeln!(dest, "/// Fallbacks: {}", v.join(", "))?;
-        }
-
-        writeln!(dest,
-            "#[allow(non_snake_case, unused_variables, dead_code)] #[inline]
-            pub unsafe fn {name}({params}) -> {return_suffix} {{ \
-                __gl_imports::mem::transmute::<_, extern \"system\" fn({typed_params}) -> {return_suffix}>\
-                    (storage::{name}.f)({idents}) \
+    for cmd in &ctx.registry().cmds {
+        write!(dest, "{}", super::gen_cmd_doc(ctx.registry(), cmd))?;
+        writeln!(
+            dest,
+            "{attrs} {inline}
+            {vis} unsafe fn {name}({params}) -> {return_suffix} {{ \
+                (storage::{ident}.f)({idents}) \
             }}",
-            name = cmd.proto.ident,
+            attrs = ctx.options().fn_attrs(&ctx.symbol_name(cmd)),
+            inline = ctx.options().inline_attr(),
+            vis = ctx.options().vis(),
+            name = ctx.fn_name(cmd),
+            ident = cmd.proto.ident,
             params = super::gen_parameters(cmd, true, true).join(", "),
-            typed_params = super::gen_parameters(cmd, false, true).join(", "),
             return_suffix = cmd.proto.ty,
             idents = super::gen_parameters(cmd, true, false).join(", "),
         )?;
@@ -147,27 +127,34 @@ where
 }
 
 /// Creates a `FnPtr` structure which contains the store for a single binding.
-fn write_fnptr_struct_def<W>(dest: &mut W) -> io::Result<()>
+pub(super) fn write_fnptr_struct_def<W>(dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
     writeln!(dest,
              "
         #[allow(missing_copy_implementations)]
-        pub struct FnPtr {{
+        pub struct FnPtr<F> {{
             /// The function pointer that will be used when calling the function.
-            f: *const __gl_imports::raw::c_void,
+            f: F,
             /// True if the pointer points to a real function, false if points to a `panic!` fn.
             is_loaded: bool,
         }}
 
-        impl FnPtr {{
-            /// Creates a `FnPtr` from a load attempt.
-            pub fn new(ptr: *const __gl_imports::raw::c_void) -> FnPtr {{
+        impl<F: Copy> FnPtr<F> {{
+            /// Creates a `FnPtr` from a load attempt, using `missing` if the symbol was not found.
+            ///
+            /// # Safety
+            ///
+            /// `F` must be the function pointer type matching the signature of the loaded symbol.
+            pub unsafe fn new(ptr: *const __gl_imports::raw::c_void, missing: F) -> FnPtr<F> {{
                 if ptr.is_null() {{
-                    FnPtr {{ f: missing_fn_panic as *const __gl_imports::raw::c_void, is_loaded: false }}
+                    FnPtr {{ f: missing, is_loaded: false }}
                 }} else {{
-                    FnPtr {{ f: ptr, is_loaded: true }}
+                    FnPtr {{
+                        f: __gl_imports::mem::transmute_copy::<*const __gl_imports::raw::c_void, F>(&ptr),
+                        is_loaded: true,
+                    }}
                 }}
             }}
         }}
@@ -184,18 +171,22 @@ where
         "mod storage {{
             #![allow(non_snake_case)]
             #![allow(non_upper_case_globals)]
-            use super::__gl_imports::raw;
+            #![allow(unused_imports)]
+            #![allow(clippy::type_complexity)]
+            use super::__gl_imports;
+            use super::types;
             use super::FnPtr;"
     )?;
 
     for c in &registry.cmds {
         writeln!(
             dest,
-            "pub static mut {name}: FnPtr = FnPtr {{
-                f: super::missing_fn_panic as *const raw::c_void,
+            "pub static mut {name}: FnPtr<{fn_ty}> = FnPtr {{
+                f: super::missing::{name},
                 is_loaded: false
             }};",
-            name = c.proto.ident
+            name = c.proto.ident,
+            fn_ty = super::gen_fn_ptr_type(c),
         )?;
     }
 
@@ -205,11 +196,12 @@ where
 /// Creates one module for each GL command.
 ///
 /// Each module contains `is_loaded` and `load_with` which interact with the `storage` module
-///  created by `write_ptrs`.
-fn write_fn_mods<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+///  created by `write_ptrs`, and a `load_resolved` function used by `load_with_report`.
+fn write_fn_mods<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
+    let registry = ctx.registry();
     for c in &registry.cmds {
         let fallbacks = match registry.aliases.get(&c.proto.ident) {
             Some(v) => {
@@ -222,60 +214,55 @@ where
             None => "&[]".to_string(),
         };
         let fnname = &c.proto.ident[..];
+        let modname = ctx.fn_name(c);
         let symbol = super::gen_symbol_name(registry.api, &c.proto.ident[..]);
         let symbol = &symbol[..];
 
         writeln!(
             dest,
             r##"
-            #[allow(non_snake_case)]
-            pub mod {fnname} {{
-                use super::{{storage, metaloadfn}};
+            {allow}
+            {vis} mod {modname} {{
+                use super::{{storage, metaloadfn, missing}};
                 use super::__gl_imports::raw;
                 use super::FnPtr;
 
                 #[inline]
-                #[allow(dead_code)]
+                {allow_dead_code}
                 pub fn is_loaded() -> bool {{
                     unsafe {{ storage::{fnname}.is_loaded }}
                 }}
 
-                #[allow(dead_code)]
+                {allow_dead_code}
                 pub fn load_with<F>(mut loadfn: F) where F: FnMut(&'static str) -> *const raw::c_void {{
-                    unsafe {{
-                        storage::{fnname} = FnPtr::new(metaloadfn(&mut loadfn, "{symbol}", {fallbacks}))
-                    }}
+                    load_resolved(&mut loadfn);
+                }}
+
+                /// Loads the function and returns the symbol that was found, if any.
+                pub(super) fn load_resolved(
+                    loadfn: &mut dyn FnMut(&'static str) -> *const raw::c_void,
+                ) -> Option<&'static str> {{
+                    let (ptr, resolved) = metaloadfn(loadfn, "{symbol}", {fallbacks});
+                    unsafe {{ storage::{fnname} = FnPtr::new(ptr, missing::{fnname}) }};
+                    resolved
                 }}
             }}
-        "##
+        "##,
+            allow = ctx.options().allow("non_snake_case"),
+            vis = ctx.options().vis(),
+            allow_dead_code = ctx.options().allow("dead_code"),
         )?;
     }
 
     Ok(())
 }
 
-/// Creates a `missing_fn_panic` function.
-///
-/// This function is the mock that is called if the real function could not be called.
-fn write_panicking_fns<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    writeln!(
-        dest,
-        "#[inline(never)]
-        fn missing_fn_panic() -> ! {{
-            panic!(\"{api} function was not loaded\")
-        }}
-        ",
-        api = registry.api
-    )
-}
-
-/// Creates the `load_with` function.
+/// Creates the `load_with` and `load_with_report` functions.
 ///
-/// The function calls `load_with` in each module created by `write_fn_mods`.
-fn write_load_fn<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+/// Both call `load_all`, which calls `load_resolved` in each module created by `write_fn_mods`
+/// and passes the symbol that was found to a callback, so that only `load_with_report` builds a
+/// report.
+fn write_load_fn<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
@@ -286,27 +273,42 @@ where
         /// ~~~ignore
         /// gl::load_with(|s| glfw.get_proc_address(s));
         /// ~~~
-        #[allow(dead_code)]
-        pub fn load_with<F>(mut loadfn: F) where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
-            #[inline(never)]
-            fn inner(loadfn: &mut dyn FnMut(&'static str) -> *const __gl_imports::raw::c_void) {{
-    ")?;
+        {allow} {vis} fn load_with<F>(mut loadfn: F) where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
+            load_all(&mut loadfn, &mut |_, _| ());
+        }}
 
-    for c in &registry.cmds {
+        /// Same as `load_with`, but also returns a report of the symbol that was resolved for
+        /// each command.
+        /// ~~~ignore
+        /// let report = gl::load_with_report(|s| glfw.get_proc_address(s));
+        /// println!(\"{{}}\", report);
+        /// ~~~
+        {allow} {vis} fn load_with_report<F>(mut loadfn: F) -> LoadReport
+            where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
+            let mut report = LoadReport::with_capacity({len});
+            load_all(&mut loadfn, &mut |info, resolved| report.push(info, resolved));
+            report
+        }}
+
+        #[inline(never)]
+        fn load_all(
+            loadfn: &mut dyn FnMut(&'static str) -> *const __gl_imports::raw::c_void,
+            on_load: &mut dyn FnMut(&MissingFn, Option<&'static str>),
+        ) {{
+    ",
+        allow = ctx.options().allow("dead_code"),
+        vis = ctx.options().vis(),
+        len = ctx.registry().cmds.len(),
+    )?;
+
+    for c in &ctx.registry().cmds {
         writeln!(
             dest,
-            "{cmd_name}::load_with(&mut *loadfn);",
-            cmd_name = &c.proto.ident[..]
+            "on_load(&missing::info::{ident}, {modname}::load_resolved(loadfn));",
+            ident = &c.proto.ident[..],
+            modname = ctx.fn_name(c),
         )?;
     }
 
-    writeln!(
-        dest,
-        "
-            }}
-
-            inner(&mut loadfn)
-        }}
-    "
-    )
+    writeln!(dest, "}}")
 }
@@ -0,0 +1,516 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::{Binding, Cmd, Registry};
+use std::collections::BTreeMap;
+use std::io;
+
+/// Generates encoders for the GLX protocol requests of the commands that have a GLX opcode in the
+/// registry.
+///
+/// Render commands are methods of `RenderCommands`, which batches them into a `glXRender`
+/// request. Single and vendor private requests are functions that return the whole request.
+#[allow(missing_copy_implementations)]
+pub struct GlxProtocolGenerator;
+
+impl super::Generator for GlxProtocolGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        write_request_fns(dest)?;
+        write_render_commands(registry, dest)?;
+        write_requests(registry, dest)?;
+        Ok(())
+    }
+}
+
+/// The types of the arguments that can be packed into a request, which all have a `to_ne_bytes`
+/// method.
+const PACKED_TYPES: &[&str] = &[
+    "types::GLbitfield",
+    "types::GLboolean",
+    "types::GLbyte",
+    "types::GLchar",
+    "types::GLclampd",
+    "types::GLclampf",
+    "types::GLdouble",
+    "types::GLenum",
+    "types::GLfixed",
+    "types::GLfloat",
+    "types::GLhalfNV",
+    "types::GLint",
+    "types::GLint64",
+    "types::GLint64EXT",
+    "types::GLshort",
+    "types::GLsizei",
+    "types::GLubyte",
+    "types::GLuint",
+    "types::GLuint64",
+    "types::GLuint64EXT",
+    "types::GLushort",
+];
+
+/// The 64-bit types, whose arguments the GLX protocol packs before the others.
+const DOUBLE_TYPES: &[&str] = &[
+    "types::GLclampd",
+    "types::GLdouble",
+    "types::GLint64",
+    "types::GLint64EXT",
+    "types::GLuint64",
+    "types::GLuint64EXT",
+];
+
+/// The commands with both 64-bit and other arguments whose layout is known, with the 64-bit
+/// arguments first. The other commands with both are skipped, since some of them keep the order
+/// of the parameters, like `glVertexAttrib1dARB`.
+const DOUBLES_FIRST: &[&str] = &[
+    "ClipPlane",
+    "MapGrid1d",
+    "MapGrid2d",
+    "MultiTexCoord1d",
+    "MultiTexCoord1dv",
+    "MultiTexCoord2d",
+    "MultiTexCoord2dv",
+    "MultiTexCoord3d",
+    "MultiTexCoord3dv",
+    "MultiTexCoord4d",
+    "MultiTexCoord4dv",
+    "TexGend",
+];
+
+/// The fields of the pixel store state that the GLX protocol packs after the arguments of the
+/// commands that return an image. The other commands that return an image, which have `format`
+/// and `type` parameters, are skipped.
+const PIXEL_STORE_FIELDS: &[(&str, &[&str])] = &[
+    ("GetColorTable", &["swap_bytes"]),
+    ("GetConvolutionFilter", &["swap_bytes"]),
+    ("GetPolygonStipple", &["lsb_first"]),
+    ("GetSeparableFilter", &["swap_bytes"]),
+    ("GetTexImage", &["swap_bytes"]),
+    ("ReadPixels", &["swap_bytes", "lsb_first"]),
+];
+
+/// How a parameter of a command is passed to its encoder, and packed into the request.
+enum Arg<'a> {
+    /// Passed and packed as it is.
+    Scalar(&'a Binding),
+    /// The length of a `Slice`, packed but computed from the slice.
+    Len { param: &'a Binding, slice: &'a str },
+    /// A pointer to a fixed number of elements, taken as a reference to an array.
+    Array {
+        param: &'a Binding,
+        elem_ty: &'a str,
+        size: &'a str,
+    },
+    /// A pointer to as many elements as another parameter, taken as a slice.
+    Slice {
+        param: &'a Binding,
+        elem_ty: &'a str,
+    },
+    /// A pointer to data returned in the reply, which isn't part of the request.
+    Reply,
+    /// A field of the pixel store state of the client, which isn't a parameter of the command.
+    PixelStore(&'static str),
+}
+
+impl Arg<'_> {
+    /// Returns `true` if the argument is packed before the others.
+    fn is_64_bit(&self) -> bool {
+        match *self {
+            Arg::Scalar(param) => DOUBLE_TYPES.contains(&&param.ty[..]),
+            Arg::Array { elem_ty, .. } | Arg::Slice { elem_ty, .. } => {
+                DOUBLE_TYPES.contains(&elem_ty)
+            },
+            Arg::Len { .. } | Arg::Reply | Arg::PixelStore(_) => false,
+        }
+    }
+}
+
+/// The GLX request that encodes a command.
+enum Request {
+    Render(u16),
+    Single(u8),
+    Vendor(u32),
+}
+
+/// Returns the GLX request of each command that can be encoded, by name.
+///
+/// The scalar forms of commands that have no GLX opcode use the opcode of their vector form,
+/// since both are encoded the same way, like `Color3f` and `Color3fv`.
+fn requests(registry: &Registry) -> BTreeMap<&str, Request> {
+    let parse = |cmd: &Cmd| {
+        let glx = cmd.glx.as_ref()?;
+        match &glx.ty[..] {
+            "render" => glx.opcode.parse().ok().map(Request::Render),
+            "single" => glx.opcode.parse().ok().map(Request::Single),
+            "vendor" => glx.opcode.parse().ok().map(Request::Vendor),
+            _ => None,
+        }
+    };
+    let cmds = registry
+        .cmds
+        .iter()
+        .map(|cmd| (&cmd.proto.ident[..], cmd))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut requests = BTreeMap::new();
+    for cmd in &registry.cmds {
+        let request = match cmd.vecequiv {
+            Some(ref vector) if cmd.glx.is_none() => {
+                cmds.get(&vector[..])
+                    .and_then(|&vector| match parse(vector)? {
+                        Request::Render(opcode) => Some(Request::Render(opcode)),
+                        _ => None,
+                    })
+            },
+            _ => parse(cmd),
+        };
+        if let Some(request) = request {
+            requests.insert(&cmd.proto.ident[..], request);
+        }
+    }
+    requests
+}
+
+/// Returns how each parameter of `cmd` is passed to its encoder, followed by the pixel store
+/// fields of the request, or `None` if one of them can't be packed or the layout of the request
+/// isn't known.
+///
+/// Output pointers are only allowed in requests with a reply, where `has_reply` is true.
+fn args(cmd: &Cmd, has_reply: bool) -> Option<Vec<Arg<'_>>> {
+    let mut args = cmd
+        .params
+        .iter()
+        .map(|param| {
+            if PACKED_TYPES.contains(&&param.ty[..]) {
+                return Some(Arg::Scalar(param));
+            }
+            if param.ty.starts_with("*mut ") && has_reply {
+                return Some(Arg::Reply);
+            }
+            let elem_ty = param.ty.strip_prefix("*const ")?;
+            if !PACKED_TYPES.contains(&elem_ty) {
+                return None;
+            }
+            let len = param.len.as_ref()?;
+            if len.parse::<usize>().is_ok() {
+                return Some(Arg::Array {
+                    param,
+                    elem_ty,
+                    size: len,
+                });
+            }
+            Some(Arg::Slice { param, elem_ty })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    // each slice gives the value of its length parameter, which can't be shared
+    let mut lens = BTreeMap::new();
+    for arg in &args {
+        if let Arg::Slice { param, .. } = *arg {
+            let len = param.len.as_ref()?;
+            if lens.insert(&len[..], &param.ident[..]).is_some() {
+                return None;
+            }
+        }
+    }
+    for arg in &mut args {
+        if let Arg::Scalar(param) = *arg {
+            if let Some(slice) = lens.remove(&param.ident[..]) {
+                *arg = Arg::Len { param, slice };
+            }
+        }
+    }
+    if !lens.is_empty() {
+        return None;
+    }
+
+    let mixed = args.iter().any(Arg::is_64_bit)
+        && args
+            .iter()
+            .any(|arg| !arg.is_64_bit() && !matches!(arg, Arg::Reply));
+    if mixed && !DOUBLES_FIRST.contains(&&cmd.proto.ident[..]) {
+        return None;
+    }
+    match PIXEL_STORE_FIELDS
+        .iter()
+        .find(|&&(name, _)| name == cmd.proto.ident)
+    {
+        Some(&(_, fields)) => args.extend(fields.iter().map(|&field| Arg::PixelStore(field))),
+        None if has_reply
+            && cmd.params.iter().any(|p| p.ident == "format")
+            && cmd.params.iter().any(|p| p.ident == "type_") =>
+        {
+            return None
+        },
+        None => (),
+    }
+    Some(args)
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &["std::convert::TryFrom", "std::fmt", "std::os::raw"];
+
+/// Creates the minor opcodes of the GLX requests, and the functions that build a request.
+fn write_request_fns<W>(dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// The minor opcode of the `glXRender` request.
+        #[allow(dead_code)]
+        pub const X_GLX_RENDER: u8 = 1;
+        /// The minor opcode of the `glXVendorPrivate` request.
+        #[allow(dead_code)]
+        pub const X_GLX_VENDOR_PRIVATE: u8 = 16;
+        /// The minor opcode of the `glXVendorPrivateWithReply` request.
+        #[allow(dead_code)]
+        pub const X_GLX_VENDOR_PRIVATE_WITH_REPLY: u8 = 17;
+
+        /// Converts the length of a slice to the type of a length parameter.
+        #[allow(dead_code)]
+        #[inline]
+        fn slice_len<T>(len: usize) -> T
+        where
+            T: __gl_imports::TryFrom<usize>,
+            T::Error: __gl_imports::fmt::Debug,
+        {{
+            T::try_from(len).expect("the slice is too long")
+        }}
+
+        /// Pads `bytes` with zeros to a multiple of four bytes.
+        #[allow(dead_code)]
+        fn pad(bytes: &mut Vec<u8>) {{
+            let len = bytes.len().next_multiple_of(4);
+            bytes.resize(len, 0);
+        }}
+
+        /// Builds an X request for the GLX extension, with the words of `header` after the length,
+        /// followed by `data`.
+        #[allow(dead_code)]
+        fn request(major_opcode: u8, minor_opcode: u8, header: &[u32], data: &[u8]) -> Vec<u8> {{
+            let mut bytes = vec![major_opcode, minor_opcode, 0, 0];
+            for word in header {{
+                bytes.extend_from_slice(&word.to_ne_bytes());
+            }}
+            bytes.extend_from_slice(data);
+            pad(&mut bytes);
+            let len: u16 = __gl_imports::TryFrom::try_from(bytes.len() / 4).expect("the request is too long");
+            bytes[2..4].copy_from_slice(&len.to_ne_bytes());
+            bytes
+        }}
+    "#
+    )
+}
+
+/// Generates the statements that pack the arguments into the vector `args`, with the 64-bit
+/// arguments first.
+fn gen_packing(args: &[Arg]) -> String {
+    let mut body = match args.iter().all(|arg| matches!(arg, Arg::Reply)) {
+        true => String::from("let args: Vec<u8> = Vec::new();\n"),
+        false => String::from("let mut args: Vec<u8> = Vec::new();\n"),
+    };
+    let (doubles, others): (Vec<_>, Vec<_>) = args.iter().partition(|arg| arg.is_64_bit());
+    for arg in doubles.into_iter().chain(others) {
+        match *arg {
+            Arg::Scalar(param) => {
+                body += &format!("args.extend_from_slice(&{}.to_ne_bytes());\n", param.ident);
+            },
+            Arg::Len { param, slice } => {
+                body += &format!(
+                    "let {ident}: {ty} = slice_len({slice}.len());\n\
+                    args.extend_from_slice(&{ident}.to_ne_bytes());\n",
+                    ident = param.ident,
+                    ty = param.ty,
+                );
+            },
+            Arg::Array { param, .. } | Arg::Slice { param, .. } => {
+                body += &format!(
+                    "for value in {}.iter() {{ args.extend_from_slice(&value.to_ne_bytes()); }}\n",
+                    param.ident
+                );
+            },
+            Arg::PixelStore(field) => {
+                body += &format!("args.extend_from_slice(&{field}.to_ne_bytes());\n");
+            },
+            Arg::Reply => (),
+        }
+    }
+    body
+}
+
+/// Generates the parameters of the encoder of a command.
+fn gen_params(args: &[Arg]) -> Vec<String> {
+    args.iter()
+        .filter_map(|arg| match *arg {
+            Arg::Scalar(param) => Some(format!("{}: {}", param.ident, param.ty)),
+            Arg::Array {
+                param,
+                elem_ty,
+                size,
+            } => Some(format!("{}: &[{elem_ty}; {size}]", param.ident)),
+            Arg::Slice { param, elem_ty } => Some(format!("{}: &[{elem_ty}]", param.ident)),
+            Arg::PixelStore(field) => Some(format!("{field}: types::GLboolean")),
+            Arg::Len { .. } | Arg::Reply => None,
+        })
+        .collect()
+}
+
+/// Creates the `RenderCommands` structure, with a method for each render command.
+fn write_render_commands<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// A batch of render commands, which are sent together in a `glXRender` request.
+        ///
+        /// Each method appends a command, with its arguments in the byte order of the client.
+        #[derive(Clone, Debug, Default, PartialEq, Eq)]
+        pub struct RenderCommands {{
+            bytes: Vec<u8>,
+        }}
+
+        #[allow(dead_code)]
+        impl RenderCommands {{
+            /// Creates an empty batch.
+            pub fn new() -> RenderCommands {{
+                RenderCommands::default()
+            }}
+
+            /// Returns the encoded commands.
+            pub fn as_bytes(&self) -> &[u8] {{
+                &self.bytes
+            }}
+
+            /// Returns `true` if no command was added since the last call to `clear`.
+            pub fn is_empty(&self) -> bool {{
+                self.bytes.is_empty()
+            }}
+
+            /// Removes all the commands.
+            pub fn clear(&mut self) {{
+                self.bytes.clear();
+            }}
+
+            /// Returns the `glXRender` request that executes the commands in the context
+            /// `context_tag`, where `major_opcode` is the opcode of the GLX extension.
+            pub fn request(&self, major_opcode: u8, context_tag: u32) -> Vec<u8> {{
+                request(major_opcode, X_GLX_RENDER, &[context_tag], &self.bytes)
+            }}
+
+            fn command(&mut self, opcode: u16, args: &[u8]) -> &mut RenderCommands {{
+                let len = (4 + args.len()).next_multiple_of(4);
+                let len: u16 = __gl_imports::TryFrom::try_from(len)
+                    .expect("the command is too long for a render request");
+                self.bytes.extend_from_slice(&len.to_ne_bytes());
+                self.bytes.extend_from_slice(&opcode.to_ne_bytes());
+                self.bytes.extend_from_slice(args);
+                pad(&mut self.bytes);
+                self
+            }}
+    "#
+    )?;
+
+    let requests = requests(registry);
+    for cmd in &registry.cmds {
+        let opcode = match requests.get(&cmd.proto.ident[..]) {
+            Some(&Request::Render(opcode)) => opcode,
+            _ => continue,
+        };
+        let args = match args(cmd, false) {
+            Some(args) => args,
+            None => continue,
+        };
+        writeln!(
+            dest,
+            "/// Appends `{symbol}`, with the opcode {opcode}.
+            #[allow(non_snake_case, clippy::too_many_arguments)]
+            pub fn {name}(&mut self, {params}) -> &mut RenderCommands {{
+                {packing}
+                self.command({opcode}, &args)
+            }}",
+            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
+            name = cmd.proto.ident,
+            params = gen_params(&args).join(", "),
+            packing = gen_packing(&args),
+        )?;
+    }
+
+    writeln!(dest, "}}")
+}
+
+/// Creates a function for each single and vendor private request.
+fn write_requests<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    let requests = requests(registry);
+    for cmd in &registry.cmds {
+        let has_reply =
+            cmd.proto.ty != "()" || cmd.params.iter().any(|p| p.ty.starts_with("*mut "));
+        let (kind, request) = match requests.get(&cmd.proto.ident[..]) {
+            Some(&Request::Single(opcode)) => (
+                format!("the single request {opcode}"),
+                format!("request(major_opcode, {opcode}, &[context_tag], &args)"),
+            ),
+            Some(&Request::Vendor(opcode)) => {
+                let minor_opcode = match has_reply {
+                    true => "X_GLX_VENDOR_PRIVATE_WITH_REPLY",
+                    false => "X_GLX_VENDOR_PRIVATE",
+                };
+                (
+                    format!("the vendor private request {opcode}"),
+                    format!(
+                        "request(major_opcode, {minor_opcode}, &[{opcode}, context_tag], &args)"
+                    ),
+                )
+            },
+            _ => continue,
+        };
+        let args = match args(cmd, has_reply) {
+            Some(args) => args,
+            None => continue,
+        };
+        let mut params = vec![
+            "major_opcode: u8".to_string(),
+            "context_tag: u32".to_string(),
+        ];
+        params.extend(gen_params(&args));
+        writeln!(
+            dest,
+            "/// Returns {kind}, which executes `{symbol}` in the context `context_tag`.
+            #[allow(non_snake_case, dead_code, clippy::too_many_arguments)]
+            pub fn {name}({params}) -> Vec<u8> {{
+                {packing}
+                {request}
+            }}",
+            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
+            name = cmd.proto.ident,
+            params = params.join(", "),
+            packing = gen_packing(&args),
+        )?;
+    }
+
+    Ok(())
+}
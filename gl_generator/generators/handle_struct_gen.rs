@@ -0,0 +1,177 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::{Binding, Registry};
+use std::collections::BTreeMap;
+use std::io;
+
+/// Generates the struct of `StructGenerator`, where the object names of each class given by the
+/// registry, such as buffers or textures, have their own type.
+///
+/// The handle types are `#[repr(transparent)]` newtypes in the `types` module, for example
+/// `types::Buffer(types::GLuint)`, so they are passed to the commands as they are.
+#[allow(missing_copy_implementations)]
+pub struct HandleStructGenerator;
+
+impl super::Generator for HandleStructGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let handles = handle_types(registry);
+        let registry = &with_handles(registry, &handles);
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        write_type_aliases(registry, &handles, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        super::struct_gen::write_fnptr_struct_def(dest)?;
+        super::gen_missing_fns(registry, dest)?;
+        super::gen_load_report(registry, dest)?;
+        super::struct_gen::write_struct(&ctx, dest)?;
+        super::struct_gen::write_impl(&ctx, dest)?;
+        Ok(())
+    }
+}
+
+/// A handle type, generated for a class of objects.
+struct Handle {
+    /// The name of the type, for example `Buffer`.
+    ident: String,
+    /// The type of the object names, for example `types::GLuint`.
+    ty: String,
+}
+
+/// Splits the type of a binding into its pointer prefix, such as `*mut `, and the type it points
+/// to.
+fn split_pointer(ty: &str) -> (&str, &str) {
+    let mut base = ty;
+    while let Some(rest) = base
+        .strip_prefix("*const ")
+        .or_else(|| base.strip_prefix("*mut "))
+    {
+        base = rest;
+    }
+    (&ty[..ty.len() - base.len()], base)
+}
+
+/// Converts a class to the name of its handle type, for example `"vertex array"` to
+/// `VertexArray`.
+fn handle_ident(class: &str) -> String {
+    class
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word[..1].to_ascii_uppercase() + &word[1..])
+        .collect()
+}
+
+/// Returns the classes of the parameters and return values of the commands, with their handle
+/// type. The type of the object names is taken from the first binding of each class whose type
+/// is defined in the `types` module.
+fn handle_types(registry: &Registry) -> BTreeMap<&str, Handle> {
+    let mut handles = BTreeMap::new();
+    for cmd in &registry.cmds {
+        for binding in Some(&cmd.proto).into_iter().chain(cmd.params.iter()) {
+            let ty = split_pointer(&binding.ty).1;
+            match binding.class {
+                Some(ref class) if ty.starts_with("types::") => {
+                    handles.entry(&class[..]).or_insert_with(|| Handle {
+                        ident: handle_ident(class),
+                        ty: ty.to_string(),
+                    });
+                },
+                _ => (),
+            }
+        }
+    }
+    handles
+}
+
+/// Returns a copy of the registry where the bindings of each class use its handle type. Bindings
+/// whose type differs from the type of the handle keep their type.
+fn with_handles(registry: &Registry, handles: &BTreeMap<&str, Handle>) -> Registry {
+    let with_handle = |binding: &Binding| {
+        let mut binding = binding.clone();
+        let handle = binding
+            .class
+            .as_ref()
+            .and_then(|class| handles.get(&class[..]));
+        if let Some(handle) = handle {
+            let (pointer, ty) = split_pointer(&binding.ty);
+            if ty == handle.ty {
+                binding.ty = format!("{pointer}types::{}", handle.ident).into();
+            }
+        }
+        binding
+    };
+
+    let mut registry = registry.clone();
+    registry.cmds = registry
+        .cmds
+        .iter()
+        .map(|cmd| {
+            let mut cmd = cmd.clone();
+            cmd.proto = with_handle(&cmd.proto);
+            cmd.params = cmd.params.iter().map(with_handle).collect();
+            cmd
+        })
+        .collect();
+    registry
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::fmt",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::process",
+    "std::sync::RwLock",
+];
+
+/// Creates a `types` module which contains all the type aliases, and the handle types.
+///
+/// See also `generators::gen_types`.
+fn write_type_aliases<W>(
+    registry: &Registry,
+    handles: &BTreeMap<&str, Handle>,
+    dest: &mut W,
+) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        pub mod types {{
+            #![allow(non_camel_case_types, non_snake_case, dead_code, missing_copy_implementations)]
+    "#
+    )?;
+
+    super::gen_types(registry.api, dest)?;
+
+    for (class, handle) in handles {
+        writeln!(
+            dest,
+            "/// The name of a {class} object.
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            pub struct {ident}(pub {ty});",
+            ident = handle.ident,
+            ty = handle.ty.trim_start_matches("types::"),
+        )?;
+    }
+
+    writeln!(dest, "}}")
+}
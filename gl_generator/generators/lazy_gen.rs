@@ -0,0 +1,366 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::Registry;
+use std::io;
+
+/// Generates bindings where each command is loaded the first time it is called.
+///
+/// Instead of looking up every symbol in `load_with`, the loader is stored and each command
+/// resolves its own pointer on first use, then caches it atomically.
+#[allow(missing_copy_implementations)]
+pub struct LazyGenerator;
+
+impl super::Generator for LazyGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        super::global_gen::write_metaloadfn(dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        write_fns(registry, dest)?;
+        write_lazy_fn_ptr_def(dest)?;
+        write_ptrs(registry, dest)?;
+        write_fn_mods(registry, dest)?;
+        super::gen_missing_fns(registry, dest)?;
+        super::gen_load_report(registry, dest)?;
+        write_load_fn(registry, dest)?;
+        Ok(())
+    }
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::boxed::Box",
+    "std::fmt",
+    "std::marker::PhantomData",
+    "std::mem",
+    "std::os::raw",
+    "std::process",
+    "std::ptr",
+    "std::sync::atomic::{AtomicPtr, Ordering}",
+    "std::sync::RwLock",
+];
+
+/// Creates the functions corresponding to the GL commands.
+///
+/// The function resolves the corresponding `LazyFnPtr` stored in the `storage` module created
+///  by `write_ptrs`, then calls it.
+fn write_fns<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    for cmd in &registry.cmds {
+        write!(dest, "{}", super::gen_cmd_doc(registry, cmd))?;
+        writeln!(
+            dest,
+            "#[allow(non_snake_case, unused_variables, dead_code)] #[inline]
+            pub unsafe fn {name}({params}) -> {return_suffix} {{ \
+                (storage::{name}.get(&missing::info::{name}, missing::{name}))({idents}) \
+            }}",
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+            idents = super::gen_parameters(cmd, true, false).join(", "),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Creates the `LazyFnPtr` structure, which contains the store for a single binding, and the
+/// `LOADER` that it resolves itself with.
+///
+/// A `LazyFnPtr` holds the address of the function to call in an `AtomicPtr`, which is null until
+/// the command is resolved. If the symbol was not found, the address of the `missing` stub is
+/// stored instead.
+fn write_lazy_fn_ptr_def<W>(dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        type Loader = __gl_imports::Box<dyn Fn(&'static str) -> *const __gl_imports::raw::c_void + Send + Sync>;
+
+        /// The loader passed to `load_with`, used to resolve each command on first call.
+        static LOADER: __gl_imports::RwLock<Option<Loader>> = __gl_imports::RwLock::new(None);
+
+        /// A function pointer that is looked up the first time it is needed.
+        #[allow(missing_copy_implementations)]
+        pub struct LazyFnPtr<F> {{
+            /// The address of the function to call, or null if the command has not been resolved
+            /// yet.
+            ptr: __gl_imports::AtomicPtr<__gl_imports::raw::c_void>,
+            f: __gl_imports::PhantomData<F>,
+        }}
+
+        #[allow(dead_code)]
+        impl<F: Copy> LazyFnPtr<F> {{
+            const fn new() -> LazyFnPtr<F> {{
+                LazyFnPtr {{
+                    ptr: __gl_imports::AtomicPtr::new(__gl_imports::ptr::null_mut()),
+                    f: __gl_imports::PhantomData,
+                }}
+            }}
+
+            /// Returns the function pointer to call, resolving it through `LOADER` if needed.
+            ///
+            /// `F` must be the type of `missing`, which matches the signature of the command.
+            #[inline]
+            fn get(&self, info: &MissingFn, missing: F) -> F {{
+                let ptr = self.current(info, missing);
+                unsafe {{ __gl_imports::mem::transmute_copy::<*mut __gl_imports::raw::c_void, F>(&ptr) }}
+            }}
+
+            /// Returns the stored address, resolving it through `LOADER` if needed.
+            #[inline]
+            fn current(&self, info: &MissingFn, missing: F) -> *mut __gl_imports::raw::c_void {{
+                let ptr = self.ptr.load(__gl_imports::Ordering::Acquire);
+                if ptr.is_null() {{
+                    self.resolve(info, missing)
+                }} else {{
+                    ptr
+                }}
+            }}
+
+            #[cold]
+            #[inline(never)]
+            fn resolve(&self, info: &MissingFn, missing: F) -> *mut __gl_imports::raw::c_void {{
+                // The lock is held until the pointer is stored, so that `load_with` can't reset
+                // it in between and leave a pointer of the previous loader.
+                let loader = match LOADER.read() {{
+                    Ok(loader) => loader,
+                    Err(poisoned) => poisoned.into_inner(),
+                }};
+                let ptr = match *loader {{
+                    Some(ref loadfn) => metaloadfn(&mut |symbol| loadfn(symbol), info.symbol, info.fallbacks).0,
+                    None => __gl_imports::ptr::null(),
+                }};
+                let new = Self::address(ptr, missing);
+                match self.ptr.compare_exchange(
+                    __gl_imports::ptr::null_mut(),
+                    new,
+                    __gl_imports::Ordering::AcqRel,
+                    __gl_imports::Ordering::Acquire,
+                ) {{
+                    Ok(_) => new,
+                    // another thread resolved it first
+                    Err(current) => current,
+                }}
+            }}
+
+            /// Returns the address to store for a load attempt, which is the one of `missing` if
+            /// the symbol was not found.
+            fn address(ptr: *const __gl_imports::raw::c_void, missing: F) -> *mut __gl_imports::raw::c_void {{
+                if ptr.is_null() {{
+                    unsafe {{ __gl_imports::mem::transmute_copy::<F, *mut __gl_imports::raw::c_void>(&missing) }}
+                }} else {{
+                    ptr as *mut __gl_imports::raw::c_void
+                }}
+            }}
+
+            /// Looks up the command with `loadfn`, and returns the symbol that was found.
+            fn load(
+                &self,
+                loadfn: &mut dyn FnMut(&'static str) -> *const __gl_imports::raw::c_void,
+                info: &MissingFn,
+                missing: F,
+            ) -> Option<&'static str> {{
+                let (ptr, resolved) = metaloadfn(loadfn, info.symbol, info.fallbacks);
+                self.set(ptr, missing);
+                resolved
+            }}
+
+            /// Stores the result of a load attempt, using `missing` if the symbol was not found.
+            fn set(&self, ptr: *const __gl_imports::raw::c_void, missing: F) {{
+                self.ptr.store(Self::address(ptr, missing), __gl_imports::Ordering::Release);
+            }}
+
+            /// Forgets the resolved pointer, so that it is looked up again on the next call.
+            fn reset(&self) {{
+                self.ptr.store(__gl_imports::ptr::null_mut(), __gl_imports::Ordering::Release);
+            }}
+
+            /// Returns `true` if the function can be called, resolving it if needed.
+            fn is_loaded(&self, info: &MissingFn, missing: F) -> bool {{
+                self.current(info, missing) != Self::address(__gl_imports::ptr::null(), missing)
+            }}
+        }}
+    "#
+    )
+}
+
+/// Creates a `storage` module which contains a static `LazyFnPtr` per GL command in the registry.
+fn write_ptrs<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        "mod storage {{
+            #![allow(non_upper_case_globals)]
+            #![allow(unused_imports)]
+            #![allow(clippy::type_complexity)]
+            use super::__gl_imports;
+            use super::types;
+            use super::LazyFnPtr;"
+    )?;
+
+    for c in &registry.cmds {
+        writeln!(
+            dest,
+            "pub static {name}: LazyFnPtr<{fn_ty}> = LazyFnPtr::new();",
+            name = c.proto.ident,
+            fn_ty = super::gen_fn_ptr_type(c),
+        )?;
+    }
+
+    writeln!(dest, "}}")
+}
+
+/// Creates one module for each GL command.
+///
+/// Each module contains `is_loaded` and `load_with` which interact with the `storage` module
+///  created by `write_ptrs`. Unlike the top-level `load_with`, the `load_with` of a module loads
+///  its command immediately.
+fn write_fn_mods<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    for c in &registry.cmds {
+        let fnname = &c.proto.ident[..];
+
+        writeln!(
+            dest,
+            r##"
+            #[allow(non_snake_case)]
+            pub mod {fnname} {{
+                use super::{{storage, metaloadfn, missing}};
+                use super::__gl_imports::raw;
+
+                #[inline]
+                #[allow(dead_code)]
+                pub fn is_loaded() -> bool {{
+                    storage::{fnname}.is_loaded(&missing::info::{fnname}, missing::{fnname})
+                }}
+
+                #[allow(dead_code)]
+                pub fn load_with<F>(mut loadfn: F) where F: FnMut(&'static str) -> *const raw::c_void {{
+                    let info = &missing::info::{fnname};
+                    storage::{fnname}.set(
+                        metaloadfn(&mut loadfn, info.symbol, info.fallbacks).0,
+                        missing::{fnname},
+                    );
+                }}
+            }}
+        "##
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Creates the `load_with` and `load_with_report` functions.
+///
+/// `load_with` stores the loader and resets each pointer in the `storage` module created by
+/// `write_ptrs`. `load_with_report` then resolves each of them.
+fn write_load_fn<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        "
+        /// Sets the function used to look up OpenGL symbols. This allows for the use of
+        /// functions like `glfwGetProcAddress` or `SDL_GL_GetProcAddress`.
+        ///
+        /// No symbol is looked up here: each function is resolved the first time it is called,
+        /// or when its `is_loaded` is called. Functions that were already resolved with a
+        /// previous loader are looked up again.
+        ///
+        /// Because it is kept and called later, possibly from another thread, the loader must be
+        /// `Fn + Send + Sync + 'static`, unlike the `FnMut` loaders of the other generators. A
+        /// loader that borrows a context, such as a window, does not compile: move what it needs
+        /// into the closure, or load the functions that need it eagerly with the `load_with` of
+        /// their module, which takes any `FnMut`.
+        ///
+        /// The loader must not call back into these bindings.
+        /// ~~~ignore
+        /// gl::load_with(move |s| glfw.get_proc_address(s));
+        /// ~~~
+        #[allow(dead_code)]
+        pub fn load_with<F>(loadfn: F)
+            where F: Fn(&'static str) -> *const __gl_imports::raw::c_void + Send + Sync + 'static {{
+            {{
+                let mut loader = match LOADER.write() {{
+                    Ok(loader) => loader,
+                    Err(poisoned) => poisoned.into_inner(),
+                }};
+                *loader = Some(__gl_imports::Box::new(loadfn));
+            }}
+    "
+    )?;
+
+    for c in &registry.cmds {
+        writeln!(
+            dest,
+            "storage::{cmd_name}.reset();",
+            cmd_name = &c.proto.ident[..]
+        )?;
+    }
+
+    writeln!(
+        dest,
+        "}}
+
+        /// Same as `load_with`, but also looks up every function immediately, and returns a report
+        /// of the symbol that was resolved for each command.
+        /// ~~~ignore
+        /// let report = gl::load_with_report(move |s| glfw.get_proc_address(s));
+        /// println!(\"{{}}\", report);
+        /// ~~~
+        #[allow(dead_code)]
+        pub fn load_with_report<F>(loadfn: F) -> LoadReport
+            where F: Fn(&'static str) -> *const __gl_imports::raw::c_void + Send + Sync + 'static {{
+            load_with(loadfn);
+            let loader = match LOADER.read() {{
+                Ok(loader) => loader,
+                Err(poisoned) => poisoned.into_inner(),
+            }};
+            let mut loadfn = |symbol| match *loader {{
+                Some(ref loadfn) => loadfn(symbol),
+                None => __gl_imports::ptr::null(),
+            }};
+            let mut report = LoadReport::with_capacity({len});",
+        len = registry.cmds.len(),
+    )?;
+
+    for c in &registry.cmds {
+        writeln!(
+            dest,
+            "report.push(
+                &missing::info::{name},
+                storage::{name}.load(&mut loadfn, &missing::info::{name}, missing::{name}),
+            );",
+            name = &c.proto.ident[..]
+        )?;
+    }
+
+    writeln!(dest, "report }}")
+}
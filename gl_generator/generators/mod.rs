@@ -12,15 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use registry::{Cmd, Enum, Registry};
+use registry::{Binding, Cmd, Enum, Group, Origin, Registry};
+use std::collections::{BTreeMap, BTreeSet};
 use std::io;
 use Api;
 
+pub mod apitrace_gen;
+pub mod checked_struct_gen;
 pub mod debug_struct_gen;
+pub mod enum_names_gen;
 pub mod global_gen;
+pub mod glx_protocol_gen;
+pub mod handle_struct_gen;
+pub mod lazy_gen;
+pub mod null_gen;
+pub mod object_tracking_gen;
+pub mod options;
+mod ref_pages;
+pub mod sections;
+pub mod slice_struct_gen;
 pub mod static_gen;
 pub mod static_struct_gen;
 pub mod struct_gen;
+pub mod thread_local_gen;
+#[cfg(feature = "tokens")]
+pub mod tokens;
+pub mod trace_gen;
+pub mod tracing_gen;
+pub mod trait_gen;
+pub mod validation_gen;
+
+pub use self::options::{GeneratorOptions, InlinePolicy, NameKind, Naming, Visibility};
+pub use self::sections::{GenContext, StructGeneratorWith, StructSections};
 
 /// Trait for a bindings generator.
 ///
@@ -31,6 +54,23 @@ pub trait Generator {
     fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
     where
         W: io::Write;
+
+    /// Builds the GL bindings with `options`.
+    ///
+    /// The generators which don't support options ignore them, and write the same bindings as
+    /// `write`.
+    fn write_with_options<W>(
+        &self,
+        registry: &Registry,
+        options: &GeneratorOptions,
+        dest: &mut W,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let _ = options;
+        self.write(registry, dest)
+    }
 }
 
 pub fn gen_struct_name(api: Api) -> &'static str {
@@ -51,9 +91,33 @@ pub fn gen_enum_item<W>(enm: &Enum, types_prefix: &str, dest: &mut W) -> io::Res
 where
     W: io::Write,
 {
-    writeln!(dest,
-        "#[allow(dead_code, non_upper_case_globals)] pub const {ident}: {types_prefix}{ty} = {value}{cast_suffix};",
-        ident = enm.ident,
+    gen_enum_item_with_options(
+        enm,
+        Api::Gl,
+        types_prefix,
+        &GeneratorOptions::default(),
+        dest,
+    )
+}
+
+/// Same as `gen_enum_item`, with the name, visibility and attributes of `options`. `api` gives
+/// the prefix of the name when `options` keeps it.
+pub fn gen_enum_item_with_options<W>(
+    enm: &Enum,
+    api: Api,
+    types_prefix: &str,
+    options: &GeneratorOptions,
+    dest: &mut W,
+) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        "{attrs} {vis} const {ident}: {types_prefix}{ty} = {value}{cast_suffix};",
+        attrs = options.const_attrs(),
+        vis = options.vis(),
+        ident = options.enum_name(api, &enm.ident),
         types_prefix = if enm.ty == "&str" { "" } else { types_prefix },
         ty = enm.ty,
         value = enm.value,
@@ -88,6 +152,29 @@ where
     Ok(())
 }
 
+/// Returns the definition of `ty` when it is one of the `types::` aliases written by `gen_types`,
+/// or `ty` itself otherwise.
+pub(super) fn resolve_type(ty: &str) -> &str {
+    const TEMPLATES: [&str; 4] = [
+        include_str!("templates/types/gl.rs"),
+        include_str!("templates/types/egl.rs"),
+        include_str!("templates/types/glx.rs"),
+        include_str!("templates/types/wgl.rs"),
+    ];
+
+    let alias = match ty.strip_prefix("types::") {
+        Some(alias) => alias,
+        None => return ty,
+    };
+    let prefix = format!("pub type {alias} = ");
+    TEMPLATES
+        .iter()
+        .flat_map(|template| template.lines())
+        .find_map(|line| line.trim_start().strip_prefix(&prefix[..]))
+        .map(|definition| definition.split(';').next().unwrap().trim_end())
+        .unwrap_or(ty)
+}
+
 /// Generates the list of Rust `Arg`s that a `Cmd` requires.
 pub fn gen_parameters(cmd: &Cmd, with_idents: bool, with_types: bool) -> Vec<String> {
     cmd.params
@@ -107,6 +194,279 @@ pub fn gen_parameters(cmd: &Cmd, with_idents: bool, with_types: bool) -> Vec<Str
         .collect()
 }
 
+/// Generates the type of the function pointer that stores a `Cmd`.
+///
+/// The `-unwind` ABI is used so that the stubs created by `gen_missing_fns` are allowed to panic.
+///
+/// Example result: `extern "system-unwind" fn(types::GLbitfield) -> ()`.
+pub fn gen_fn_ptr_type(cmd: &Cmd) -> String {
+    format!(
+        "extern \"system-unwind\" fn({typed_params}) -> {return_suffix}",
+        typed_params = gen_parameters(cmd, false, true).join(", "),
+        return_suffix = cmd.proto.ty,
+    )
+}
+
+/// Creates the items that handle calls to commands that could not be loaded.
+///
+/// This writes a `MissingFn` description type, a `MissingFnPolicy` that can be changed at runtime
+/// with `set_missing_fn_policy`, and a `missing` module which contains one stub function per GL
+/// command. Each stub has the same signature as the command it stands in for, and reports the
+/// symbol and fallbacks that were tried. The `info` submodule holds the `MissingFn` of each
+/// command, for generators that want to report a missing function before calling the stub. They
+/// pass it to `missing_fn_called`, which panics at the location of their caller.
+///
+/// The `__gl_imports` module must export `fmt`, `process` and `sync::RwLock`.
+pub fn gen_missing_fns<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// Describes a command that was called without having been loaded.
+        #[derive(Copy, Clone, Debug)]
+        pub struct MissingFn {{
+            /// The symbol that was looked up, for example `"glClear"`.
+            pub symbol: &'static str,
+            /// The fallback symbols that were tried after `symbol` could not be found.
+            pub fallbacks: &'static [&'static str],
+        }}
+
+        impl __gl_imports::fmt::Display for MissingFn {{
+            fn fmt(&self, f: &mut __gl_imports::fmt::Formatter) -> __gl_imports::fmt::Result {{
+                write!(f, "{api} function `{{}}` was not loaded", self.symbol)?;
+                if !self.fallbacks.is_empty() {{
+                    write!(f, " (also tried `{{}}`)", self.fallbacks.join("`, `"))?;
+                }}
+                Ok(())
+            }}
+        }}
+
+        /// What happens when a command that was not loaded is called.
+        #[allow(dead_code)]
+        #[derive(Copy, Clone, Debug)]
+        pub enum MissingFnPolicy {{
+            /// Panic with a message naming the command. This is the default.
+            Panic,
+            /// Print a message naming the command to stderr, then abort the process.
+            Abort,
+            /// Call the given function, which must not return.
+            Callback(fn(&MissingFn) -> !),
+        }}
+
+        static MISSING_FN_POLICY: __gl_imports::RwLock<MissingFnPolicy> =
+            __gl_imports::RwLock::new(MissingFnPolicy::Panic);
+
+        /// Sets what happens when a command that was not loaded is called.
+        #[allow(dead_code)]
+        pub fn set_missing_fn_policy(policy: MissingFnPolicy) {{
+            match MISSING_FN_POLICY.write() {{
+                Ok(mut current) => *current = policy,
+                Err(poisoned) => *poisoned.into_inner() = policy,
+            }}
+        }}
+
+        fn missing_fn_policy() -> MissingFnPolicy {{
+            match MISSING_FN_POLICY.read() {{
+                Ok(policy) => *policy,
+                Err(poisoned) => *poisoned.into_inner(),
+            }}
+        }}
+
+        /// Called by the stubs of the `missing` module.
+        #[cold]
+        #[inline(never)]
+        fn missing_fn_panic(missing: &MissingFn) -> ! {{
+            match missing_fn_policy() {{
+                MissingFnPolicy::Panic => panic!("{{}}", missing),
+                MissingFnPolicy::Abort => {{
+                    eprintln!("{{missing}}");
+                    __gl_imports::process::abort()
+                }},
+                MissingFnPolicy::Callback(callback) => callback(missing),
+            }}
+        }}
+
+        /// Called by methods that check whether a command is loaded before calling it, so that
+        /// the panic points at the call site of the method.
+        #[allow(dead_code)]
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn missing_fn_called(missing: &MissingFn) -> ! {{
+            match missing_fn_policy() {{
+                MissingFnPolicy::Panic => panic!("{{}}", missing),
+                _ => missing_fn_panic(missing),
+            }}
+        }}
+
+        mod missing {{
+            #![allow(non_snake_case, non_upper_case_globals, unused_imports, unused_variables)]
+            #![allow(clippy::too_many_arguments, clippy::unused_unit)]
+            use super::__gl_imports;
+            use super::types;
+
+            pub mod info {{
+                use super::super::MissingFn;
+        "#,
+        api = registry.api,
+    )?;
+
+    for cmd in &registry.cmds {
+        let fallbacks = match registry.aliases.get(&cmd.proto.ident) {
+            Some(fbs) => fbs
+                .iter()
+                .map(|name| format!("\"{}\"", gen_symbol_name(registry.api, name)))
+                .collect::<Vec<_>>()
+                .join(", "),
+            None => String::new(),
+        };
+        writeln!(
+            dest,
+            "pub const {name}: MissingFn = MissingFn {{ symbol: \"{symbol}\", fallbacks: &[{fallbacks}] }};",
+            name = cmd.proto.ident,
+            symbol = gen_symbol_name(registry.api, &cmd.proto.ident),
+        )?;
+    }
+
+    writeln!(dest, "}}")?;
+
+    for cmd in &registry.cmds {
+        writeln!(
+            dest,
+            "#[inline(never)]
+            pub extern \"system-unwind\" fn {name}({params}) -> {return_suffix} {{
+                super::missing_fn_panic(&info::{name})
+            }}",
+            name = cmd.proto.ident,
+            params = gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+        )?;
+    }
+
+    writeln!(dest, "}}")
+}
+
+/// Creates the `LoadReport` type returned by the `load_with_report` functions.
+///
+/// A report lists the symbol that was resolved for each command. It is built from the `MissingFn`
+/// descriptions written by `gen_missing_fns`, which must be called first.
+pub fn gen_load_report<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// The outcome of loading a single command.
+        #[allow(dead_code)]
+        #[derive(Copy, Clone, Debug)]
+        pub struct LoadedFn {{
+            /// The symbol that was looked up first, for example `"glClear"`.
+            pub symbol: &'static str,
+            /// The fallback symbols that are tried after `symbol`.
+            pub fallbacks: &'static [&'static str],
+            /// The symbol that was found, or `None` if neither `symbol` nor any of the fallbacks
+            /// could be loaded.
+            pub resolved: Option<&'static str>,
+        }}
+
+        #[allow(dead_code)]
+        impl LoadedFn {{
+            /// Returns `true` if the command was loaded.
+            #[inline]
+            pub fn is_loaded(&self) -> bool {{
+                self.resolved.is_some()
+            }}
+
+            /// Returns `true` if the command was loaded through one of its fallbacks.
+            #[inline]
+            pub fn used_fallback(&self) -> bool {{
+                match self.resolved {{
+                    Some(resolved) => resolved != self.symbol,
+                    None => false,
+                }}
+            }}
+        }}
+
+        /// Lists which commands were resolved by `load_with_report`, and through which symbol.
+        #[derive(Clone, Debug, Default)]
+        pub struct LoadReport {{
+            commands: Vec<LoadedFn>,
+        }}
+
+        #[allow(dead_code)]
+        impl LoadReport {{
+            fn with_capacity(capacity: usize) -> LoadReport {{
+                LoadReport {{ commands: Vec::with_capacity(capacity) }}
+            }}
+
+            fn push(&mut self, info: &MissingFn, resolved: Option<&'static str>) {{
+                self.commands.push(LoadedFn {{
+                    symbol: info.symbol,
+                    fallbacks: info.fallbacks,
+                    resolved,
+                }});
+            }}
+
+            /// Returns the outcome for every command, in alphabetical order.
+            pub fn commands(&self) -> &[LoadedFn] {{
+                &self.commands
+            }}
+
+            /// Returns the commands that were loaded.
+            pub fn loaded(&self) -> impl Iterator<Item = &LoadedFn> {{
+                self.commands.iter().filter(|c| c.is_loaded())
+            }}
+
+            /// Returns the commands that could not be loaded.
+            pub fn missing(&self) -> impl Iterator<Item = &LoadedFn> {{
+                self.commands.iter().filter(|c| !c.is_loaded())
+            }}
+
+            /// Returns the number of commands that were loaded.
+            pub fn loaded_count(&self) -> usize {{
+                self.loaded().count()
+            }}
+
+            /// Returns the number of commands that were loaded through one of their fallbacks.
+            pub fn fallback_count(&self) -> usize {{
+                self.commands.iter().filter(|c| c.used_fallback()).count()
+            }}
+
+            /// Returns the number of commands that could not be loaded.
+            pub fn missing_count(&self) -> usize {{
+                self.missing().count()
+            }}
+        }}
+
+        impl __gl_imports::fmt::Display for LoadReport {{
+            fn fmt(&self, f: &mut __gl_imports::fmt::Formatter) -> __gl_imports::fmt::Result {{
+                write!(
+                    f,
+                    "loaded {{}} of {{}} {api} functions ({{}} through fallbacks)",
+                    self.loaded_count(),
+                    self.commands.len(),
+                    self.fallback_count(),
+                )?;
+                for command in &self.commands {{
+                    match command.resolved {{
+                        None => write!(f, "\nmissing: {{}}", command.symbol)?,
+                        Some(resolved) if resolved != command.symbol => {{
+                            write!(f, "\nfallback: {{}} -> {{}}", command.symbol, resolved)?
+                        }},
+                        Some(_) => (),
+                    }}
+                }}
+                Ok(())
+            }}
+        }}
+        "#,
+        api = registry.api,
+    )
+}
+
 /// Generates the native symbol name of a `Cmd`.
 ///
 /// Example results: `"glClear"`, `"wglCreateContext"`, etc.
@@ -118,3 +478,426 @@ pub fn gen_symbol_name(api: Api, cmd: &str) -> String {
         Api::Egl => format!("egl{cmd}"),
     }
 }
+
+/// Generates the native name of an enum, for example `GL_COLOR_BUFFER_BIT` for
+/// `COLOR_BUFFER_BIT`.
+pub fn gen_enum_symbol_name(api: Api, ident: &str) -> String {
+    let prefix = match api {
+        Api::Gl | Api::GlCore | Api::Gles1 | Api::Gles2 | Api::Glsc2 => "GL_",
+        Api::Glx => "GLX_",
+        Api::Wgl => "WGL_",
+        Api::Egl => "EGL_",
+    };
+    match ident.strip_prefix('_') {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => format!("{prefix}{rest}"),
+        _ => format!("{prefix}{ident}"),
+    }
+}
+
+/// Returns the name of an API as written in the documentation, for example `"OpenGL ES"`.
+fn api_title(api: Api) -> &'static str {
+    match api {
+        Api::Gl | Api::GlCore => "OpenGL",
+        Api::Gles1 | Api::Gles2 => "OpenGL ES",
+        Api::Glsc2 => "OpenGL SC",
+        Api::Glx => "GLX",
+        Api::Wgl => "WGL",
+        Api::Egl => "EGL",
+    }
+}
+
+/// Returns a sentence listing the first version and the extensions that provide an item, or
+/// `None` if the registry doesn't know where it comes from.
+fn gen_origins_doc(api: Api, origins: Option<&BTreeSet<Origin>>) -> Option<String> {
+    let origins = origins?;
+    let version = origins.iter().find_map(|origin| match *origin {
+        Origin::Version(ref number) => Some(format!("{} {number}", api_title(api))),
+        Origin::Extension(_) => None,
+    });
+    let mut names = version.into_iter().collect::<Vec<_>>();
+    names.extend(origins.iter().filter_map(|origin| match *origin {
+        Origin::Version(_) => None,
+        Origin::Extension(ref name) => Some(format!("`{name}`")),
+    }));
+    let last = names.pop()?;
+    Some(match names.is_empty() {
+        true => format!("Provided by {last}."),
+        false => format!("Provided by {} and {last}.", names.join(", ")),
+    })
+}
+
+/// Returns the URL of the reference page of a command on the Khronos registry.
+///
+/// Only the commands of the core versions of OpenGL, OpenGL ES and EGL have reference pages, and
+/// some of them share the page of their family, like `glUniform` for `glUniform4fv`.
+fn gen_ref_page_url(registry: &Registry, cmd: &Cmd) -> Option<String> {
+    let origins = registry.cmd_origins.get(&cmd.proto.ident)?;
+    if !origins
+        .iter()
+        .any(|origin| matches!(*origin, Origin::Version(_)))
+    {
+        return None;
+    }
+    let name = &cmd.proto.ident[..];
+    let (dir, page) = match registry.api {
+        Api::Gl | Api::GlCore => (
+            "OpenGL-Refpages/gl4/html",
+            ref_pages::find(ref_pages::GL4, name)?,
+        ),
+        Api::Gles2 => (
+            "OpenGL-Refpages/es3/html",
+            ref_pages::find(ref_pages::ES3, name)?,
+        ),
+        Api::Egl => (
+            "EGL/sdk/docs/man/html",
+            *ref_pages::EGL.iter().find(|&&page| page == name)?,
+        ),
+        Api::Gles1 | Api::Glsc2 | Api::Glx | Api::Wgl => return None,
+    };
+    Some(format!(
+        "https://registry.khronos.org/{dir}/{}.xhtml",
+        gen_symbol_name(registry.api, page)
+    ))
+}
+
+/// Generates the documentation of the function that calls a command, as `///` lines.
+///
+/// It gives the native symbol, the first version and the extensions that provide the command, the
+/// enum groups of its parameters and return value, its aliases, and a link to its reference page.
+pub fn gen_cmd_doc(registry: &Registry, cmd: &Cmd) -> String {
+    let mut paragraphs = vec![format!(
+        "`{}`",
+        gen_symbol_name(registry.api, &cmd.proto.ident)
+    )];
+    paragraphs.extend(gen_origins_doc(
+        registry.api,
+        registry.cmd_origins.get(&cmd.proto.ident),
+    ));
+
+    let groups = cmd
+        .params
+        .iter()
+        .filter_map(|param| {
+            let group = param.group.as_ref()?;
+            Some(format!("- `{}`: `{group}`", param.ident))
+        })
+        .collect::<Vec<_>>();
+    if !groups.is_empty() {
+        paragraphs.push(format!("Parameter groups:\n\n{}", groups.join("\n")));
+    }
+    if let Some(ref group) = cmd.proto.group {
+        paragraphs.push(format!("Returns a value of the `{group}` group."));
+    }
+
+    let aliases = cmd
+        .alias
+        .iter()
+        .chain(registry.aliases.get(&cmd.proto.ident).into_iter().flatten())
+        .map(|alias| format!("`{}`", gen_symbol_name(registry.api, alias)))
+        .collect::<Vec<_>>();
+    if !aliases.is_empty() {
+        paragraphs.push(format!("Aliases: {}.", aliases.join(", ")));
+    }
+
+    if let Some(url) = gen_ref_page_url(registry, cmd) {
+        paragraphs.push(format!("Reference page: <{url}>"));
+    }
+    gen_doc_lines(&paragraphs)
+}
+
+/// Generates the documentation of the constant of an enum, as `///` lines.
+///
+/// It gives the native name, the first version and the extensions that provide the enum, the
+/// groups it belongs to, and the enum it is an alias of.
+pub fn gen_enum_doc(registry: &Registry, enm: &Enum) -> String {
+    let mut paragraphs = vec![format!(
+        "`{}`",
+        gen_enum_symbol_name(registry.api, &enm.ident)
+    )];
+    paragraphs.extend(gen_origins_doc(
+        registry.api,
+        registry.enum_origins.get(&enm.ident),
+    ));
+
+    let groups = registry
+        .groups
+        .values()
+        .filter(|group| group.enums.contains(&enm.ident))
+        .map(|group| format!("`{}`", group.ident))
+        .collect::<Vec<_>>();
+    if !groups.is_empty() {
+        paragraphs.push(format!("Groups: {}.", groups.join(", ")));
+    }
+
+    if let Some(ref alias) = enm.alias {
+        paragraphs.push(format!("Alias of `{alias}`."));
+    }
+    gen_doc_lines(&paragraphs)
+}
+
+/// Writes paragraphs as `///` lines, separated by empty lines.
+fn gen_doc_lines(paragraphs: &[String]) -> String {
+    paragraphs
+        .join("\n\n")
+        .lines()
+        .map(|line| match line.is_empty() {
+            true => "///\n".to_string(),
+            false => format!("/// {line}\n"),
+        })
+        .collect()
+}
+
+/// Suffixes of the names of commands that come from vendor extensions.
+const VENDOR_SUFFIXES: &[&str] = &[
+    "3DFX", "AMD", "ANGLE", "APPLE", "ARB", "ARM", "ATI", "EXT", "HP", "IBM", "IMG", "INGR",
+    "INTEL", "KHR", "MESA", "NV", "NVX", "OES", "OML", "OVR", "PGI", "QCOM", "REND", "S3", "SGI",
+    "SGIS", "SGIX", "SUN", "SUNX", "WIN",
+];
+
+/// Converts the name of a command to snake_case, for example `GenBuffers` to `gen_buffers`,
+/// `TexImage2D` to `tex_image_2d`, `BindBufferARB` to `bind_buffer_arb` and `TexImage3DOES` to
+/// `tex_image_3d_oes`.
+fn snake_case(name: &str) -> String {
+    let suffix = VENDOR_SUFFIXES
+        .iter()
+        .filter(|suffix| name.len() > suffix.len() && name.ends_with(*suffix))
+        .filter(|suffix| {
+            // the suffix follows a word, or a dimension like `3D`
+            let mut before = name[..name.len() - suffix.len()].chars().rev();
+            match (before.next(), before.next()) {
+                (Some('D'), Some(c)) if c.is_ascii_digit() => true,
+                (Some(c), _) => !c.is_ascii_uppercase(),
+                (None, _) => false,
+            }
+        })
+        .max_by_key(|suffix| suffix.len());
+    let (name, suffix) = match suffix {
+        Some(suffix) => (&name[..name.len() - suffix.len()], Some(suffix)),
+        None => (name, None),
+    };
+
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut result = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let prev = if i > 0 { Some(chars[i - 1]) } else { None };
+        let next = chars.get(i + 1);
+        let boundary = match prev {
+            Some(prev) if c.is_ascii_uppercase() => {
+                prev.is_ascii_lowercase()
+                    || (prev.is_ascii_uppercase() && next.is_some_and(|n| n.is_ascii_lowercase()))
+            },
+            Some(prev) if c.is_ascii_digit() && prev.is_ascii_alphabetic() => chars[i..]
+                .iter()
+                .find(|c| !c.is_ascii_digit())
+                .is_some_and(|c| c.is_ascii_uppercase()),
+            _ => false,
+        };
+        if boundary {
+            result.push('_');
+        }
+        result.push(c.to_ascii_lowercase());
+    }
+    if let Some(suffix) = suffix {
+        result.push('_');
+        result.push_str(&suffix.to_ascii_lowercase());
+    }
+    result
+}
+
+/// Parses the value of an enum, written in decimal or hexadecimal.
+fn parse_enum_value(enm: &Enum) -> Option<u32> {
+    match enm.value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => enm.value.parse().ok(),
+    }
+}
+
+/// Returns the enums of type `GLenum` or `EGLenum`, by their name without a leading underscore,
+/// as listed in the groups of the registry.
+fn enums_by_group_name(registry: &Registry) -> BTreeMap<&str, &Enum> {
+    registry
+        .enums
+        .iter()
+        .filter(|enm| enm.ty == "GLenum" || enm.ty == "EGLenum")
+        .map(|enm| (enm.ident.trim_start_matches('_'), enm))
+        .collect()
+}
+
+/// Returns the distinct values of a group and their names, in the order of the group. When
+/// several names have the same value, core names are preferred.
+fn group_values<'a>(
+    registry: &Registry,
+    group: &Group,
+    enums: &BTreeMap<&str, &'a Enum>,
+) -> Vec<(u32, &'a str)> {
+    let mut names = group
+        .enums
+        .iter()
+        .filter_map(|name| enums.get(&name[..]))
+        .filter_map(|enm| Some((parse_enum_value(enm)?, &enm.ident[..])))
+        .enumerate()
+        .collect::<Vec<_>>();
+    names.sort_by_key(|&(i, (value, name))| (value, !is_core_enum(registry, name), i));
+    let mut seen = BTreeSet::new();
+    names.retain(|&(_, (value, _))| seen.insert(value));
+    // keep the order of the group, so that bits are formatted in that order
+    names.sort_by_key(|&(i, _)| i);
+    names.into_iter().map(|(_, name)| name).collect()
+}
+
+/// Returns whether an enum is required by a version of the API, rather than only by
+/// extensions.
+fn is_core_enum(registry: &Registry, ident: &str) -> bool {
+    registry.enum_origins.get(ident).is_some_and(|origins| {
+        origins
+            .iter()
+            .any(|origin| matches!(*origin, Origin::Version(_)))
+    })
+}
+
+/// Returns the groups of the `GLenum` and `GLbitfield` parameters and return values of the
+/// commands, with the distinct values of each group and their names. Groups without any value
+/// in the registry are left out.
+fn used_enum_groups(registry: &Registry) -> BTreeMap<&str, Vec<(u32, &str)>> {
+    let enums = enums_by_group_name(registry);
+
+    let mut groups = BTreeMap::new();
+    let bindings = registry
+        .cmds
+        .iter()
+        .flat_map(|cmd| Some(&cmd.proto).into_iter().chain(cmd.params.iter()));
+    for binding in bindings {
+        if binding.ty != "types::GLenum" && binding.ty != "types::GLbitfield" {
+            continue;
+        }
+        let group = match binding.group.as_ref().and_then(|g| registry.groups.get(g)) {
+            Some(group) if !groups.contains_key(&group.ident[..]) => group,
+            _ => continue,
+        };
+        let names = group_values(registry, group, &enums);
+        if !names.is_empty() {
+            groups.insert(&group.ident[..], names);
+        }
+    }
+    groups
+}
+
+/// Generates an `enum_groups` module with a table of the values and names of each enum group
+/// used by the commands, and the `EnumArg` and `BitfieldArg` types that format a value with
+/// these names.
+///
+/// See also `generators::gen_enum_arg`.
+pub fn gen_enum_groups<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// An enum value, formatted as its name if it is known, or in hexadecimal.
+        #[derive(Clone, Copy)]
+        #[allow(dead_code)]
+        struct EnumArg {{
+            value: u32,
+            names: &'static [(u32, &'static str)],
+            prefix: &'static str,
+        }}
+
+        #[allow(dead_code)]
+        impl EnumArg {{
+            fn new(value: u32, names: &'static [(u32, &'static str)]) -> EnumArg {{
+                EnumArg {{ value, names, prefix: "" }}
+            }}
+
+            /// Formats the name with `prefix` in front of it, for example `GL_`.
+            fn with_prefix(self, prefix: &'static str) -> EnumArg {{
+                EnumArg {{ prefix, ..self }}
+            }}
+        }}
+
+        impl __gl_imports::fmt::Debug for EnumArg {{
+            fn fmt(&self, f: &mut __gl_imports::fmt::Formatter) -> __gl_imports::fmt::Result {{
+                match self.names.iter().find(|&&(value, _)| value == self.value) {{
+                    Some(&(_, name)) => write!(f, "{{}}{{name}}", self.prefix),
+                    None => write!(f, "{{:#06x}}", self.value),
+                }}
+            }}
+        }}
+
+        /// A bitfield value, formatted as the names of its bits separated by `|`. Unknown bits
+        /// are formatted in hexadecimal.
+        #[derive(Clone, Copy)]
+        #[allow(dead_code)]
+        struct BitfieldArg {{
+            value: u32,
+            names: &'static [(u32, &'static str)],
+            prefix: &'static str,
+        }}
+
+        #[allow(dead_code)]
+        impl BitfieldArg {{
+            fn new(value: u32, names: &'static [(u32, &'static str)]) -> BitfieldArg {{
+                BitfieldArg {{ value, names, prefix: "" }}
+            }}
+
+            /// Formats the names with `prefix` in front of them, for example `GL_`.
+            fn with_prefix(self, prefix: &'static str) -> BitfieldArg {{
+                BitfieldArg {{ prefix, ..self }}
+            }}
+        }}
+
+        impl __gl_imports::fmt::Debug for BitfieldArg {{
+            fn fmt(&self, f: &mut __gl_imports::fmt::Formatter) -> __gl_imports::fmt::Result {{
+                if self.value == 0 {{
+                    return f.write_str("0");
+                }}
+                let mut rest = self.value;
+                for &(bit, name) in self.names {{
+                    if bit.count_ones() == 1 && rest & bit != 0 {{
+                        if rest != self.value {{
+                            f.write_str(" | ")?;
+                        }}
+                        write!(f, "{{}}{{name}}", self.prefix)?;
+                        rest &= !bit;
+                    }}
+                }}
+                match rest {{
+                    0 => Ok(()),
+                    _ if rest == self.value => write!(f, "{{rest:#x}}"),
+                    _ => write!(f, " | {{rest:#x}}"),
+                }}
+            }}
+        }}
+
+        mod enum_groups {{
+            #![allow(non_upper_case_globals, dead_code)]
+    "#
+    )?;
+
+    for (group, names) in used_enum_groups(registry) {
+        writeln!(dest, "pub const {group}: &[(u32, &str)] = &[")?;
+        for (value, name) in names {
+            writeln!(dest, "({value:#x}, \"{name}\"),")?;
+        }
+        writeln!(dest, "];")?;
+    }
+
+    writeln!(dest, "}}")
+}
+
+/// Returns the names of the enum groups generated by `gen_enum_groups`.
+pub fn gen_enum_group_names(registry: &Registry) -> BTreeSet<&str> {
+    used_enum_groups(registry).into_keys().collect()
+}
+
+/// Returns an expression that wraps `ident`, the value of `binding`, in the `EnumArg` or
+/// `BitfieldArg` generated by `gen_enum_groups`, or `None` if it doesn't belong to one of
+/// `groups`, as returned by `gen_enum_group_names`.
+pub fn gen_enum_arg(binding: &Binding, ident: &str, groups: &BTreeSet<&str>) -> Option<String> {
+    let group = binding.group.as_ref().filter(|g| groups.contains(&g[..]))?;
+    match &binding.ty[..] {
+        "types::GLenum" => Some(format!("EnumArg::new({ident}, enum_groups::{group})")),
+        "types::GLbitfield" => Some(format!("BitfieldArg::new({ident}, enum_groups::{group})")),
+        _ => None,
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::{Cmd, Registry};
+use std::io;
+
+/// Generates a headless implementation of every command, for running tests without a driver.
+///
+/// The generated `load` function can be passed to the `load_with` of other bindings. Each
+/// command does nothing and returns a default value, except for the commands that create
+/// objects, which hand out unique names. Each command can be overridden with a closure.
+#[allow(missing_copy_implementations)]
+pub struct NullGenerator;
+
+impl super::Generator for NullGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        write_names(dest)?;
+        write_overrides(registry, dest)?;
+        write_null_fns(registry, dest)?;
+        write_override_mods(registry, dest)?;
+        write_load_fn(registry, dest)?;
+        Ok(())
+    }
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::boxed::Box",
+    "std::mem",
+    "std::os::raw",
+    "std::ptr",
+    "std::slice",
+    "std::sync::atomic::{AtomicU32, Ordering}",
+    "std::sync::{RwLock, RwLockReadGuard}",
+];
+
+/// Creates the counter that object names are taken from.
+fn write_names<W>(dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        static NEXT_NAME: __gl_imports::AtomicU32 = __gl_imports::AtomicU32::new(1);
+
+        /// Returns `count` consecutive object names that have never been returned before.
+        #[allow(dead_code)]
+        fn next_names(count: i32) -> u32 {{
+            NEXT_NAME.fetch_add(count.max(0) as u32, __gl_imports::Ordering::Relaxed)
+        }}
+
+        /// Writes `n` new object names to `names`.
+        #[allow(dead_code)]
+        unsafe fn gen_names(n: i32, names: *mut u32) {{
+            if n <= 0 || names.is_null() {{
+                return;
+            }}
+            let first = next_names(n);
+            let names = __gl_imports::slice::from_raw_parts_mut(names, n as usize);
+            for (name, value) in names.iter_mut().zip(first..) {{
+                *name = value;
+            }}
+        }}
+
+        #[allow(dead_code)]
+        fn read_override<'a, T>(lock: &'a __gl_imports::RwLock<T>) -> __gl_imports::RwLockReadGuard<'a, T> {{
+            match lock.read() {{
+                Ok(guard) => guard,
+                Err(poisoned) => poisoned.into_inner(),
+            }}
+        }}
+    "#
+    )
+}
+
+/// Generates the type of the closure that overrides a `Cmd`.
+fn gen_override_type(cmd: &Cmd) -> String {
+    format!(
+        "__gl_imports::Box<dyn Fn({params}) -> {return_suffix} + Send + Sync>",
+        params = super::gen_parameters(cmd, false, true).join(", "),
+        return_suffix = cmd.proto.ty,
+    )
+}
+
+/// Creates an `overrides` module which contains a static per command that stores its override.
+fn write_overrides<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        "mod overrides {{
+            #![allow(non_upper_case_globals, unused_imports, clippy::type_complexity)]
+            use super::__gl_imports;
+            use super::types;"
+    )?;
+
+    for cmd in &registry.cmds {
+        writeln!(
+            dest,
+            "pub static {name}: __gl_imports::RwLock<Option<{ty}>> = __gl_imports::RwLock::new(None);",
+            name = cmd.proto.ident,
+            ty = gen_override_type(cmd),
+        )?;
+    }
+
+    writeln!(dest, "}}")
+}
+
+/// Generates the default behaviour of a `Cmd` that has not been overridden.
+fn gen_default_body(cmd: &Cmd) -> String {
+    let name = &cmd.proto.ident[..];
+    let params = &cmd.params;
+    let creates = name.starts_with("Gen") || name.starts_with("Create");
+
+    if creates && params.len() == 2 && params[1].ty == "*mut types::GLuint" {
+        // `GenBuffers(n, buffers)`
+        format!("gen_names({}, {})", params[0].ident, params[1].ident)
+    } else if creates
+        && params.len() == 3
+        && params[1].ty == "types::GLsizei"
+        && params[2].ty == "*mut types::GLuint"
+    {
+        // `CreateTextures(target, n, textures)`
+        format!("gen_names({}, {})", params[1].ident, params[2].ident)
+    } else if creates && cmd.proto.ty == "types::GLuint" {
+        // `GenLists(range)` reserves `range` consecutive names
+        if name == "GenLists" {
+            format!("next_names({})", params[0].ident)
+        } else {
+            "next_names(1)".to_string()
+        }
+    } else if name == "GetString" || name == "GetStringi" {
+        "b\"\\0\".as_ptr()".to_string()
+    } else if name.starts_with("CheckFramebufferStatus")
+        || name.starts_with("CheckNamedFramebufferStatus")
+    {
+        // FRAMEBUFFER_COMPLETE
+        "0x8CD5".to_string()
+    } else if cmd.proto.ty == "()" {
+        String::new()
+    } else {
+        "__gl_imports::mem::zeroed()".to_string()
+    }
+}
+
+/// Creates a `null_fns` module which contains the implementation of each command.
+fn write_null_fns<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        "mod null_fns {{
+            #![allow(non_snake_case, unused_variables, unused_imports, unused_unsafe)]
+            use super::__gl_imports;
+            use super::types;
+            use super::{{gen_names, next_names, overrides, read_override}};"
+    )?;
+
+    for cmd in &registry.cmds {
+        writeln!(
+            dest,
+            "pub extern \"system-unwind\" fn {name}({params}) -> {return_suffix} {{
+                if let Some(ref null_override) = *read_override(&overrides::{name}) {{
+                    return null_override({idents});
+                }}
+                unsafe {{ {default} }}
+            }}",
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+            idents = super::gen_parameters(cmd, true, false).join(", "),
+            default = gen_default_body(cmd),
+        )?;
+    }
+
+    writeln!(dest, "}}")
+}
+
+/// Creates one module for each GL command.
+///
+/// Each module contains `set` and `reset`, which change the override stored in the `overrides`
+///  module created by `write_overrides`.
+fn write_override_mods<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    for cmd in &registry.cmds {
+        writeln!(
+            dest,
+            r##"
+            #[allow(non_snake_case)]
+            pub mod {name} {{
+                #[allow(unused_imports)]
+                use super::{{__gl_imports, overrides, types}};
+
+                /// Replaces the default behaviour of the command with `f`.
+                #[allow(dead_code)]
+                pub fn set<F>(f: F) where F: Fn({params}) -> {return_suffix} + Send + Sync + 'static {{
+                    let mut lock = match overrides::{name}.write() {{
+                        Ok(lock) => lock,
+                        Err(poisoned) => poisoned.into_inner(),
+                    }};
+                    *lock = Some(__gl_imports::Box::new(f));
+                }}
+
+                /// Restores the default behaviour of the command.
+                #[allow(dead_code)]
+                pub fn reset() {{
+                    let mut lock = match overrides::{name}.write() {{
+                        Ok(lock) => lock,
+                        Err(poisoned) => poisoned.into_inner(),
+                    }};
+                    *lock = None;
+                }}
+            }}
+        "##,
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, false, true).join(", "),
+            return_suffix = cmd.proto.ty,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Creates the `load` function, which returns the implementation of a symbol.
+fn write_load_fn<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// Returns the headless implementation of `symbol`, or null if it is unknown.
+        ///
+        /// ~~~ignore
+        /// gl::load_with(null_gl::load);
+        /// ~~~
+        #[allow(dead_code)]
+        pub fn load(symbol: &str) -> *const __gl_imports::raw::c_void {{
+            match symbol {{"#
+    )?;
+
+    for cmd in &registry.cmds {
+        writeln!(
+            dest,
+            "\"{symbol}\" => null_fns::{name} as *const __gl_imports::raw::c_void,",
+            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
+            name = cmd.proto.ident,
+        )?;
+    }
+
+    writeln!(
+        dest,
+        "
+                _ => __gl_imports::ptr::null(),
+            }}
+        }}
+
+        /// Restores the default behaviour of every command.
+        #[allow(dead_code)]
+        pub fn reset_overrides() {{"
+    )?;
+
+    for cmd in &registry.cmds {
+        writeln!(dest, "{name}::reset();", name = cmd.proto.ident)?;
+    }
+
+    writeln!(dest, "}}")
+}
@@ -0,0 +1,534 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::{Binding, Cmd, Registry};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+
+/// Generates the struct of `StructGenerator`, which records the object names that are created
+/// and deleted by each class of commands, such as `GenBuffers` and `DeleteBuffers`.
+///
+/// Deleting a name twice, and using a deleted name, are reported to a handler. The objects that
+/// are still alive can be listed with the command and call site that created them.
+#[allow(missing_copy_implementations)]
+pub struct ObjectTrackingGenerator;
+
+impl super::Generator for ObjectTrackingGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        write_object_tracker(dest)?;
+        super::struct_gen::write_fnptr_struct_def(dest)?;
+        super::gen_missing_fns(registry, dest)?;
+        super::gen_load_report(registry, dest)?;
+        super::struct_gen::write_struct_with_fields(&ctx, &FIELDS, dest)?;
+        write_impl(&ctx, dest)?;
+        Ok(())
+    }
+}
+
+/// The fields of the struct that store the tracker and the function called for misuses, with
+/// their type and initial value.
+const FIELDS: [(&str, &str); 2] = [
+    (
+        "objects",
+        "__gl_imports::Arc<__gl_imports::Mutex<ObjectTracker>>",
+    ),
+    (
+        "object_error_handler",
+        "__gl_imports::Arc<dyn Fn(&ObjectError) + Send + Sync>",
+    ),
+];
+const FIELD_VALUES: [&str; 2] = [
+    "__gl_imports::Arc::default()",
+    "__gl_imports::Arc::new(print_object_error)",
+];
+
+/// What a command does with the object names of a class.
+enum ObjectCmd<'a> {
+    /// Writes `len` new names to the pointer `names`, like `GenBuffers(n, buffers)`.
+    CreateMany {
+        class: String,
+        names: &'a Binding,
+        len: &'a Binding,
+    },
+    /// Returns a new name, like `CreateProgram()`.
+    CreateOne { class: String },
+    /// Deletes the `len` names pointed to by `names`, like `DeleteBuffers(n, buffers)`.
+    DeleteMany {
+        class: String,
+        names: &'a Binding,
+        len: &'a Binding,
+    },
+    /// Deletes the name `name`, like `DeleteProgram(program)`.
+    DeleteOne { class: String, name: &'a Binding },
+}
+
+impl<'a> ObjectCmd<'a> {
+    fn class(&self) -> &str {
+        match *self {
+            ObjectCmd::CreateMany { ref class, .. }
+            | ObjectCmd::CreateOne { ref class }
+            | ObjectCmd::DeleteMany { ref class, .. }
+            | ObjectCmd::DeleteOne { ref class, .. } => class,
+        }
+    }
+
+    fn is_create(&self) -> bool {
+        matches!(
+            *self,
+            ObjectCmd::CreateMany { .. } | ObjectCmd::CreateOne { .. }
+        )
+    }
+}
+
+/// Converts the end of the name of a command to a class, for example `VertexArrays` to
+/// `"vertex array"` and `Queries` to `"query"`. The suffix of a vendor is left out.
+fn class_of(name: &str, plural: bool) -> String {
+    let mut name = name;
+    for suffix in super::VENDOR_SUFFIXES {
+        if let Some(rest) = name.strip_suffix(suffix) {
+            name = rest;
+            break;
+        }
+    }
+    let singular = match name.strip_suffix("ies") {
+        Some(rest) if plural => format!("{rest}y"),
+        _ if plural => name.trim_end_matches('s').to_string(),
+        _ => name.to_string(),
+    };
+    let mut class = String::new();
+    for c in singular.chars() {
+        if c.is_ascii_uppercase() && !class.is_empty() {
+            class.push(' ');
+        }
+        class.push(c.to_ascii_lowercase());
+    }
+    class
+}
+
+/// Converts a class to the usual name of the parameters that take one of its objects, for
+/// example `"vertex array"` to `vertexArray`.
+fn param_name(class: &str) -> String {
+    let mut words = class.split(' ');
+    let first = words.next().unwrap_or("").to_string();
+    words.fold(first, |name, word| {
+        name + &word[..1].to_ascii_uppercase() + &word[1..]
+    })
+}
+
+/// Returns what `cmd` does with object names, from its name and parameters.
+fn object_cmd(cmd: &Cmd) -> Option<ObjectCmd<'_>> {
+    let ident = &cmd.proto.ident;
+    let names_param = |ty: &str| {
+        cmd.params
+            .iter()
+            .find(|param| param.ty == ty && param.len.is_some())
+    };
+    let len_of = |names: &Binding| {
+        let len = names.len.as_ref()?;
+        cmd.params
+            .iter()
+            .find(|param| param.ident == *len && param.ty == "types::GLsizei")
+    };
+    let class_or = |binding: &Binding, name: &str, plural: bool| {
+        binding
+            .class
+            .clone()
+            .unwrap_or_else(|| class_of(name, plural))
+    };
+
+    if let Some(name) = ident
+        .strip_prefix("Gen")
+        .or_else(|| ident.strip_prefix("Create"))
+    {
+        if let Some(names) = names_param("*mut types::GLuint") {
+            return Some(ObjectCmd::CreateMany {
+                class: class_or(names, name, true),
+                names,
+                len: len_of(names)?,
+            });
+        }
+        if ident.starts_with("Create") && cmd.proto.ty == "types::GLuint" {
+            return Some(ObjectCmd::CreateOne {
+                class: class_or(&cmd.proto, name, false),
+            });
+        }
+    }
+    if let Some(name) = ident.strip_prefix("Delete") {
+        if let Some(names) = names_param("*const types::GLuint") {
+            return Some(ObjectCmd::DeleteMany {
+                class: class_or(names, name, true),
+                names,
+                len: len_of(names)?,
+            });
+        }
+        if cmd.params.len() == 1 && cmd.params[0].ty == "types::GLuint" {
+            return Some(ObjectCmd::DeleteOne {
+                class: class_or(&cmd.params[0], name, false),
+                name: &cmd.params[0],
+            });
+        }
+    }
+    None
+}
+
+/// Returns the commands that create or delete object names, for the classes that have both.
+fn object_cmds(registry: &Registry) -> BTreeMap<&str, ObjectCmd<'_>> {
+    let cmds = registry
+        .cmds
+        .iter()
+        .filter_map(|cmd| Some((&cmd.proto.ident[..], object_cmd(cmd)?)))
+        .collect::<BTreeMap<_, _>>();
+    let created = cmds
+        .values()
+        .filter(|cmd| cmd.is_create())
+        .map(|cmd| cmd.class().to_string())
+        .collect::<BTreeSet<_>>();
+    let deleted = cmds
+        .values()
+        .filter(|cmd| !cmd.is_create())
+        .map(|cmd| cmd.class().to_string())
+        .collect::<BTreeSet<_>>();
+    cmds.into_iter()
+        .filter(|(_, cmd)| created.contains(cmd.class()) && deleted.contains(cmd.class()))
+        .collect()
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::collections::{BTreeMap, BTreeSet}",
+    "std::fmt",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::panic",
+    "std::process",
+    "std::slice",
+    "std::sync::{Arc, Mutex, MutexGuard}",
+    "std::sync::RwLock",
+];
+
+/// Creates the `LiveObject` and `ObjectError` types, and the `ObjectTracker` that records the
+/// object names.
+fn write_object_tracker<W>(dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// An object that was created and not deleted yet.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct LiveObject {{
+            /// The class of the object, for example `"texture"`.
+            pub class: &'static str,
+            /// The name of the object.
+            pub name: types::GLuint,
+            /// The symbol of the command that created the object, for example `"glGenTextures"`.
+            pub command: &'static str,
+            /// Where that command was called.
+            pub location: &'static __gl_imports::panic::Location<'static>,
+        }}
+
+        impl __gl_imports::fmt::Display for LiveObject {{
+            fn fmt(&self, f: &mut __gl_imports::fmt::Formatter) -> __gl_imports::fmt::Result {{
+                write!(f, "{{}} {{}} created by {{}} at {{}}", self.class, self.name, self.command, self.location)
+            }}
+        }}
+
+        /// A misuse of an object name.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum ObjectError {{
+            /// A name was deleted after it had already been deleted.
+            DoubleDelete {{
+                class: &'static str,
+                name: types::GLuint,
+                command: &'static str,
+                location: &'static __gl_imports::panic::Location<'static>,
+            }},
+            /// A name was used after it had been deleted.
+            UseAfterDelete {{
+                class: &'static str,
+                name: types::GLuint,
+                command: &'static str,
+                location: &'static __gl_imports::panic::Location<'static>,
+            }},
+        }}
+
+        impl __gl_imports::fmt::Display for ObjectError {{
+            fn fmt(&self, f: &mut __gl_imports::fmt::Formatter) -> __gl_imports::fmt::Result {{
+                match *self {{
+                    ObjectError::DoubleDelete {{ class, name, command, location }} => {{
+                        write!(f, "{{command}} at {{location}}: {{class}} {{name}} was already deleted")
+                    }},
+                    ObjectError::UseAfterDelete {{ class, name, command, location }} => {{
+                        write!(f, "{{command}} at {{location}}: {{class}} {{name}} was deleted")
+                    }},
+                }}
+            }}
+        }}
+
+        /// Prints a misuse of an object name to stderr. This is the default handler.
+        #[allow(dead_code)]
+        pub fn print_object_error(error: &ObjectError) {{
+            eprintln!("{{error}}");
+        }}
+
+        /// The object names that are alive, and the ones that were deleted, by class.
+        #[derive(Default)]
+        struct ObjectTracker {{
+            live: __gl_imports::BTreeMap<(&'static str, types::GLuint), LiveObject>,
+            deleted: __gl_imports::BTreeSet<(&'static str, types::GLuint)>,
+            dump_on_drop: bool,
+        }}
+
+        #[allow(dead_code)]
+        impl ObjectTracker {{
+            fn create(&mut self, object: LiveObject) {{
+                if object.name != 0 {{
+                    self.deleted.remove(&(object.class, object.name));
+                    self.live.insert((object.class, object.name), object);
+                }}
+            }}
+
+            fn delete(&mut self, class: &'static str, name: types::GLuint) -> bool {{
+                if name == 0 || self.live.remove(&(class, name)).is_some() {{
+                    self.deleted.insert((class, name));
+                    true
+                }} else {{
+                    !self.deleted.contains(&(class, name))
+                }}
+            }}
+
+            fn is_deleted(&self, class: &'static str, name: types::GLuint) -> bool {{
+                name != 0 && self.deleted.contains(&(class, name))
+            }}
+        }}
+
+        impl Drop for ObjectTracker {{
+            fn drop(&mut self) {{
+                if self.dump_on_drop {{
+                    for object in self.live.values() {{
+                        eprintln!("leaked {{object}}");
+                    }}
+                }}
+            }}
+        }}
+
+        /// Returns the names pointed to by a parameter, or nothing if the pointer is null.
+        #[allow(dead_code)]
+        unsafe fn object_names<'a>(names: *const types::GLuint, len: types::GLsizei) -> &'a [types::GLuint] {{
+            if names.is_null() || len <= 0 {{
+                &[]
+            }} else {{
+                __gl_imports::slice::from_raw_parts(names, len as usize)
+            }}
+        }}
+    "#
+    )
+}
+
+/// Creates the `impl` of the structure created by `write_struct_with_fields`, where the commands
+/// that create, delete or use object names record them.
+fn write_impl<W>(ctx: &super::GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    let registry = ctx.registry();
+    writeln!(
+        dest,
+        "impl {api} {{",
+        api = super::gen_struct_name(registry.api)
+    )?;
+
+    let fields = FIELDS
+        .iter()
+        .zip(FIELD_VALUES.iter())
+        .map(|(&(name, _), &value)| (name, value))
+        .collect::<Vec<_>>();
+    super::struct_gen::write_load_fns_with_fields(ctx, &fields, dest)?;
+
+    writeln!(
+        dest,
+        r#"
+        fn objects(&self) -> __gl_imports::MutexGuard<'_, ObjectTracker> {{
+            match self.objects.lock() {{
+                Ok(objects) => objects,
+                Err(poisoned) => poisoned.into_inner(),
+            }}
+        }}
+
+        /// Returns the objects that were created and not deleted yet, by class and name.
+        #[allow(dead_code)]
+        pub fn live_objects(&self) -> Vec<LiveObject> {{
+            self.objects().live.values().cloned().collect()
+        }}
+
+        /// Prints the objects that were created and not deleted yet to stderr.
+        #[allow(dead_code)]
+        pub fn dump_live_objects(&self) {{
+            for object in self.live_objects() {{
+                eprintln!("{{object}}");
+            }}
+        }}
+
+        /// Sets whether the objects that are still alive are printed to stderr when the struct
+        /// and all its clones are dropped.
+        #[allow(dead_code)]
+        pub fn set_dump_on_drop(&self, dump: bool) {{
+            self.objects().dump_on_drop = dump;
+        }}
+
+        /// Replaces the function that is called with each misuse of an object name, before the
+        /// command runs. The default handler is `print_object_error`.
+        #[allow(dead_code)]
+        pub fn set_object_error_handler<F>(&mut self, handler: F)
+            where F: Fn(&ObjectError) + Send + Sync + 'static {{
+            self.object_error_handler = __gl_imports::Arc::new(handler);
+        }}
+
+        #[allow(dead_code)]
+        fn create_objects(&self, class: &'static str, names: &[types::GLuint], command: &'static str, location: &'static __gl_imports::panic::Location<'static>) {{
+            let mut objects = self.objects();
+            for &name in names {{
+                objects.create(LiveObject {{ class, name, command, location }});
+            }}
+        }}
+
+        #[allow(dead_code)]
+        fn delete_objects(&self, class: &'static str, names: &[types::GLuint], command: &'static str, location: &'static __gl_imports::panic::Location<'static>) {{
+            let errors = {{
+                let mut objects = self.objects();
+                names
+                    .iter()
+                    .filter(|&&name| !objects.delete(class, name))
+                    .map(|&name| ObjectError::DoubleDelete {{ class, name, command, location }})
+                    .collect::<Vec<_>>()
+            }};
+            for error in &errors {{
+                (self.object_error_handler)(error);
+            }}
+        }}
+
+        #[allow(dead_code)]
+        fn use_object(&self, class: &'static str, name: types::GLuint, command: &'static str, location: &'static __gl_imports::panic::Location<'static>) {{
+            let is_deleted = self.objects().is_deleted(class, name);
+            if is_deleted {{
+                (self.object_error_handler)(&ObjectError::UseAfterDelete {{ class, name, command, location }});
+            }}
+        }}"#
+    )?;
+
+    let object_cmds = object_cmds(registry);
+    let classes = object_cmds
+        .values()
+        .map(|cmd| cmd.class().to_string())
+        .collect::<BTreeSet<_>>();
+    for cmd in &registry.cmds {
+        let symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident);
+        let mut before = String::new();
+        let mut after = String::new();
+        match object_cmds.get(&cmd.proto.ident[..]) {
+            Some(ObjectCmd::CreateMany { class, names, len }) => {
+                after = format!(
+                    "self.create_objects(\"{class}\", object_names({names}.cast_const(), {len}), \"{symbol}\", caller);",
+                    names = names.ident,
+                    len = len.ident,
+                );
+            },
+            Some(ObjectCmd::CreateOne { class }) => {
+                after = format!("self.create_objects(\"{class}\", &[r], \"{symbol}\", caller);");
+            },
+            Some(ObjectCmd::DeleteMany { class, names, len }) => {
+                before = format!(
+                    "self.delete_objects(\"{class}\", object_names({names}, {len}), \"{symbol}\", caller);",
+                    names = names.ident,
+                    len = len.ident,
+                );
+            },
+            Some(ObjectCmd::DeleteOne { class, name }) => {
+                before = format!(
+                    "self.delete_objects(\"{class}\", &[{name}], \"{symbol}\", caller);",
+                    name = name.ident,
+                );
+            },
+            // `Is*` commands are allowed to query deleted names
+            None if !cmd.proto.ident.starts_with("Is") => {
+                for param in &cmd.params {
+                    if param.ty != "types::GLuint" {
+                        continue;
+                    }
+                    let class = match param.class {
+                        Some(ref class) => classes.get(class),
+                        None => classes
+                            .iter()
+                            .find(|class| param_name(class) == param.ident),
+                    };
+                    if let Some(class) = class {
+                        before += &format!(
+                            "self.use_object(\"{class}\", {param}, \"{symbol}\", caller);",
+                            param = param.ident,
+                        );
+                    }
+                }
+            },
+            None => (),
+        }
+
+        let call = format!(
+            "(self.{name}.f)({idents})",
+            name = cmd.proto.ident,
+            idents = super::gen_parameters(cmd, true, false).join(", "),
+        );
+        let body = match (before.is_empty() && after.is_empty(), &cmd.proto.ty[..]) {
+            (true, _) => call,
+            (false, "()") => format!(
+                "let caller = __gl_imports::panic::Location::caller(); {before} {call}; {after}"
+            ),
+            (false, _) if after.is_empty() => format!(
+                "let caller = __gl_imports::panic::Location::caller(); {before} {call}"
+            ),
+            (false, _) => format!(
+                "let caller = __gl_imports::panic::Location::caller(); {before} let r = {call}; {after} r"
+            ),
+        };
+
+        write!(dest, "{}", super::gen_cmd_doc(registry, cmd))?;
+        writeln!(
+            dest,
+            "#[allow(non_snake_case, unused_variables, dead_code)]
+            #[inline] #[track_caller] pub unsafe fn {name}(&self, {params}) -> {return_suffix} {{ \
+                {body} \
+            }}",
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+        )?
+    }
+
+    writeln!(
+        dest,
+        "}}
+
+        unsafe impl __gl_imports::Send for {api} {{}}",
+        api = super::gen_struct_name(registry.api)
+    )
+}
@@ -0,0 +1,293 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::underscore_keyword;
+use Api;
+
+/// The lints allowed on the functions that call the commands, when lint allows are emitted.
+const COMMAND_LINTS: &str = "non_snake_case, unused_variables, dead_code, \
+    clippy::missing_safety_doc, clippy::too_many_arguments, clippy::unused_unit";
+
+/// The visibility of the generated items.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Visibility {
+    /// `pub`, the default.
+    #[default]
+    Public,
+    /// `pub(crate)`.
+    Crate,
+}
+
+impl Visibility {
+    /// Returns the keyword of the visibility, for example `"pub(crate)"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Visibility::Public => "pub",
+            Visibility::Crate => "pub(crate)",
+        }
+    }
+}
+
+/// The inline attribute of the functions that call the commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum InlinePolicy {
+    /// `#[inline]`, the default.
+    #[default]
+    Hint,
+    /// `#[inline(always)]`.
+    Always,
+    /// `#[inline(never)]`.
+    Never,
+    /// No inline attribute.
+    Omit,
+}
+
+/// How the functions that call the commands are named.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Naming {
+    /// The names of the registry, for example `BindBuffer`, the default.
+    #[default]
+    Registry,
+    /// snake_case names, for example `bind_buffer`.
+    ///
+    /// A word starts at each uppercase letter that follows a lowercase letter, and at each run of
+    /// digits that is followed by an uppercase letter, so that `TexImage2D` becomes
+    /// `tex_image_2d` and `Uniform4fv` becomes `uniform4fv`. Vendor suffixes are separated, as in
+    /// `bind_buffer_arb`.
+    SnakeCase,
+}
+
+/// The kind of identifier passed to the callback of `GeneratorOptions::rename`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NameKind {
+    /// The name of a function that calls a command.
+    Command,
+    /// The name of the constant of an enum.
+    Enum,
+}
+
+/// Options for the items written by `GlobalGenerator`, `StaticGenerator`, `StructGenerator`,
+/// `DebugStructGenerator` and `StaticStructGenerator`.
+///
+/// These apply to the functions that call the commands, the constants of the enums, the `types`
+/// module, the structure and its `load_with` functions. The supporting items, such as `FnPtr`
+/// and `LoadReport`, are always written the same way.
+///
+/// ```no_run
+/// # use gl_generator::*;
+/// # let mut file = Vec::new();
+/// let options = GeneratorOptions::new()
+///     .visibility(Visibility::Crate)
+///     .inline(InlinePolicy::Omit)
+///     .doc_alias(true);
+///
+/// Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, [])
+///     .write_bindings_with_options(GlobalGenerator, &options, &mut file)
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct GeneratorOptions {
+    visibility: Visibility,
+    inline: InlinePolicy,
+    fn_attrs: Vec<String>,
+    const_attrs: Vec<String>,
+    doc_alias: bool,
+    lint_allows: bool,
+    naming: Naming,
+    strip_cmd_prefix: bool,
+    strip_enum_prefix: bool,
+    rename: Option<fn(NameKind, &str) -> String>,
+}
+
+impl Default for GeneratorOptions {
+    fn default() -> GeneratorOptions {
+        GeneratorOptions {
+            visibility: Visibility::Public,
+            inline: InlinePolicy::Hint,
+            fn_attrs: Vec::new(),
+            const_attrs: Vec::new(),
+            doc_alias: false,
+            lint_allows: true,
+            naming: Naming::Registry,
+            strip_cmd_prefix: true,
+            strip_enum_prefix: true,
+            rename: None,
+        }
+    }
+}
+
+impl GeneratorOptions {
+    /// Creates the default options, which give the same bindings as `Generator::write`.
+    pub fn new() -> GeneratorOptions {
+        GeneratorOptions::default()
+    }
+
+    /// Sets the visibility of the items. Defaults to `Visibility::Public`.
+    pub fn visibility(mut self, visibility: Visibility) -> GeneratorOptions {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Sets the inline attribute of the functions that call the commands. Defaults to
+    /// `InlinePolicy::Hint`.
+    pub fn inline(mut self, inline: InlinePolicy) -> GeneratorOptions {
+        self.inline = inline;
+        self
+    }
+
+    /// Adds an attribute to the functions that call the commands, without the `#[]`, for
+    /// example `"cold"`.
+    pub fn fn_attr(mut self, attr: &str) -> GeneratorOptions {
+        self.fn_attrs.push(attr.to_string());
+        self
+    }
+
+    /// Adds an attribute to the constants of the enums, without the `#[]`, for example
+    /// `"doc(hidden)"`.
+    pub fn const_attr(mut self, attr: &str) -> GeneratorOptions {
+        self.const_attrs.push(attr.to_string());
+        self
+    }
+
+    /// Adds `#[doc(alias = "glClear")]` to the functions that call the commands, so that they can
+    /// be found by their native name in rustdoc. Defaults to `false`.
+    pub fn doc_alias(mut self, doc_alias: bool) -> GeneratorOptions {
+        self.doc_alias = doc_alias;
+        self
+    }
+
+    /// Writes `#[allow(...)]` attributes for the lints that the items would trigger, such as
+    /// `non_snake_case` or `clippy::too_many_arguments`. Defaults to `true`.
+    ///
+    /// Without them, the lints must be allowed where the bindings are included.
+    pub fn lint_allows(mut self, lint_allows: bool) -> GeneratorOptions {
+        self.lint_allows = lint_allows;
+        self
+    }
+
+    /// Sets how the functions that call the commands are named. Defaults to
+    /// `Naming::Registry`.
+    pub fn naming(mut self, naming: Naming) -> GeneratorOptions {
+        self.naming = naming;
+        self
+    }
+
+    /// Removes the prefix of the commands from the names of the functions that call them, for
+    /// example `Clear` rather than `glClear`. Defaults to `true`.
+    pub fn strip_cmd_prefix(mut self, strip: bool) -> GeneratorOptions {
+        self.strip_cmd_prefix = strip;
+        self
+    }
+
+    /// Removes the prefix of the enums from the names of their constants, for example
+    /// `COLOR_BUFFER_BIT` rather than `GL_COLOR_BUFFER_BIT`. Defaults to `true`.
+    pub fn strip_enum_prefix(mut self, strip: bool) -> GeneratorOptions {
+        self.strip_enum_prefix = strip;
+        self
+    }
+
+    /// Sets a function that renames the functions that call the commands and the constants of
+    /// the enums. It receives the name given by the other naming options, and must return a
+    /// valid Rust identifier.
+    pub fn rename(mut self, rename: fn(NameKind, &str) -> String) -> GeneratorOptions {
+        self.rename = Some(rename);
+        self
+    }
+
+    /// Returns the name of the function that calls the command `ident`, given without its
+    /// prefix as in `Cmd::proto`.
+    pub fn cmd_name(&self, api: Api, ident: &str) -> String {
+        let name = match self.strip_cmd_prefix {
+            true => ident.to_string(),
+            false => super::gen_symbol_name(api, ident),
+        };
+        let name = match self.naming {
+            Naming::Registry => name,
+            Naming::SnakeCase => super::snake_case(&name),
+        };
+        self.apply_rename(NameKind::Command, underscore_keyword(name))
+    }
+
+    /// Returns the name of the constant of the enum `ident`, given without its prefix as in
+    /// `Enum::ident`.
+    pub fn enum_name(&self, api: Api, ident: &str) -> String {
+        let name = match self.strip_enum_prefix {
+            true => ident.to_string(),
+            false => super::gen_enum_symbol_name(api, ident),
+        };
+        self.apply_rename(NameKind::Enum, name)
+    }
+
+    fn apply_rename(&self, kind: NameKind, name: String) -> String {
+        match self.rename {
+            Some(rename) => rename(kind, &name),
+            None => name,
+        }
+    }
+
+    /// Returns the visibility keyword of the items, for example `"pub"`.
+    pub fn vis(&self) -> &'static str {
+        self.visibility.as_str()
+    }
+
+    /// Returns `#[allow({lints})]`, or nothing if lint allows are not emitted.
+    pub fn allow(&self, lints: &str) -> String {
+        if self.lint_allows {
+            format!("#[allow({lints})]")
+        } else {
+            String::new()
+        }
+    }
+
+    /// Returns `#![allow({lints})]`, or nothing if lint allows are not emitted.
+    pub fn inner_allow(&self, lints: &str) -> String {
+        if self.lint_allows {
+            format!("#![allow({lints})]")
+        } else {
+            String::new()
+        }
+    }
+
+    /// Returns the inline attribute of the functions that call the commands.
+    pub fn inline_attr(&self) -> &'static str {
+        match self.inline {
+            InlinePolicy::Hint => "#[inline]",
+            InlinePolicy::Always => "#[inline(always)]",
+            InlinePolicy::Never => "#[inline(never)]",
+            InlinePolicy::Omit => "",
+        }
+    }
+
+    /// Returns the attributes of the function that calls the command whose native name is
+    /// `symbol`, except the inline attribute.
+    pub fn fn_attrs(&self, symbol: &str) -> String {
+        let mut attrs = self.allow(COMMAND_LINTS);
+        if self.doc_alias {
+            attrs.push_str(&format!("#[doc(alias = \"{symbol}\")]"));
+        }
+        for attr in &self.fn_attrs {
+            attrs.push_str(&format!("#[{attr}]"));
+        }
+        attrs
+    }
+
+    /// Returns the attributes of the constants of the enums.
+    pub fn const_attrs(&self) -> String {
+        let mut attrs = self.allow("dead_code, non_upper_case_globals");
+        for attr in &self.const_attrs {
+            attrs.push_str(&format!("#[{attr}]"));
+        }
+        attrs
+    }
+}
@@ -0,0 +1,1236 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The names of the reference pages of the Khronos registry.
+//!
+//! Most commands are documented by the page with their name, but some pages document a family of
+//! commands, like `glUniform` for `glUniform4fv`. Commands that aren't in these tables have no
+//! page, such as the compatibility profile commands.
+
+/// The OpenGL 4 reference pages, with the other commands that each of them documents.
+pub const GL4: &[(&str, &[&str])] = &[
+    ("ActiveShaderProgram", &[]),
+    ("ActiveTexture", &[]),
+    ("AttachShader", &[]),
+    ("BeginConditionalRender", &["EndConditionalRender"]),
+    ("BeginQuery", &["EndQuery"]),
+    ("BeginQueryIndexed", &["EndQueryIndexed"]),
+    ("BeginTransformFeedback", &["EndTransformFeedback"]),
+    ("BindAttribLocation", &[]),
+    ("BindBuffer", &[]),
+    ("BindBufferBase", &[]),
+    ("BindBufferRange", &[]),
+    ("BindBuffersBase", &[]),
+    ("BindBuffersRange", &[]),
+    ("BindFragDataLocation", &[]),
+    ("BindFragDataLocationIndexed", &[]),
+    ("BindFramebuffer", &[]),
+    ("BindImageTexture", &[]),
+    ("BindImageTextures", &[]),
+    ("BindProgramPipeline", &[]),
+    ("BindRenderbuffer", &[]),
+    ("BindSampler", &[]),
+    ("BindSamplers", &[]),
+    ("BindTexture", &[]),
+    ("BindTextureUnit", &[]),
+    ("BindTextures", &[]),
+    ("BindTransformFeedback", &[]),
+    ("BindVertexArray", &[]),
+    ("BindVertexBuffer", &["VertexArrayVertexBuffer"]),
+    ("BindVertexBuffers", &["VertexArrayVertexBuffers"]),
+    ("BlendColor", &[]),
+    ("BlendEquation", &["BlendEquationi"]),
+    ("BlendEquationSeparate", &["BlendEquationSeparatei"]),
+    ("BlendFunc", &["BlendFunci"]),
+    ("BlendFuncSeparate", &["BlendFuncSeparatei"]),
+    ("BlitFramebuffer", &["BlitNamedFramebuffer"]),
+    ("BufferData", &["NamedBufferData"]),
+    ("BufferStorage", &["NamedBufferStorage"]),
+    ("BufferSubData", &["NamedBufferSubData"]),
+    ("CheckFramebufferStatus", &["CheckNamedFramebufferStatus"]),
+    ("ClampColor", &[]),
+    ("Clear", &[]),
+    (
+        "ClearBuffer",
+        &[
+            "ClearBufferiv",
+            "ClearBufferuiv",
+            "ClearBufferfv",
+            "ClearBufferfi",
+            "ClearNamedFramebufferiv",
+            "ClearNamedFramebufferuiv",
+            "ClearNamedFramebufferfv",
+            "ClearNamedFramebufferfi",
+        ],
+    ),
+    ("ClearBufferData", &["ClearNamedBufferData"]),
+    ("ClearBufferSubData", &["ClearNamedBufferSubData"]),
+    ("ClearColor", &[]),
+    ("ClearDepth", &["ClearDepthf"]),
+    ("ClearStencil", &[]),
+    ("ClearTexImage", &[]),
+    ("ClearTexSubImage", &[]),
+    ("ClientWaitSync", &[]),
+    ("ClipControl", &[]),
+    ("ColorMask", &["ColorMaski"]),
+    ("CompileShader", &[]),
+    ("CompressedTexImage1D", &[]),
+    ("CompressedTexImage2D", &[]),
+    ("CompressedTexImage3D", &[]),
+    ("CompressedTexSubImage1D", &["CompressedTextureSubImage1D"]),
+    ("CompressedTexSubImage2D", &["CompressedTextureSubImage2D"]),
+    ("CompressedTexSubImage3D", &["CompressedTextureSubImage3D"]),
+    ("CopyBufferSubData", &["CopyNamedBufferSubData"]),
+    ("CopyImageSubData", &[]),
+    ("CopyTexImage1D", &[]),
+    ("CopyTexImage2D", &[]),
+    ("CopyTexSubImage1D", &["CopyTextureSubImage1D"]),
+    ("CopyTexSubImage2D", &["CopyTextureSubImage2D"]),
+    ("CopyTexSubImage3D", &["CopyTextureSubImage3D"]),
+    ("CreateBuffers", &[]),
+    ("CreateFramebuffers", &[]),
+    ("CreateProgram", &[]),
+    ("CreateProgramPipelines", &[]),
+    ("CreateQueries", &[]),
+    ("CreateRenderbuffers", &[]),
+    ("CreateSamplers", &[]),
+    ("CreateShader", &[]),
+    ("CreateShaderProgram", &["CreateShaderProgramv"]),
+    ("CreateTextures", &[]),
+    ("CreateTransformFeedbacks", &[]),
+    ("CreateVertexArrays", &[]),
+    ("CullFace", &[]),
+    ("DebugMessageCallback", &[]),
+    ("DebugMessageControl", &[]),
+    ("DebugMessageInsert", &[]),
+    ("DeleteBuffers", &[]),
+    ("DeleteFramebuffers", &[]),
+    ("DeleteProgram", &[]),
+    ("DeleteProgramPipelines", &[]),
+    ("DeleteQueries", &[]),
+    ("DeleteRenderbuffers", &[]),
+    ("DeleteSamplers", &[]),
+    ("DeleteShader", &[]),
+    ("DeleteSync", &[]),
+    ("DeleteTextures", &[]),
+    ("DeleteTransformFeedbacks", &[]),
+    ("DeleteVertexArrays", &[]),
+    ("DepthFunc", &[]),
+    ("DepthMask", &[]),
+    ("DepthRange", &["DepthRangef"]),
+    ("DepthRangeArray", &["DepthRangeArrayv"]),
+    ("DepthRangeIndexed", &[]),
+    ("DetachShader", &[]),
+    ("DispatchCompute", &[]),
+    ("DispatchComputeIndirect", &[]),
+    ("DrawArrays", &[]),
+    ("DrawArraysIndirect", &[]),
+    ("DrawArraysInstanced", &[]),
+    ("DrawArraysInstancedBaseInstance", &[]),
+    ("DrawBuffer", &["NamedFramebufferDrawBuffer"]),
+    ("DrawBuffers", &["NamedFramebufferDrawBuffers"]),
+    ("DrawElements", &[]),
+    ("DrawElementsBaseVertex", &[]),
+    ("DrawElementsIndirect", &[]),
+    ("DrawElementsInstanced", &[]),
+    ("DrawElementsInstancedBaseInstance", &[]),
+    ("DrawElementsInstancedBaseVertex", &[]),
+    ("DrawElementsInstancedBaseVertexBaseInstance", &[]),
+    ("DrawRangeElements", &[]),
+    ("DrawRangeElementsBaseVertex", &[]),
+    ("DrawTransformFeedback", &[]),
+    ("DrawTransformFeedbackInstanced", &[]),
+    ("DrawTransformFeedbackStream", &[]),
+    ("DrawTransformFeedbackStreamInstanced", &[]),
+    ("Enable", &["Disable", "Enablei", "Disablei"]),
+    (
+        "EnableVertexAttribArray",
+        &[
+            "DisableVertexAttribArray",
+            "EnableVertexArrayAttrib",
+            "DisableVertexArrayAttrib",
+        ],
+    ),
+    ("FenceSync", &[]),
+    ("Finish", &[]),
+    ("Flush", &[]),
+    ("FlushMappedBufferRange", &["FlushMappedNamedBufferRange"]),
+    ("FramebufferParameteri", &["NamedFramebufferParameteri"]),
+    ("FramebufferRenderbuffer", &["NamedFramebufferRenderbuffer"]),
+    (
+        "FramebufferTexture",
+        &[
+            "FramebufferTexture1D",
+            "FramebufferTexture2D",
+            "FramebufferTexture3D",
+            "NamedFramebufferTexture",
+        ],
+    ),
+    ("FramebufferTextureLayer", &["NamedFramebufferTextureLayer"]),
+    ("FrontFace", &[]),
+    ("GenBuffers", &[]),
+    ("GenFramebuffers", &[]),
+    ("GenProgramPipelines", &[]),
+    ("GenQueries", &[]),
+    ("GenRenderbuffers", &[]),
+    ("GenSamplers", &[]),
+    ("GenTextures", &[]),
+    ("GenTransformFeedbacks", &[]),
+    ("GenVertexArrays", &[]),
+    ("GenerateMipmap", &["GenerateTextureMipmap"]),
+    (
+        "Get",
+        &[
+            "GetBooleanv",
+            "GetDoublev",
+            "GetFloatv",
+            "GetIntegerv",
+            "GetInteger64v",
+            "GetBooleani_v",
+            "GetIntegeri_v",
+            "GetFloati_v",
+            "GetDoublei_v",
+            "GetInteger64i_v",
+        ],
+    ),
+    ("GetActiveAtomicCounterBufferiv", &[]),
+    ("GetActiveAttrib", &[]),
+    ("GetActiveSubroutineName", &[]),
+    (
+        "GetActiveSubroutineUniform",
+        &["GetActiveSubroutineUniformiv"],
+    ),
+    ("GetActiveSubroutineUniformName", &[]),
+    ("GetActiveUniform", &[]),
+    ("GetActiveUniformBlock", &["GetActiveUniformBlockiv"]),
+    ("GetActiveUniformBlockName", &[]),
+    ("GetActiveUniformName", &[]),
+    ("GetActiveUniformsiv", &[]),
+    ("GetAttachedShaders", &[]),
+    ("GetAttribLocation", &[]),
+    (
+        "GetBufferParameter",
+        &[
+            "GetBufferParameteriv",
+            "GetBufferParameteri64v",
+            "GetNamedBufferParameteriv",
+            "GetNamedBufferParameteri64v",
+        ],
+    ),
+    ("GetBufferPointerv", &["GetNamedBufferPointerv"]),
+    ("GetBufferSubData", &["GetNamedBufferSubData"]),
+    (
+        "GetCompressedTexImage",
+        &["GetnCompressedTexImage", "GetCompressedTextureImage"],
+    ),
+    ("GetCompressedTextureSubImage", &[]),
+    ("GetDebugMessageLog", &[]),
+    ("GetError", &[]),
+    ("GetFragDataIndex", &[]),
+    ("GetFragDataLocation", &[]),
+    (
+        "GetFramebufferAttachmentParameter",
+        &[
+            "GetFramebufferAttachmentParameteriv",
+            "GetNamedFramebufferAttachmentParameteriv",
+        ],
+    ),
+    (
+        "GetFramebufferParameter",
+        &[
+            "GetFramebufferParameteriv",
+            "GetNamedFramebufferParameteriv",
+        ],
+    ),
+    ("GetGraphicsResetStatus", &[]),
+    (
+        "GetInternalformat",
+        &["GetInternalformativ", "GetInternalformati64v"],
+    ),
+    ("GetMultisample", &["GetMultisamplefv"]),
+    ("GetObjectLabel", &[]),
+    ("GetObjectPtrLabel", &[]),
+    ("GetPointerv", &[]),
+    ("GetProgram", &["GetProgramiv"]),
+    ("GetProgramBinary", &[]),
+    ("GetProgramInfoLog", &[]),
+    ("GetProgramInterface", &["GetProgramInterfaceiv"]),
+    ("GetProgramPipeline", &["GetProgramPipelineiv"]),
+    ("GetProgramPipelineInfoLog", &[]),
+    ("GetProgramResource", &["GetProgramResourceiv"]),
+    ("GetProgramResourceIndex", &[]),
+    ("GetProgramResourceLocation", &[]),
+    ("GetProgramResourceLocationIndex", &[]),
+    ("GetProgramResourceName", &[]),
+    ("GetProgramStage", &["GetProgramStageiv"]),
+    ("GetQueryIndexed", &["GetQueryIndexediv"]),
+    (
+        "GetQueryObject",
+        &[
+            "GetQueryObjectiv",
+            "GetQueryObjectuiv",
+            "GetQueryObjecti64v",
+            "GetQueryObjectui64v",
+            "GetQueryBufferObjectiv",
+            "GetQueryBufferObjectuiv",
+            "GetQueryBufferObjecti64v",
+            "GetQueryBufferObjectui64v",
+        ],
+    ),
+    ("GetQueryiv", &[]),
+    (
+        "GetRenderbufferParameter",
+        &[
+            "GetRenderbufferParameteriv",
+            "GetNamedRenderbufferParameteriv",
+        ],
+    ),
+    (
+        "GetSamplerParameter",
+        &[
+            "GetSamplerParameterfv",
+            "GetSamplerParameteriv",
+            "GetSamplerParameterIiv",
+            "GetSamplerParameterIuiv",
+        ],
+    ),
+    ("GetShader", &["GetShaderiv"]),
+    ("GetShaderInfoLog", &[]),
+    ("GetShaderPrecisionFormat", &[]),
+    ("GetShaderSource", &[]),
+    ("GetString", &["GetStringi"]),
+    ("GetSubroutineIndex", &[]),
+    ("GetSubroutineUniformLocation", &[]),
+    ("GetSync", &["GetSynciv"]),
+    ("GetTexImage", &["GetnTexImage", "GetTextureImage"]),
+    (
+        "GetTexLevelParameter",
+        &[
+            "GetTexLevelParameterfv",
+            "GetTexLevelParameteriv",
+            "GetTextureLevelParameterfv",
+            "GetTextureLevelParameteriv",
+        ],
+    ),
+    (
+        "GetTexParameter",
+        &[
+            "GetTexParameterfv",
+            "GetTexParameteriv",
+            "GetTexParameterIiv",
+            "GetTexParameterIuiv",
+            "GetTextureParameterfv",
+            "GetTextureParameteriv",
+            "GetTextureParameterIiv",
+            "GetTextureParameterIuiv",
+        ],
+    ),
+    ("GetTextureSubImage", &[]),
+    (
+        "GetTransformFeedback",
+        &[
+            "GetTransformFeedbackiv",
+            "GetTransformFeedbacki_v",
+            "GetTransformFeedbacki64_v",
+        ],
+    ),
+    ("GetTransformFeedbackVarying", &[]),
+    (
+        "GetUniform",
+        &[
+            "GetUniformfv",
+            "GetUniformiv",
+            "GetUniformuiv",
+            "GetUniformdv",
+            "GetnUniformfv",
+            "GetnUniformiv",
+            "GetnUniformuiv",
+            "GetnUniformdv",
+        ],
+    ),
+    ("GetUniformBlockIndex", &[]),
+    ("GetUniformIndices", &[]),
+    ("GetUniformLocation", &[]),
+    ("GetUniformSubroutine", &["GetUniformSubroutineuiv"]),
+    (
+        "GetVertexArrayIndexed",
+        &["GetVertexArrayIndexediv", "GetVertexArrayIndexed64iv"],
+    ),
+    ("GetVertexArrayiv", &[]),
+    (
+        "GetVertexAttrib",
+        &[
+            "GetVertexAttribdv",
+            "GetVertexAttribfv",
+            "GetVertexAttribiv",
+            "GetVertexAttribIiv",
+            "GetVertexAttribIuiv",
+            "GetVertexAttribLdv",
+        ],
+    ),
+    ("GetVertexAttribPointerv", &[]),
+    ("Hint", &[]),
+    ("InvalidateBufferData", &[]),
+    ("InvalidateBufferSubData", &[]),
+    ("InvalidateFramebuffer", &["InvalidateNamedFramebufferData"]),
+    (
+        "InvalidateSubFramebuffer",
+        &["InvalidateNamedFramebufferSubData"],
+    ),
+    ("InvalidateTexImage", &[]),
+    ("InvalidateTexSubImage", &[]),
+    ("IsBuffer", &[]),
+    ("IsEnabled", &["IsEnabledi"]),
+    ("IsFramebuffer", &[]),
+    ("IsProgram", &[]),
+    ("IsProgramPipeline", &[]),
+    ("IsQuery", &[]),
+    ("IsRenderbuffer", &[]),
+    ("IsSampler", &[]),
+    ("IsShader", &[]),
+    ("IsSync", &[]),
+    ("IsTexture", &[]),
+    ("IsTransformFeedback", &[]),
+    ("IsVertexArray", &[]),
+    ("LineWidth", &[]),
+    ("LinkProgram", &[]),
+    ("LogicOp", &[]),
+    ("MapBuffer", &["MapNamedBuffer"]),
+    ("MapBufferRange", &["MapNamedBufferRange"]),
+    ("MemoryBarrier", &["MemoryBarrierByRegion"]),
+    ("MinSampleShading", &[]),
+    ("MultiDrawArrays", &[]),
+    ("MultiDrawArraysIndirect", &[]),
+    ("MultiDrawElements", &[]),
+    ("MultiDrawElementsBaseVertex", &[]),
+    ("MultiDrawElementsIndirect", &[]),
+    ("ObjectLabel", &[]),
+    ("ObjectPtrLabel", &[]),
+    ("PatchParameter", &["PatchParameteri", "PatchParameterfv"]),
+    ("PauseTransformFeedback", &[]),
+    ("PixelStore", &["PixelStoref", "PixelStorei"]),
+    (
+        "PointParameter",
+        &[
+            "PointParameterf",
+            "PointParameteri",
+            "PointParameterfv",
+            "PointParameteriv",
+        ],
+    ),
+    ("PointSize", &[]),
+    ("PolygonMode", &[]),
+    ("PolygonOffset", &[]),
+    ("PopDebugGroup", &[]),
+    ("PrimitiveRestartIndex", &[]),
+    ("ProgramBinary", &[]),
+    ("ProgramParameter", &["ProgramParameteri"]),
+    (
+        "ProgramUniform",
+        &[
+            "ProgramUniform1f",
+            "ProgramUniform1i",
+            "ProgramUniform1ui",
+            "ProgramUniform1d",
+            "ProgramUniform2f",
+            "ProgramUniform2i",
+            "ProgramUniform2ui",
+            "ProgramUniform2d",
+            "ProgramUniform3f",
+            "ProgramUniform3i",
+            "ProgramUniform3ui",
+            "ProgramUniform3d",
+            "ProgramUniform4f",
+            "ProgramUniform4i",
+            "ProgramUniform4ui",
+            "ProgramUniform4d",
+            "ProgramUniform1fv",
+            "ProgramUniform1iv",
+            "ProgramUniform1uiv",
+            "ProgramUniform1dv",
+            "ProgramUniform2fv",
+            "ProgramUniform2iv",
+            "ProgramUniform2uiv",
+            "ProgramUniform2dv",
+            "ProgramUniform3fv",
+            "ProgramUniform3iv",
+            "ProgramUniform3uiv",
+            "ProgramUniform3dv",
+            "ProgramUniform4fv",
+            "ProgramUniform4iv",
+            "ProgramUniform4uiv",
+            "ProgramUniform4dv",
+            "ProgramUniformMatrix2fv",
+            "ProgramUniformMatrix2dv",
+            "ProgramUniformMatrix3fv",
+            "ProgramUniformMatrix3dv",
+            "ProgramUniformMatrix4fv",
+            "ProgramUniformMatrix4dv",
+            "ProgramUniformMatrix2x3fv",
+            "ProgramUniformMatrix2x3dv",
+            "ProgramUniformMatrix3x2fv",
+            "ProgramUniformMatrix3x2dv",
+            "ProgramUniformMatrix2x4fv",
+            "ProgramUniformMatrix2x4dv",
+            "ProgramUniformMatrix4x2fv",
+            "ProgramUniformMatrix4x2dv",
+            "ProgramUniformMatrix3x4fv",
+            "ProgramUniformMatrix3x4dv",
+            "ProgramUniformMatrix4x3fv",
+            "ProgramUniformMatrix4x3dv",
+        ],
+    ),
+    ("ProvokingVertex", &[]),
+    ("PushDebugGroup", &[]),
+    ("QueryCounter", &[]),
+    ("ReadBuffer", &["NamedFramebufferReadBuffer"]),
+    ("ReadPixels", &["ReadnPixels"]),
+    ("ReleaseShaderCompiler", &[]),
+    ("RenderbufferStorage", &["NamedRenderbufferStorage"]),
+    (
+        "RenderbufferStorageMultisample",
+        &["NamedRenderbufferStorageMultisample"],
+    ),
+    ("ResumeTransformFeedback", &[]),
+    ("SampleCoverage", &[]),
+    ("SampleMaski", &[]),
+    (
+        "SamplerParameter",
+        &[
+            "SamplerParameterf",
+            "SamplerParameteri",
+            "SamplerParameterfv",
+            "SamplerParameteriv",
+            "SamplerParameterIiv",
+            "SamplerParameterIuiv",
+        ],
+    ),
+    ("Scissor", &[]),
+    ("ScissorArray", &["ScissorArrayv"]),
+    ("ScissorIndexed", &["ScissorIndexedv"]),
+    ("ShaderBinary", &[]),
+    ("ShaderSource", &[]),
+    ("ShaderStorageBlockBinding", &[]),
+    ("StencilFunc", &[]),
+    ("StencilFuncSeparate", &[]),
+    ("StencilMask", &[]),
+    ("StencilMaskSeparate", &[]),
+    ("StencilOp", &[]),
+    ("StencilOpSeparate", &[]),
+    ("TexBuffer", &["TextureBuffer"]),
+    ("TexBufferRange", &["TextureBufferRange"]),
+    ("TexImage1D", &[]),
+    ("TexImage2D", &[]),
+    ("TexImage2DMultisample", &[]),
+    ("TexImage3D", &[]),
+    ("TexImage3DMultisample", &[]),
+    (
+        "TexParameter",
+        &[
+            "TexParameterf",
+            "TexParameteri",
+            "TexParameterfv",
+            "TexParameteriv",
+            "TexParameterIiv",
+            "TexParameterIuiv",
+            "TextureParameterf",
+            "TextureParameteri",
+            "TextureParameterfv",
+            "TextureParameteriv",
+            "TextureParameterIiv",
+            "TextureParameterIuiv",
+        ],
+    ),
+    ("TexStorage1D", &["TextureStorage1D"]),
+    ("TexStorage2D", &["TextureStorage2D"]),
+    ("TexStorage2DMultisample", &["TextureStorage2DMultisample"]),
+    ("TexStorage3D", &["TextureStorage3D"]),
+    ("TexStorage3DMultisample", &["TextureStorage3DMultisample"]),
+    ("TexSubImage1D", &["TextureSubImage1D"]),
+    ("TexSubImage2D", &["TextureSubImage2D"]),
+    ("TexSubImage3D", &["TextureSubImage3D"]),
+    ("TextureBarrier", &[]),
+    ("TextureView", &[]),
+    ("TransformFeedbackBufferBase", &[]),
+    ("TransformFeedbackBufferRange", &[]),
+    ("TransformFeedbackVaryings", &[]),
+    (
+        "Uniform",
+        &[
+            "Uniform1f",
+            "Uniform1i",
+            "Uniform1ui",
+            "Uniform1d",
+            "Uniform2f",
+            "Uniform2i",
+            "Uniform2ui",
+            "Uniform2d",
+            "Uniform3f",
+            "Uniform3i",
+            "Uniform3ui",
+            "Uniform3d",
+            "Uniform4f",
+            "Uniform4i",
+            "Uniform4ui",
+            "Uniform4d",
+            "Uniform1fv",
+            "Uniform1iv",
+            "Uniform1uiv",
+            "Uniform1dv",
+            "Uniform2fv",
+            "Uniform2iv",
+            "Uniform2uiv",
+            "Uniform2dv",
+            "Uniform3fv",
+            "Uniform3iv",
+            "Uniform3uiv",
+            "Uniform3dv",
+            "Uniform4fv",
+            "Uniform4iv",
+            "Uniform4uiv",
+            "Uniform4dv",
+            "UniformMatrix2fv",
+            "UniformMatrix2dv",
+            "UniformMatrix3fv",
+            "UniformMatrix3dv",
+            "UniformMatrix4fv",
+            "UniformMatrix4dv",
+            "UniformMatrix2x3fv",
+            "UniformMatrix2x3dv",
+            "UniformMatrix3x2fv",
+            "UniformMatrix3x2dv",
+            "UniformMatrix2x4fv",
+            "UniformMatrix2x4dv",
+            "UniformMatrix4x2fv",
+            "UniformMatrix4x2dv",
+            "UniformMatrix3x4fv",
+            "UniformMatrix3x4dv",
+            "UniformMatrix4x3fv",
+            "UniformMatrix4x3dv",
+        ],
+    ),
+    ("UniformBlockBinding", &[]),
+    ("UniformSubroutines", &["UniformSubroutinesuiv"]),
+    ("UnmapBuffer", &["UnmapNamedBuffer"]),
+    ("UseProgram", &[]),
+    ("UseProgramStages", &[]),
+    ("ValidateProgram", &[]),
+    ("ValidateProgramPipeline", &[]),
+    ("VertexArrayElementBuffer", &[]),
+    (
+        "VertexAttrib",
+        &[
+            "VertexAttrib1f",
+            "VertexAttrib1fv",
+            "VertexAttrib1s",
+            "VertexAttrib1sv",
+            "VertexAttrib1d",
+            "VertexAttrib1dv",
+            "VertexAttrib2f",
+            "VertexAttrib2fv",
+            "VertexAttrib2s",
+            "VertexAttrib2sv",
+            "VertexAttrib2d",
+            "VertexAttrib2dv",
+            "VertexAttrib3f",
+            "VertexAttrib3fv",
+            "VertexAttrib3s",
+            "VertexAttrib3sv",
+            "VertexAttrib3d",
+            "VertexAttrib3dv",
+            "VertexAttrib4f",
+            "VertexAttrib4fv",
+            "VertexAttrib4s",
+            "VertexAttrib4sv",
+            "VertexAttrib4d",
+            "VertexAttrib4dv",
+            "VertexAttrib4Nub",
+            "VertexAttrib4iv",
+            "VertexAttrib4bv",
+            "VertexAttrib4ubv",
+            "VertexAttrib4usv",
+            "VertexAttrib4uiv",
+            "VertexAttrib4Nbv",
+            "VertexAttrib4Nsv",
+            "VertexAttrib4Niv",
+            "VertexAttrib4Nubv",
+            "VertexAttrib4Nusv",
+            "VertexAttrib4Nuiv",
+            "VertexAttribI1i",
+            "VertexAttribI1iv",
+            "VertexAttribI1ui",
+            "VertexAttribI1uiv",
+            "VertexAttribI2i",
+            "VertexAttribI2iv",
+            "VertexAttribI2ui",
+            "VertexAttribI2uiv",
+            "VertexAttribI3i",
+            "VertexAttribI3iv",
+            "VertexAttribI3ui",
+            "VertexAttribI3uiv",
+            "VertexAttribI4i",
+            "VertexAttribI4iv",
+            "VertexAttribI4ui",
+            "VertexAttribI4uiv",
+            "VertexAttribI4bv",
+            "VertexAttribI4sv",
+            "VertexAttribI4ubv",
+            "VertexAttribI4usv",
+            "VertexAttribL1d",
+            "VertexAttribL1dv",
+            "VertexAttribL2d",
+            "VertexAttribL2dv",
+            "VertexAttribL3d",
+            "VertexAttribL3dv",
+            "VertexAttribL4d",
+            "VertexAttribL4dv",
+            "VertexAttribP1ui",
+            "VertexAttribP1uiv",
+            "VertexAttribP2ui",
+            "VertexAttribP2uiv",
+            "VertexAttribP3ui",
+            "VertexAttribP3uiv",
+            "VertexAttribP4ui",
+            "VertexAttribP4uiv",
+        ],
+    ),
+    ("VertexAttribBinding", &["VertexArrayAttribBinding"]),
+    ("VertexAttribDivisor", &[]),
+    (
+        "VertexAttribFormat",
+        &[
+            "VertexAttribIFormat",
+            "VertexAttribLFormat",
+            "VertexArrayAttribFormat",
+            "VertexArrayAttribIFormat",
+            "VertexArrayAttribLFormat",
+        ],
+    ),
+    (
+        "VertexAttribPointer",
+        &["VertexAttribIPointer", "VertexAttribLPointer"],
+    ),
+    ("VertexBindingDivisor", &["VertexArrayBindingDivisor"]),
+    ("Viewport", &[]),
+    ("ViewportArray", &["ViewportArrayv"]),
+    (
+        "ViewportIndexed",
+        &["ViewportIndexedf", "ViewportIndexedfv"],
+    ),
+    ("WaitSync", &[]),
+];
+
+/// The OpenGL ES 3 reference pages, with the other commands that each of them documents.
+pub const ES3: &[(&str, &[&str])] = &[
+    ("ActiveShaderProgram", &[]),
+    ("ActiveTexture", &[]),
+    ("AttachShader", &[]),
+    ("BeginQuery", &["EndQuery"]),
+    ("BeginTransformFeedback", &["EndTransformFeedback"]),
+    ("BindAttribLocation", &[]),
+    ("BindBuffer", &[]),
+    ("BindBufferBase", &[]),
+    ("BindBufferRange", &[]),
+    ("BindFramebuffer", &[]),
+    ("BindImageTexture", &[]),
+    ("BindProgramPipeline", &[]),
+    ("BindRenderbuffer", &[]),
+    ("BindSampler", &[]),
+    ("BindTexture", &[]),
+    ("BindTransformFeedback", &[]),
+    ("BindVertexArray", &[]),
+    ("BindVertexBuffer", &[]),
+    ("BlendBarrier", &[]),
+    ("BlendColor", &[]),
+    ("BlendEquation", &["BlendEquationi"]),
+    ("BlendEquationSeparate", &["BlendEquationSeparatei"]),
+    ("BlendFunc", &["BlendFunci"]),
+    ("BlendFuncSeparate", &["BlendFuncSeparatei"]),
+    ("BlitFramebuffer", &[]),
+    ("BufferData", &[]),
+    ("BufferSubData", &[]),
+    ("CheckFramebufferStatus", &[]),
+    ("Clear", &[]),
+    (
+        "ClearBuffer",
+        &[
+            "ClearBufferiv",
+            "ClearBufferuiv",
+            "ClearBufferfv",
+            "ClearBufferfi",
+        ],
+    ),
+    ("ClearColor", &[]),
+    ("ClearDepthf", &[]),
+    ("ClearStencil", &[]),
+    ("ClientWaitSync", &[]),
+    ("ColorMask", &["ColorMaski"]),
+    ("CompileShader", &[]),
+    ("CompressedTexImage2D", &[]),
+    ("CompressedTexImage3D", &[]),
+    ("CompressedTexSubImage2D", &[]),
+    ("CompressedTexSubImage3D", &[]),
+    ("CopyBufferSubData", &[]),
+    ("CopyImageSubData", &[]),
+    ("CopyTexImage2D", &[]),
+    ("CopyTexSubImage2D", &[]),
+    ("CopyTexSubImage3D", &[]),
+    ("CreateProgram", &[]),
+    ("CreateShader", &[]),
+    ("CreateShaderProgramv", &[]),
+    ("CullFace", &[]),
+    ("DebugMessageCallback", &[]),
+    ("DebugMessageControl", &[]),
+    ("DebugMessageInsert", &[]),
+    ("DeleteBuffers", &[]),
+    ("DeleteFramebuffers", &[]),
+    ("DeleteProgram", &[]),
+    ("DeleteProgramPipelines", &[]),
+    ("DeleteQueries", &[]),
+    ("DeleteRenderbuffers", &[]),
+    ("DeleteSamplers", &[]),
+    ("DeleteShader", &[]),
+    ("DeleteSync", &[]),
+    ("DeleteTextures", &[]),
+    ("DeleteTransformFeedbacks", &[]),
+    ("DeleteVertexArrays", &[]),
+    ("DepthFunc", &[]),
+    ("DepthMask", &[]),
+    ("DepthRangef", &[]),
+    ("DetachShader", &[]),
+    ("DispatchCompute", &[]),
+    ("DispatchComputeIndirect", &[]),
+    ("DrawArrays", &[]),
+    ("DrawArraysIndirect", &[]),
+    ("DrawArraysInstanced", &[]),
+    ("DrawBuffers", &[]),
+    ("DrawElements", &[]),
+    ("DrawElementsBaseVertex", &[]),
+    ("DrawElementsIndirect", &[]),
+    ("DrawElementsInstanced", &[]),
+    ("DrawElementsInstancedBaseVertex", &[]),
+    ("DrawRangeElements", &[]),
+    ("DrawRangeElementsBaseVertex", &[]),
+    ("Enable", &["Disable", "Enablei", "Disablei"]),
+    ("EnableVertexAttribArray", &["DisableVertexAttribArray"]),
+    ("FenceSync", &[]),
+    ("Finish", &[]),
+    ("Flush", &[]),
+    ("FlushMappedBufferRange", &[]),
+    ("FramebufferParameteri", &[]),
+    ("FramebufferRenderbuffer", &[]),
+    ("FramebufferTexture", &[]),
+    ("FramebufferTexture2D", &[]),
+    ("FramebufferTextureLayer", &[]),
+    ("FrontFace", &[]),
+    ("GenBuffers", &[]),
+    ("GenFramebuffers", &[]),
+    ("GenProgramPipelines", &[]),
+    ("GenQueries", &[]),
+    ("GenRenderbuffers", &[]),
+    ("GenSamplers", &[]),
+    ("GenTextures", &[]),
+    ("GenTransformFeedbacks", &[]),
+    ("GenVertexArrays", &[]),
+    ("GenerateMipmap", &[]),
+    (
+        "Get",
+        &[
+            "GetBooleanv",
+            "GetFloatv",
+            "GetIntegerv",
+            "GetInteger64v",
+            "GetBooleani_v",
+            "GetIntegeri_v",
+            "GetInteger64i_v",
+        ],
+    ),
+    ("GetActiveAttrib", &[]),
+    ("GetActiveUniform", &[]),
+    ("GetActiveUniformBlockName", &[]),
+    ("GetActiveUniformBlockiv", &[]),
+    ("GetActiveUniformsiv", &[]),
+    ("GetAttachedShaders", &[]),
+    ("GetAttribLocation", &[]),
+    (
+        "GetBufferParameter",
+        &["GetBufferParameteriv", "GetBufferParameteri64v"],
+    ),
+    ("GetBufferPointerv", &[]),
+    ("GetDebugMessageLog", &[]),
+    ("GetError", &[]),
+    ("GetFragDataLocation", &[]),
+    ("GetFramebufferAttachmentParameteriv", &[]),
+    ("GetFramebufferParameteriv", &[]),
+    ("GetGraphicsResetStatus", &[]),
+    ("GetInternalformativ", &[]),
+    ("GetMultisamplefv", &[]),
+    ("GetObjectLabel", &[]),
+    ("GetObjectPtrLabel", &[]),
+    ("GetPointerv", &[]),
+    ("GetProgramBinary", &[]),
+    ("GetProgramInfoLog", &[]),
+    ("GetProgramInterfaceiv", &[]),
+    ("GetProgramPipelineInfoLog", &[]),
+    ("GetProgramPipelineiv", &[]),
+    ("GetProgramResourceIndex", &[]),
+    ("GetProgramResourceLocation", &[]),
+    ("GetProgramResourceName", &[]),
+    ("GetProgramResourceiv", &[]),
+    ("GetProgramiv", &[]),
+    ("GetQueryObjectuiv", &[]),
+    ("GetQueryiv", &[]),
+    ("GetRenderbufferParameteriv", &[]),
+    (
+        "GetSamplerParameter",
+        &[
+            "GetSamplerParameterfv",
+            "GetSamplerParameteriv",
+            "GetSamplerParameterIiv",
+            "GetSamplerParameterIuiv",
+        ],
+    ),
+    ("GetShaderInfoLog", &[]),
+    ("GetShaderPrecisionFormat", &[]),
+    ("GetShaderSource", &[]),
+    ("GetShaderiv", &[]),
+    ("GetString", &["GetStringi"]),
+    ("GetSynciv", &[]),
+    (
+        "GetTexLevelParameter",
+        &["GetTexLevelParameterfv", "GetTexLevelParameteriv"],
+    ),
+    (
+        "GetTexParameter",
+        &[
+            "GetTexParameterfv",
+            "GetTexParameteriv",
+            "GetTexParameterIiv",
+            "GetTexParameterIuiv",
+        ],
+    ),
+    ("GetTransformFeedbackVarying", &[]),
+    (
+        "GetUniform",
+        &[
+            "GetUniformfv",
+            "GetUniformiv",
+            "GetUniformuiv",
+            "GetnUniformfv",
+            "GetnUniformiv",
+            "GetnUniformuiv",
+        ],
+    ),
+    ("GetUniformBlockIndex", &[]),
+    ("GetUniformIndices", &[]),
+    ("GetUniformLocation", &[]),
+    (
+        "GetVertexAttrib",
+        &[
+            "GetVertexAttribfv",
+            "GetVertexAttribiv",
+            "GetVertexAttribIiv",
+            "GetVertexAttribIuiv",
+        ],
+    ),
+    ("GetVertexAttribPointerv", &[]),
+    ("Hint", &[]),
+    ("InvalidateFramebuffer", &[]),
+    ("InvalidateSubFramebuffer", &[]),
+    ("IsBuffer", &[]),
+    ("IsEnabled", &["IsEnabledi"]),
+    ("IsFramebuffer", &[]),
+    ("IsProgram", &[]),
+    ("IsProgramPipeline", &[]),
+    ("IsQuery", &[]),
+    ("IsRenderbuffer", &[]),
+    ("IsSampler", &[]),
+    ("IsShader", &[]),
+    ("IsSync", &[]),
+    ("IsTexture", &[]),
+    ("IsTransformFeedback", &[]),
+    ("IsVertexArray", &[]),
+    ("LineWidth", &[]),
+    ("LinkProgram", &[]),
+    ("MapBufferRange", &[]),
+    ("MemoryBarrier", &["MemoryBarrierByRegion"]),
+    ("MinSampleShading", &[]),
+    ("ObjectLabel", &[]),
+    ("ObjectPtrLabel", &[]),
+    ("PatchParameteri", &[]),
+    ("PauseTransformFeedback", &[]),
+    ("PixelStorei", &[]),
+    ("PolygonOffset", &[]),
+    ("PopDebugGroup", &[]),
+    ("PrimitiveBoundingBox", &[]),
+    ("ProgramBinary", &[]),
+    ("ProgramParameteri", &[]),
+    (
+        "ProgramUniform",
+        &[
+            "ProgramUniform1f",
+            "ProgramUniform1i",
+            "ProgramUniform1ui",
+            "ProgramUniform2f",
+            "ProgramUniform2i",
+            "ProgramUniform2ui",
+            "ProgramUniform3f",
+            "ProgramUniform3i",
+            "ProgramUniform3ui",
+            "ProgramUniform4f",
+            "ProgramUniform4i",
+            "ProgramUniform4ui",
+            "ProgramUniform1fv",
+            "ProgramUniform1iv",
+            "ProgramUniform1uiv",
+            "ProgramUniform2fv",
+            "ProgramUniform2iv",
+            "ProgramUniform2uiv",
+            "ProgramUniform3fv",
+            "ProgramUniform3iv",
+            "ProgramUniform3uiv",
+            "ProgramUniform4fv",
+            "ProgramUniform4iv",
+            "ProgramUniform4uiv",
+            "ProgramUniformMatrix2fv",
+            "ProgramUniformMatrix3fv",
+            "ProgramUniformMatrix4fv",
+            "ProgramUniformMatrix2x3fv",
+            "ProgramUniformMatrix3x2fv",
+            "ProgramUniformMatrix2x4fv",
+            "ProgramUniformMatrix4x2fv",
+            "ProgramUniformMatrix3x4fv",
+            "ProgramUniformMatrix4x3fv",
+        ],
+    ),
+    ("PushDebugGroup", &[]),
+    ("ReadBuffer", &[]),
+    ("ReadPixels", &["ReadnPixels"]),
+    ("ReleaseShaderCompiler", &[]),
+    ("RenderbufferStorage", &[]),
+    ("RenderbufferStorageMultisample", &[]),
+    ("ResumeTransformFeedback", &[]),
+    ("SampleCoverage", &[]),
+    ("SampleMaski", &[]),
+    (
+        "SamplerParameter",
+        &[
+            "SamplerParameterf",
+            "SamplerParameteri",
+            "SamplerParameterfv",
+            "SamplerParameteriv",
+            "SamplerParameterIiv",
+            "SamplerParameterIuiv",
+        ],
+    ),
+    ("Scissor", &[]),
+    ("ShaderBinary", &[]),
+    ("ShaderSource", &[]),
+    ("StencilFunc", &[]),
+    ("StencilFuncSeparate", &[]),
+    ("StencilMask", &[]),
+    ("StencilMaskSeparate", &[]),
+    ("StencilOp", &[]),
+    ("StencilOpSeparate", &[]),
+    ("TexBuffer", &[]),
+    ("TexBufferRange", &[]),
+    ("TexImage2D", &[]),
+    ("TexImage3D", &[]),
+    (
+        "TexParameter",
+        &[
+            "TexParameterf",
+            "TexParameteri",
+            "TexParameterfv",
+            "TexParameteriv",
+            "TexParameterIiv",
+            "TexParameterIuiv",
+        ],
+    ),
+    ("TexStorage2D", &[]),
+    ("TexStorage2DMultisample", &[]),
+    ("TexStorage3D", &[]),
+    ("TexStorage3DMultisample", &[]),
+    ("TexSubImage2D", &[]),
+    ("TexSubImage3D", &[]),
+    ("TransformFeedbackVaryings", &[]),
+    (
+        "Uniform",
+        &[
+            "Uniform1f",
+            "Uniform1i",
+            "Uniform1ui",
+            "Uniform2f",
+            "Uniform2i",
+            "Uniform2ui",
+            "Uniform3f",
+            "Uniform3i",
+            "Uniform3ui",
+            "Uniform4f",
+            "Uniform4i",
+            "Uniform4ui",
+            "Uniform1fv",
+            "Uniform1iv",
+            "Uniform1uiv",
+            "Uniform2fv",
+            "Uniform2iv",
+            "Uniform2uiv",
+            "Uniform3fv",
+            "Uniform3iv",
+            "Uniform3uiv",
+            "Uniform4fv",
+            "Uniform4iv",
+            "Uniform4uiv",
+            "UniformMatrix2fv",
+            "UniformMatrix3fv",
+            "UniformMatrix4fv",
+            "UniformMatrix2x3fv",
+            "UniformMatrix3x2fv",
+            "UniformMatrix2x4fv",
+            "UniformMatrix4x2fv",
+            "UniformMatrix3x4fv",
+            "UniformMatrix4x3fv",
+        ],
+    ),
+    ("UniformBlockBinding", &[]),
+    ("UnmapBuffer", &[]),
+    ("UseProgram", &[]),
+    ("UseProgramStages", &[]),
+    ("ValidateProgram", &[]),
+    ("ValidateProgramPipeline", &[]),
+    (
+        "VertexAttrib",
+        &[
+            "VertexAttrib1f",
+            "VertexAttrib1fv",
+            "VertexAttrib1s",
+            "VertexAttrib1sv",
+            "VertexAttrib1d",
+            "VertexAttrib1dv",
+            "VertexAttrib2f",
+            "VertexAttrib2fv",
+            "VertexAttrib2s",
+            "VertexAttrib2sv",
+            "VertexAttrib2d",
+            "VertexAttrib2dv",
+            "VertexAttrib3f",
+            "VertexAttrib3fv",
+            "VertexAttrib3s",
+            "VertexAttrib3sv",
+            "VertexAttrib3d",
+            "VertexAttrib3dv",
+            "VertexAttrib4f",
+            "VertexAttrib4fv",
+            "VertexAttrib4s",
+            "VertexAttrib4sv",
+            "VertexAttrib4d",
+            "VertexAttrib4dv",
+            "VertexAttrib4Nub",
+            "VertexAttrib4iv",
+            "VertexAttrib4bv",
+            "VertexAttrib4ubv",
+            "VertexAttrib4usv",
+            "VertexAttrib4uiv",
+            "VertexAttrib4Nbv",
+            "VertexAttrib4Nsv",
+            "VertexAttrib4Niv",
+            "VertexAttrib4Nubv",
+            "VertexAttrib4Nusv",
+            "VertexAttrib4Nuiv",
+            "VertexAttribI1i",
+            "VertexAttribI1iv",
+            "VertexAttribI1ui",
+            "VertexAttribI1uiv",
+            "VertexAttribI2i",
+            "VertexAttribI2iv",
+            "VertexAttribI2ui",
+            "VertexAttribI2uiv",
+            "VertexAttribI3i",
+            "VertexAttribI3iv",
+            "VertexAttribI3ui",
+            "VertexAttribI3uiv",
+            "VertexAttribI4i",
+            "VertexAttribI4iv",
+            "VertexAttribI4ui",
+            "VertexAttribI4uiv",
+            "VertexAttribI4bv",
+            "VertexAttribI4sv",
+            "VertexAttribI4ubv",
+            "VertexAttribI4usv",
+        ],
+    ),
+    ("VertexAttribBinding", &[]),
+    ("VertexAttribDivisor", &[]),
+    ("VertexAttribFormat", &["VertexAttribIFormat"]),
+    ("VertexAttribPointer", &["VertexAttribIPointer"]),
+    ("VertexBindingDivisor", &[]),
+    ("Viewport", &[]),
+    ("WaitSync", &[]),
+];
+
+/// The EGL reference pages, which each document the command with their name.
+pub const EGL: &[&str] = &[
+    "BindAPI",
+    "BindTexImage",
+    "ChooseConfig",
+    "ClientWaitSync",
+    "CopyBuffers",
+    "CreateContext",
+    "CreateImage",
+    "CreatePbufferFromClientBuffer",
+    "CreatePbufferSurface",
+    "CreatePixmapSurface",
+    "CreatePlatformPixmapSurface",
+    "CreatePlatformWindowSurface",
+    "CreateSync",
+    "CreateWindowSurface",
+    "DestroyContext",
+    "DestroyImage",
+    "DestroySurface",
+    "DestroySync",
+    "GetConfigAttrib",
+    "GetConfigs",
+    "GetCurrentContext",
+    "GetCurrentDisplay",
+    "GetCurrentSurface",
+    "GetDisplay",
+    "GetError",
+    "GetPlatformDisplay",
+    "GetProcAddress",
+    "GetSyncAttrib",
+    "Initialize",
+    "MakeCurrent",
+    "QueryAPI",
+    "QueryContext",
+    "QueryString",
+    "QuerySurface",
+    "ReleaseTexImage",
+    "ReleaseThread",
+    "SurfaceAttrib",
+    "SwapBuffers",
+    "SwapInterval",
+    "Terminate",
+    "WaitClient",
+    "WaitGL",
+    "WaitNative",
+    "WaitSync",
+];
+
+/// Returns the page of `pages` that documents the command `name`.
+pub fn find(pages: &[(&'static str, &[&str])], name: &str) -> Option<&'static str> {
+    pages
+        .iter()
+        .find(|&&(page, cmds)| page == name || cmds.contains(&name))
+        .map(|&(page, _)| page)
+}
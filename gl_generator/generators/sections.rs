@@ -0,0 +1,357 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The sections that the bindings are made of, to be reused by custom generators.
+//!
+//! Each section is written by a function that takes a `GenContext`. `StructSections` writes the
+//! bindings of `StructGenerator` from these functions, and any of its sections can be replaced,
+//! down to the body of a single method. `StructGeneratorWith` turns a `StructSections` into a
+//! `Generator`.
+
+use registry::{Cmd, Registry};
+use std::io;
+use Api;
+
+use super::GeneratorOptions;
+
+/// The imports of `GenContext::new`, which are needed by the sections of `StructSections`.
+const STRUCT_IMPORTS: &[&str] = &[
+    "std::fmt",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::process",
+    "std::sync::RwLock",
+];
+
+/// The registry and options that the sections of the bindings are generated from.
+#[derive(Clone, Debug)]
+pub struct GenContext<'a> {
+    registry: &'a Registry,
+    imports: Vec<String>,
+    options: GeneratorOptions,
+}
+
+impl<'a> GenContext<'a> {
+    /// Creates a context for `registry`, with the imports needed by the sections of
+    /// `StructSections`.
+    pub fn new(registry: &'a Registry) -> GenContext<'a> {
+        GenContext {
+            registry,
+            imports: STRUCT_IMPORTS.iter().map(|path| path.to_string()).collect(),
+            options: GeneratorOptions::default(),
+        }
+    }
+
+    /// Sets the options of the items written by the sections.
+    pub fn with_options(mut self, options: GeneratorOptions) -> GenContext<'a> {
+        self.options = options;
+        self
+    }
+
+    /// Adds a path to the `__gl_imports` module written by `write_header`, for example
+    /// `"std::sync::Arc"`.
+    pub fn with_import(mut self, path: &str) -> GenContext<'a> {
+        self.imports.push(path.to_string());
+        self
+    }
+
+    /// Replaces the paths of the `__gl_imports` module written by `write_header`.
+    pub fn with_imports<I>(mut self, paths: I) -> GenContext<'a>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.imports = paths.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the registry that the bindings are generated from.
+    pub fn registry(&self) -> &'a Registry {
+        self.registry
+    }
+
+    /// Returns the API of the registry.
+    pub fn api(&self) -> Api {
+        self.registry.api
+    }
+
+    /// Returns the paths of the `__gl_imports` module written by `write_header`.
+    pub fn imports(&self) -> &[String] {
+        &self.imports
+    }
+
+    /// Returns the options of the items written by the sections.
+    pub fn options(&self) -> &GeneratorOptions {
+        &self.options
+    }
+
+    /// Returns the name of the struct that stores the function pointers, for example `"Gl"`.
+    pub fn struct_name(&self) -> &'static str {
+        super::gen_struct_name(self.registry.api)
+    }
+
+    /// Returns the native symbol of a command, for example `"glClear"`.
+    pub fn symbol_name(&self, cmd: &Cmd) -> String {
+        super::gen_symbol_name(self.registry.api, &cmd.proto.ident)
+    }
+
+    /// Returns the name of the function that calls a command, as given by the naming options,
+    /// for example `"Clear"` or `"clear"`.
+    pub fn fn_name(&self, cmd: &Cmd) -> String {
+        self.options.cmd_name(self.registry.api, &cmd.proto.ident)
+    }
+
+    /// Returns the commands that are loaded when a command can't be found, without their
+    /// prefix.
+    pub fn fallbacks(&self, cmd: &Cmd) -> &'a [String] {
+        match self.registry.aliases.get(&cmd.proto.ident) {
+            Some(fallbacks) => fallbacks,
+            None => &[],
+        }
+    }
+}
+
+/// Creates a `__gl_imports` module which contains the imports of the context, for the items of
+/// the bindings to refer to.
+pub fn write_header<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(dest, "mod __gl_imports {{")?;
+    for path in ctx.imports() {
+        writeln!(dest, "pub use {path};")?;
+    }
+    writeln!(dest, "}}")
+}
+
+/// Creates a `types` module which contains all the type aliases.
+///
+/// See also `generators::gen_types`.
+pub fn write_type_aliases<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        "{vis} mod types {{ {allow}",
+        vis = ctx.options().vis(),
+        allow = ctx.options().inner_allow(
+            "non_camel_case_types, non_snake_case, dead_code, missing_copy_implementations, \
+             clippy::upper_case_acronyms"
+        ),
+    )?;
+
+    super::gen_types(ctx.api(), dest)?;
+
+    writeln!(dest, "}}")
+}
+
+/// Creates all the `<enum>` elements at the root of the bindings, documented by `gen_enum_doc`.
+pub fn write_enums<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    for enm in &ctx.registry().enums {
+        write!(dest, "{}", super::gen_enum_doc(ctx.registry(), enm))?;
+        super::gen_enum_item_with_options(enm, ctx.api(), "types::", ctx.options(), dest)?;
+    }
+
+    Ok(())
+}
+
+/// Creates a `FnPtr` structure which contains the store for a single binding.
+///
+/// The `__gl_imports` module must export `mem` and `os::raw`.
+pub fn write_fn_ptr_struct<W>(_ctx: &GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    super::struct_gen::write_fnptr_struct_def(dest)
+}
+
+/// Creates a structure which stores a `FnPtr` for each command, named after the API.
+pub fn write_struct<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    super::struct_gen::write_struct(ctx, dest)
+}
+
+/// Creates the `load_with` and `load_with_report` functions of the structure created by
+/// `write_struct`, to be written inside its `impl`.
+pub fn write_load_fns<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    super::struct_gen::write_load_fns(ctx, dest)
+}
+
+/// The sections of the bindings written by `StructGenerator`. Each of them can be replaced,
+/// and the others are written as they are by `StructGenerator`.
+///
+/// `StructGeneratorWith` writes the bindings from the sections of a type that implements this
+/// trait:
+///
+/// ```no_run
+/// # use gl_generator::{Cmd, GenContext, StructSections};
+/// struct CountingGenerator;
+///
+/// impl StructSections for CountingGenerator {
+///     fn gen_body(&self, ctx: &GenContext, cmd: &Cmd) -> String {
+///         format!("CALLS.fetch_add(1, ::std::sync::atomic::Ordering::Relaxed); {}",
+///                 StructSections::gen_body(&gl_generator::StructGenerator, ctx, cmd))
+///     }
+/// }
+///
+/// # let registry = gl_generator::Registry::new(gl_generator::Api::Gl, (4, 6),
+/// #     gl_generator::Profile::Core, gl_generator::Fallbacks::All, []);
+/// # let mut file = Vec::new();
+/// registry.write_bindings(gl_generator::StructGeneratorWith(CountingGenerator), &mut file)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub trait StructSections {
+    /// Writes the `__gl_imports` module. See `write_header`.
+    fn write_header<W>(&self, ctx: &GenContext, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write_header(ctx, dest)
+    }
+
+    /// Writes the `types` module. See `write_type_aliases`.
+    fn write_type_aliases<W>(&self, ctx: &GenContext, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write_type_aliases(ctx, dest)
+    }
+
+    /// Writes the enums. See `write_enums`.
+    fn write_enums<W>(&self, ctx: &GenContext, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write_enums(ctx, dest)
+    }
+
+    /// Writes the items that the structure relies on: `FnPtr`, the stubs of the commands that
+    /// could not be loaded, and `LoadReport`.
+    fn write_support<W>(&self, ctx: &GenContext, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write_fn_ptr_struct(ctx, dest)?;
+        super::gen_missing_fns(ctx.registry(), dest)?;
+        super::gen_load_report(ctx.registry(), dest)
+    }
+
+    /// Writes the structure. See `write_struct`.
+    fn write_struct<W>(&self, ctx: &GenContext, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write_struct(ctx, dest)
+    }
+
+    /// Writes the `impl` of the structure, with the load functions and a method for each
+    /// command.
+    fn write_impl<W>(&self, ctx: &GenContext, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        writeln!(dest, "impl {api} {{", api = ctx.struct_name())?;
+
+        write_load_fns(ctx, dest)?;
+
+        for cmd in &ctx.registry().cmds {
+            self.write_method(ctx, cmd, dest)?;
+        }
+
+        writeln!(
+            dest,
+            "}}
+
+        unsafe impl __gl_imports::Send for {api} {{}}",
+            api = ctx.struct_name()
+        )
+    }
+
+    /// Writes the method that calls `cmd`, documented by `gen_cmd_doc`, with the body returned by
+    /// `gen_body`.
+    fn write_method<W>(&self, ctx: &GenContext, cmd: &Cmd, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write!(dest, "{}", super::gen_cmd_doc(ctx.registry(), cmd))?;
+        writeln!(
+            dest,
+            "{attrs} {inline} {vis} unsafe fn {name}(&self, {params}) -> {return_suffix} {{ \
+                {body} \
+            }}",
+            attrs = ctx.options().fn_attrs(&ctx.symbol_name(cmd)),
+            inline = ctx.options().inline_attr(),
+            vis = ctx.options().vis(),
+            name = ctx.fn_name(cmd),
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+            body = self.gen_body(ctx, cmd),
+        )
+    }
+
+    /// Returns the body of the method that calls `cmd`, which calls its function pointer.
+    fn gen_body(&self, _ctx: &GenContext, cmd: &Cmd) -> String {
+        format!(
+            "(self.{name}.f)({idents})",
+            name = cmd.proto.ident,
+            idents = super::gen_parameters(cmd, true, false).join(", "),
+        )
+    }
+}
+
+/// A `Generator` that writes the bindings of `StructGenerator` with the sections of `S`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StructGeneratorWith<S>(pub S);
+
+impl<S> super::Generator for StructGeneratorWith<S>
+where
+    S: StructSections,
+{
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        self.write_with_options(registry, &GeneratorOptions::default(), dest)
+    }
+
+    fn write_with_options<W>(
+        &self,
+        registry: &Registry,
+        options: &GeneratorOptions,
+        dest: &mut W,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = GenContext::new(registry).with_options(options.clone());
+        let sections = &self.0;
+        sections.write_header(&ctx, dest)?;
+        sections.write_type_aliases(&ctx, dest)?;
+        sections.write_enums(&ctx, dest)?;
+        sections.write_support(&ctx, dest)?;
+        sections.write_struct(&ctx, dest)?;
+        sections.write_impl(&ctx, dest)?;
+        Ok(())
+    }
+}
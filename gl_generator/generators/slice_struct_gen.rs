@@ -0,0 +1,432 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::{Binding, Cmd, Registry};
+use std::collections::BTreeMap;
+use std::io;
+
+/// Generates the struct of `StructGenerator`, with an additional snake_case method for each
+/// command whose pointer parameters all have a known length. These methods take slices, arrays
+/// and references instead of pointers, and compute the length parameters from the slices.
+///
+/// The methods are safe, because the lengths that the command reads or writes are checked against
+/// the slices. They stay `unsafe` when the command also takes a value that is itself a pointer,
+/// such as a `GLsync` or a callback.
+///
+/// The sizes of the arrays and slices come from the `len` attribute of each pointer parameter.
+/// The vector forms of commands, which the registry links to a scalar form with `vecequiv`, such
+/// as `Color3fv` for `Color3f`, take a pointer with a `len` of `1` as an array of one element
+/// rather than a reference, and mention their scalar form in their documentation.
+#[allow(missing_copy_implementations)]
+pub struct SliceStructGenerator;
+
+impl super::Generator for SliceStructGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        super::Generator::write(&super::struct_gen::StructGenerator, registry, dest)?;
+        write_slice_len(dest)?;
+        write_impl(registry, dest)?;
+        Ok(())
+    }
+}
+
+/// How a parameter of a command is passed to its wrapper.
+enum WrapperParam<'a> {
+    /// Passed as it is.
+    Value(&'a Binding),
+    /// The length of the slices that follow it, computed from the first one.
+    Len(&'a Binding),
+    /// A pointer to as many elements as the parameter `len`, taken as a slice. When the length is
+    /// a multiple of the parameter, like `count*4`, the slice is made of arrays of `chunk`
+    /// elements.
+    Slice {
+        param: &'a Binding,
+        elem_ty: &'a str,
+        mutable: bool,
+        len: &'a Binding,
+        chunk: Option<&'a str>,
+    },
+    /// A pointer to a fixed number of elements, taken as a reference to an array.
+    Array {
+        param: &'a Binding,
+        elem_ty: &'a str,
+        mutable: bool,
+        size: &'a str,
+    },
+    /// A pointer to a single element, taken as a reference.
+    Ref { param: &'a Binding, mutable: bool },
+    /// An array of strings together with an array of their lengths, taken as `&[&str]`.
+    Strs {
+        param: &'a Binding,
+        len: &'a Binding,
+    },
+    /// The lengths of the strings of a `Strs` parameter, computed from them.
+    StrLens(&'a Binding),
+    /// An array of NUL-terminated strings, taken as `&[&str]` and copied into `CString`s.
+    CStrs {
+        param: &'a Binding,
+        len: &'a Binding,
+    },
+}
+
+/// Integer types of the parameters that can give the length of a slice.
+const LEN_TYPES: &[&str] = &[
+    "types::GLsizei",
+    "types::GLint",
+    "types::GLuint",
+    "types::GLsizeiptr",
+    "types::GLsizeiptrARB",
+    "types::GLintptr",
+];
+
+/// Returns how each parameter of `cmd` is passed to its wrapper, or `None` if it has no pointer
+/// parameter or one of them can't be taken as a slice, an array or a reference.
+///
+/// `is_vector` tells whether `cmd` is the vector form of another command, in which case a
+/// pointer to a single element is taken as an array too.
+fn wrapper_params<'a>(cmd: &'a Cmd, is_vector: bool) -> Option<Vec<WrapperParam<'a>>> {
+    let find_len = |len: &str| {
+        cmd.params
+            .iter()
+            .find(|p| p.ident == *len || p.ident == format!("{len}_"))
+            .filter(|p| LEN_TYPES.contains(&&p.ty[..]))
+    };
+
+    let mut params = cmd
+        .params
+        .iter()
+        .map(|param| {
+            let (mutable, elem_ty) = if let Some(elem_ty) = param.ty.strip_prefix("*const ") {
+                (false, elem_ty)
+            } else if let Some(elem_ty) = param.ty.strip_prefix("*mut ") {
+                (true, elem_ty)
+            } else {
+                return Some(WrapperParam::Value(param));
+            };
+            if elem_ty == "*const types::GLchar" && !mutable {
+                let len = find_len(param.len.as_ref()?)?;
+                return Some(WrapperParam::Strs { param, len });
+            }
+            if elem_ty.starts_with('*') {
+                return None;
+            }
+            let len = param.len.as_deref()?;
+            if len == "1" && !is_vector {
+                return Some(WrapperParam::Ref { param, mutable });
+            }
+            let elem_ty = match elem_ty {
+                "__gl_imports::raw::c_void" => "u8",
+                elem_ty => elem_ty,
+            };
+            if len.parse::<usize>().is_ok() {
+                return Some(WrapperParam::Array {
+                    param,
+                    elem_ty,
+                    mutable,
+                    size: len,
+                });
+            }
+            let (len, chunk) = match len.split_once('*') {
+                Some((len, "1")) => (len, None),
+                Some((len, chunk)) if chunk.parse::<usize>().is_ok() => (len, Some(chunk)),
+                Some(_) => return None,
+                None => (len, None),
+            };
+            Some(WrapperParam::Slice {
+                param,
+                elem_ty,
+                mutable,
+                len: find_len(len)?,
+                chunk,
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut lens = Vec::new();
+    let mut has_pointers = false;
+    for param in &params {
+        match *param {
+            WrapperParam::Slice { len, .. } => lens.push(len.ident.clone()),
+            WrapperParam::Strs { len, .. } => lens.push(len.ident.clone()),
+            WrapperParam::Array { .. } => has_pointers = true,
+            _ => (),
+        }
+    }
+    if lens.is_empty() && !has_pointers {
+        return None;
+    }
+
+    // the lengths of the strings are an input array with the same length as the strings
+    let has_strs = params
+        .iter()
+        .any(|param| matches!(param, WrapperParam::Strs { .. }));
+    for param in &mut params {
+        match *param {
+            WrapperParam::Value(value) if lens.contains(&value.ident) => {
+                *param = WrapperParam::Len(value);
+            },
+            WrapperParam::Slice {
+                param: value,
+                elem_ty: "types::GLint",
+                mutable: false,
+                ..
+            } if has_strs => {
+                *param = WrapperParam::StrLens(value);
+            },
+            _ => (),
+        }
+    }
+
+    // without lengths, the strings must be NUL-terminated
+    let has_str_lens = params
+        .iter()
+        .any(|param| matches!(param, WrapperParam::StrLens(_)));
+    if !has_str_lens {
+        for param in &mut params {
+            if let WrapperParam::Strs { param: value, len } = *param {
+                *param = WrapperParam::CStrs { param: value, len };
+            }
+        }
+    }
+    Some(params)
+}
+
+/// Returns `true` if a parameter of type `ty` can't point to memory, so that it can be passed by
+/// a safe method.
+fn is_plain_value(ty: &str) -> bool {
+    let mut ty = ty.to_string();
+    loop {
+        let resolved = super::resolve_type(&ty);
+        if resolved == ty {
+            break;
+        }
+        // the aliases refer to each other without the `types::` prefix
+        let prefixed = format!("types::{resolved}");
+        ty = match super::resolve_type(&prefixed) == prefixed {
+            true => resolved.to_string(),
+            false => prefixed,
+        };
+    }
+    match ty.strip_prefix("super::__gl_imports::raw::") {
+        Some(raw) => raw != "c_void",
+        None => ["isize", "usize", "i64", "u64", "f32", "f64"].contains(&&ty[..]),
+    }
+}
+
+/// Creates the function that converts the length of a slice to the type of a length parameter.
+fn write_slice_len<W>(dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// Converts the length of a slice to the type of a length parameter.
+        #[allow(dead_code)]
+        #[inline]
+        fn slice_len<T>(len: usize) -> T
+        where
+            T: ::std::convert::TryFrom<usize>,
+            T::Error: ::std::fmt::Debug,
+        {{
+            T::try_from(len).expect("the slice is too long")
+        }}
+    "#
+    )
+}
+
+/// Creates a second `impl` of the structure created by `write_struct`, with the methods that
+/// take slices.
+fn write_impl<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        "impl {api} {{",
+        api = super::gen_struct_name(registry.api)
+    )?;
+
+    // the vector form of each command, like `Color3fv` for `Color3f`
+    let scalar_forms = registry
+        .cmds
+        .iter()
+        .filter_map(|cmd| Some((&cmd.vecequiv.as_ref()?[..], &cmd.proto.ident[..])))
+        .collect::<BTreeMap<_, _>>();
+
+    for cmd in &registry.cmds {
+        let scalar_form = scalar_forms.get(&cmd.proto.ident[..]);
+        let params = match wrapper_params(cmd, scalar_form.is_some()) {
+            Some(params) => params,
+            None => continue,
+        };
+
+        // the first slice of each length parameter gives its value, and the others must have the
+        // same length
+        let mut firsts: Vec<(&str, &str)> = Vec::new();
+        let mut body = String::new();
+        for param in &params {
+            let (param, len) = match *param {
+                WrapperParam::Slice { param, len, .. } => (param, len),
+                WrapperParam::Strs { param, len } | WrapperParam::CStrs { param, len } => {
+                    (param, len)
+                },
+                _ => continue,
+            };
+            match firsts.iter().find(|&&(l, _)| l == len.ident) {
+                Some(&(_, first)) => {
+                    body += &format!(
+                        "assert_eq!({ident}.len(), {first}.len(), \"`{ident}` and `{first}` must have the same length\");\n",
+                        ident = param.ident,
+                    );
+                },
+                None => firsts.push((&len.ident, &param.ident)),
+            }
+        }
+        let strs = params.iter().find_map(|param| match *param {
+            WrapperParam::Strs { param, .. } => Some(&param.ident),
+            _ => None,
+        });
+
+        let mut typed_params = Vec::new();
+        let mut args = Vec::new();
+        for param in &params {
+            match *param {
+                WrapperParam::Value(param) => {
+                    typed_params.push(format!("{}: {}", param.ident, param.ty));
+                    args.push(param.ident.clone());
+                },
+                WrapperParam::Len(param) => {
+                    let &(_, first) = firsts.iter().find(|&&(len, _)| len == param.ident).unwrap();
+                    body += &format!("let {} = slice_len({first}.len());\n", param.ident);
+                    args.push(param.ident.clone());
+                },
+                WrapperParam::Slice {
+                    param,
+                    elem_ty,
+                    mutable,
+                    chunk,
+                    ..
+                } => {
+                    let (reference, as_ptr) = match mutable {
+                        true => ("&mut ", "as_mut_ptr()"),
+                        false => ("&", "as_ptr()"),
+                    };
+                    match chunk {
+                        Some(chunk) => typed_params.push(format!(
+                            "{}: {reference}[[{elem_ty}; {chunk}]]",
+                            param.ident
+                        )),
+                        None => {
+                            typed_params.push(format!("{}: {reference}[{elem_ty}]", param.ident))
+                        },
+                    }
+                    args.push(match (elem_ty, chunk) {
+                        ("u8", _) | (_, Some(_)) => format!("{}.{as_ptr}.cast()", param.ident),
+                        _ => format!("{}.{as_ptr}", param.ident),
+                    });
+                },
+                WrapperParam::Array {
+                    param,
+                    elem_ty,
+                    mutable,
+                    size,
+                } => {
+                    let (reference, as_ptr) = match mutable {
+                        true => ("&mut ", "as_mut_ptr()"),
+                        false => ("&", "as_ptr()"),
+                    };
+                    typed_params.push(format!("{}: {reference}[{elem_ty}; {size}]", param.ident));
+                    args.push(match elem_ty {
+                        "u8" => format!("{}.{as_ptr}.cast()", param.ident),
+                        _ => format!("{}.{as_ptr}", param.ident),
+                    });
+                },
+                WrapperParam::Ref { param, mutable } => {
+                    let elem_ty = match mutable {
+                        true => format!("&mut {}", &param.ty["*mut ".len()..]),
+                        false => format!("&{}", &param.ty["*const ".len()..]),
+                    };
+                    typed_params.push(format!("{}: {elem_ty}", param.ident));
+                    args.push(param.ident.clone());
+                },
+                WrapperParam::Strs { param, .. } => {
+                    typed_params.push(format!("{}: &[&str]", param.ident));
+                    body += &format!(
+                        "let {ident}_ptrs = {ident}.iter().map(|s| s.as_ptr().cast()).collect::<Vec<_>>();\n",
+                        ident = param.ident,
+                    );
+                    args.push(format!("{}_ptrs.as_ptr()", param.ident));
+                },
+                WrapperParam::CStrs { param, .. } => {
+                    typed_params.push(format!("{}: &[&str]", param.ident));
+                    body += &format!(
+                        "let {ident}_c_strs = {ident}.iter().map(|&s| ::std::ffi::CString::new(s).expect(\"the string contains a NUL byte\")).collect::<Vec<_>>();
+                        let {ident}_ptrs = {ident}_c_strs.iter().map(|s| s.as_ptr().cast()).collect::<Vec<_>>();\n",
+                        ident = param.ident,
+                    );
+                    args.push(format!("{}_ptrs.as_ptr()", param.ident));
+                },
+                WrapperParam::StrLens(param) => {
+                    body += &format!(
+                        "let {ident} = {strs}.iter().map(|s| slice_len(s.len())).collect::<Vec<types::GLint>>();\n",
+                        ident = param.ident,
+                        strs = strs.unwrap(),
+                    );
+                    args.push(format!("{}.as_ptr()", param.ident));
+                },
+            }
+        }
+
+        let is_safe = params.iter().all(|param| match *param {
+            WrapperParam::Value(param) => is_plain_value(&param.ty),
+            _ => true,
+        });
+        let (qualifier, call) = match is_safe {
+            true => (
+                "",
+                format!("unsafe {{ self.{}({}) }}", cmd.proto.ident, args.join(", ")),
+            ),
+            false => (
+                "unsafe ",
+                format!("self.{}({})", cmd.proto.ident, args.join(", ")),
+            ),
+        };
+
+        let doc = match scalar_form {
+            Some(scalar_form) => format!(
+                " This is the array form of `{}`.",
+                super::gen_symbol_name(registry.api, scalar_form)
+            ),
+            None => String::new(),
+        };
+        writeln!(
+            dest,
+            "/// Calls `{symbol}`, with slices instead of pointers and lengths.{doc}
+            #[allow(non_snake_case, dead_code, clippy::too_many_arguments)]
+            #[inline] pub {qualifier}fn {name}(&self, {typed_params}) -> {return_suffix} {{
+                {body}
+                {call}
+            }}",
+            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
+            name = super::snake_case(&cmd.proto.ident),
+            typed_params = typed_params.join(", "),
+            return_suffix = cmd.proto.ty,
+        )?;
+    }
+
+    writeln!(dest, "}}")
+}
@@ -15,6 +15,8 @@
 use registry::Registry;
 use std::io;
 
+use super::{GenContext, GeneratorOptions};
+
 #[allow(missing_copy_implementations)]
 pub struct StaticGenerator;
 
@@ -23,89 +25,60 @@ impl super::Generator for StaticGenerator {
     where
         W: io::Write,
     {
-        write_header(dest)?;
-        write_type_aliases(registry, dest)?;
-        write_enums(registry, dest)?;
-        write_fns(registry, dest)?;
-        Ok(())
+        self.write_with_options(registry, &GeneratorOptions::default(), dest)
     }
-}
 
-/// Creates a `__gl_imports` module which contains all the external symbols that we need for the
-///  bindings.
-fn write_header<W>(dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    writeln!(
-        dest,
-        r#"
-        mod __gl_imports {{
-            pub use std::mem;
-            pub use std::os::raw;
-        }}
-    "#
-    )
-}
-
-/// Creates a `types` module which contains all the type aliases.
-///
-/// See also `generators::gen_types`.
-fn write_type_aliases<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    writeln!(
-        dest,
-        r#"
-        pub mod types {{
-            #![allow(non_camel_case_types, non_snake_case, dead_code, missing_copy_implementations)]
-    "#
-    )?;
-
-    super::gen_types(registry.api, dest)?;
-
-    writeln!(
-        dest,
-        "
-        }}
-    "
-    )
-}
-
-/// Creates all the `<enum>` elements at the root of the bindings.
-fn write_enums<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    for enm in &registry.enums {
-        super::gen_enum_item(enm, "types::", dest)?;
+    fn write_with_options<W>(
+        &self,
+        registry: &Registry,
+        options: &GeneratorOptions,
+        dest: &mut W,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = GenContext::new(registry)
+            .with_imports(IMPORTS.iter().copied())
+            .with_options(options.clone());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        write_fns(&ctx, dest)?;
+        Ok(())
     }
-
-    Ok(())
 }
 
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &["std::os::raw"];
+
 /// io::Writes all functions corresponding to the GL bindings.
 ///
 /// These are foreign functions, they don't have any content.
-fn write_fns<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+fn write_fns<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
     writeln!(
         dest,
         "
-        #[allow(non_snake_case, unused_variables, dead_code)]
-        extern \"system\" {{"
+        {allow}
+        extern \"system\" {{",
+        allow = ctx
+            .options()
+            .allow("non_snake_case, unused_variables, dead_code"),
     )?;
 
-    for cmd in &registry.cmds {
+    for cmd in &ctx.registry().cmds {
+        let symbol = ctx.symbol_name(cmd);
+        write!(dest, "{}", super::gen_cmd_doc(ctx.registry(), cmd))?;
         writeln!(
             dest,
-            "#[link_name=\"{symbol}\"]
-            pub fn {name}({params}) -> {return_suffix};",
-            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
-            name = cmd.proto.ident,
+            "{attrs} #[link_name=\"{symbol}\"]
+            {vis} fn {name}({params}) -> {return_suffix};",
+            attrs = ctx.options().fn_attrs(&symbol),
+            vis = ctx.options().vis(),
+            name = ctx.fn_name(cmd),
             params = super::gen_parameters(cmd, true, true).join(", "),
             return_suffix = cmd.proto.ty,
         )?;
@@ -15,6 +15,8 @@
 use registry::Registry;
 use std::io;
 
+use super::{GenContext, GeneratorOptions};
+
 #[allow(missing_copy_implementations)]
 pub struct StaticStructGenerator;
 
@@ -23,108 +25,85 @@ impl super::Generator for StaticStructGenerator {
     where
         W: io::Write,
     {
-        write_header(dest)?;
-        write_type_aliases(registry, dest)?;
-        write_enums(registry, dest)?;
-        write_struct(registry, dest)?;
-        write_impl(registry, dest)?;
-        write_fns(registry, dest)?;
-        Ok(())
+        self.write_with_options(registry, &GeneratorOptions::default(), dest)
     }
-}
 
-/// Creates a `__gl_imports` module which contains all the external symbols that we need for the
-///  bindings.
-fn write_header<W>(dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    writeln!(
-        dest,
-        r#"
-        mod __gl_imports {{
-            pub use std::mem;
-            pub use std::os::raw;
-        }}
-    "#
-    )
-}
-
-/// Creates a `types` module which contains all the type aliases.
-///
-/// See also `generators::gen_types`.
-fn write_type_aliases<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    writeln!(
-        dest,
-        r#"
-        pub mod types {{
-            #![allow(non_camel_case_types, non_snake_case, dead_code, missing_copy_implementations)]
-    "#
-    )?;
-
-    super::gen_types(registry.api, dest)?;
-
-    writeln!(dest, "}}")
-}
-
-/// Creates all the `<enum>` elements at the root of the bindings.
-fn write_enums<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    for enm in &registry.enums {
-        super::gen_enum_item(enm, "types::", dest)?;
+    fn write_with_options<W>(
+        &self,
+        registry: &Registry,
+        options: &GeneratorOptions,
+        dest: &mut W,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = GenContext::new(registry)
+            .with_imports(IMPORTS.iter().copied())
+            .with_options(options.clone());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        write_struct(&ctx, dest)?;
+        write_impl(&ctx, dest)?;
+        write_fns(&ctx, dest)?;
+        Ok(())
     }
-
-    Ok(())
 }
 
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &["std::os::raw"];
+
 /// Creates a stub structure.
 ///
 /// The name of the struct corresponds to the namespace.
-fn write_struct<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+fn write_struct<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
     writeln!(
         dest,
         "
-        #[allow(non_camel_case_types, non_snake_case, dead_code)]
+        {allow}
         #[derive(Copy, Clone)]
-        pub struct {api};",
-        api = super::gen_struct_name(registry.api),
+        {vis} struct {api};",
+        allow = ctx
+            .options()
+            .allow("non_camel_case_types, non_snake_case, dead_code"),
+        vis = ctx.options().vis(),
+        api = ctx.struct_name(),
     )
 }
 
 /// Creates the `impl` of the structure created by `write_struct`.
-fn write_impl<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+fn write_impl<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
     writeln!(dest,
         "impl {api} {{
             /// Stub function.
-            #[allow(dead_code)]
-            pub fn load_with<F>(mut _loadfn: F) -> {api} where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
+            {allow} {vis} fn load_with<F>(mut _loadfn: F) -> {api} where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
                 {api}
             }}",
-        api = super::gen_struct_name(registry.api),
+        allow = ctx.options().allow("dead_code"),
+        vis = ctx.options().vis(),
+        api = ctx.struct_name(),
     )?;
 
-    for cmd in &registry.cmds {
+    for cmd in &ctx.registry().cmds {
+        write!(dest, "{}", super::gen_cmd_doc(ctx.registry(), cmd))?;
         writeln!(
             dest,
-            "#[allow(non_snake_case)]
-            // #[allow(unused_variables)]
-            #[allow(dead_code)]
-            #[inline]
-            pub unsafe fn {name}(&self, {typed_params}) -> {return_suffix} {{
-                {name}({idents})
+            "{attrs} {inline}
+            {vis} unsafe fn {name}(&self, {typed_params}) -> {return_suffix} {{
+                {ident}({idents})
             }}",
-            name = cmd.proto.ident,
+            attrs = ctx.options().fn_attrs(&ctx.symbol_name(cmd)),
+            inline = ctx.options().inline_attr(),
+            vis = ctx.options().vis(),
+            name = ctx.fn_name(cmd),
+            ident = cmd.proto.ident,
             typed_params = super::gen_parameters(cmd, true, true).join(", "),
             return_suffix = cmd.proto.ty,
             idents = super::gen_parameters(cmd, true, false).join(", "),
@@ -137,24 +116,25 @@ where
 /// io::Writes all functions corresponding to the GL bindings.
 ///
 /// These are foreign functions, they don't have any content.
-fn write_fns<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+fn write_fns<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
     writeln!(
         dest,
         "
-        #[allow(non_snake_case)]
-        #[allow(unused_variables)]
-        #[allow(dead_code)]
-        extern \"system\" {{"
+        {allow}
+        extern \"system\" {{",
+        allow = ctx
+            .options()
+            .allow("non_snake_case, unused_variables, dead_code"),
     )?;
 
-    for cmd in &registry.cmds {
+    for cmd in &ctx.registry().cmds {
         writeln!(
             dest,
             "#[link_name=\"{symbol}\"] fn {name}({params}) -> {return_suffix};",
-            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
+            symbol = ctx.symbol_name(cmd),
             name = cmd.proto.ident,
             params = super::gen_parameters(cmd, true, true).join(", "),
             return_suffix = cmd.proto.ty,
@@ -15,77 +15,36 @@
 use registry::Registry;
 use std::io;
 
+use super::{GenContext, GeneratorOptions};
+
 #[allow(missing_copy_implementations)]
 pub struct StructGenerator;
 
+impl super::StructSections for StructGenerator {}
+
 impl super::Generator for StructGenerator {
     fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
     where
         W: io::Write,
     {
-        write_header(dest)?;
-        write_type_aliases(registry, dest)?;
-        write_enums(registry, dest)?;
-        write_fnptr_struct_def(dest)?;
-        write_panicking_fns(registry, dest)?;
-        write_struct(registry, dest)?;
-        write_impl(registry, dest)?;
-        Ok(())
+        super::StructGeneratorWith(StructGenerator).write(registry, dest)
     }
-}
-
-/// Creates a `__gl_imports` module which contains all the external symbols that we need for the
-///  bindings.
-fn write_header<W>(dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    writeln!(
-        dest,
-        r#"
-        mod __gl_imports {{
-            pub use std::mem;
-            pub use std::marker::Send;
-            pub use std::os::raw;
-        }}
-    "#
-    )
-}
-
-/// Creates a `types` module which contains all the type aliases.
-///
-/// See also `generators::gen_types`.
-fn write_type_aliases<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    writeln!(
-        dest,
-        r#"
-        pub mod types {{
-            #![allow(non_camel_case_types, non_snake_case, dead_code, missing_copy_implementations)]
-    "#
-    )?;
-
-    super::gen_types(registry.api, dest)?;
-
-    writeln!(dest, "}}")
-}
 
-/// Creates all the `<enum>` elements at the root of the bindings.
-fn write_enums<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
-where
-    W: io::Write,
-{
-    for enm in &registry.enums {
-        super::gen_enum_item(enm, "types::", dest)?;
+    fn write_with_options<W>(
+        &self,
+        registry: &Registry,
+        options: &GeneratorOptions,
+        dest: &mut W,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        super::StructGeneratorWith(StructGenerator).write_with_options(registry, options, dest)
     }
-
-    Ok(())
 }
 
 /// Creates a `FnPtr` structure which contains the store for a single binding.
-fn write_fnptr_struct_def<W>(dest: &mut W) -> io::Result<()>
+pub(super) fn write_fnptr_struct_def<W>(dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
@@ -94,23 +53,25 @@ where
         "
         #[allow(dead_code, missing_copy_implementations)]
         #[derive(Clone)]
-        pub struct FnPtr {{
+        pub struct FnPtr<F> {{
             /// The function pointer that will be used when calling the function.
-            f: *const __gl_imports::raw::c_void,
+            f: F,
             /// True if the pointer points to a real function, false if points to a `panic!` fn.
             is_loaded: bool,
         }}
 
-        impl FnPtr {{
-            /// Creates a `FnPtr` from a load attempt.
-            fn new(ptr: *const __gl_imports::raw::c_void) -> FnPtr {{
+        impl<F: Copy> FnPtr<F> {{
+            /// Creates a `FnPtr` from a load attempt, using `missing` if the symbol was not found.
+            ///
+            /// `F` must be the function pointer type matching the signature of the loaded symbol.
+            unsafe fn new(ptr: *const __gl_imports::raw::c_void, missing: F) -> FnPtr<F> {{
                 if ptr.is_null() {{
+                    FnPtr {{ f: missing, is_loaded: false }}
+                }} else {{
                     FnPtr {{
-                        f: missing_fn_panic as *const __gl_imports::raw::c_void,
-                        is_loaded: false
+                        f: __gl_imports::mem::transmute_copy::<*const __gl_imports::raw::c_void, F>(&ptr),
+                        is_loaded: true,
                     }}
-                }} else {{
-                    FnPtr {{ f: ptr, is_loaded: true }}
                 }}
             }}
 
@@ -127,44 +88,53 @@ where
     )
 }
 
-/// Creates a `panicking` module which contains one function per GL command.
+/// Creates a structure which stores all the `FnPtr` of the bindings.
 ///
-/// These functions are the mocks that are called if the real function could not be loaded.
-fn write_panicking_fns<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+/// The name of the struct corresponds to the namespace.
+pub(super) fn write_struct<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
 where
     W: io::Write,
 {
-    writeln!(
-        dest,
-        "#[inline(never)]
-        fn missing_fn_panic() -> ! {{
-            panic!(\"{api} function was not loaded\")
-        }}",
-        api = registry.api
-    )
+    write_struct_with_fields(ctx, &[], dest)
 }
 
-/// Creates a structure which stores all the `FnPtr` of the bindings.
-///
-/// The name of the struct corresponds to the namespace.
-fn write_struct<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+/// Same as `write_struct`, with additional private fields given as `(name, type)`.
+pub(super) fn write_struct_with_fields<W>(
+    ctx: &GenContext,
+    fields: &[(&str, &str)],
+    dest: &mut W,
+) -> io::Result<()>
 where
     W: io::Write,
 {
     writeln!(
         dest,
         "
-        #[allow(non_camel_case_types, non_snake_case, dead_code)]
+        {allow}
         #[derive(Clone)]
-        pub struct {api} {{",
-        api = super::gen_struct_name(registry.api)
+        {vis} struct {api} {{",
+        allow = ctx
+            .options()
+            .allow("non_camel_case_types, non_snake_case, dead_code, clippy::type_complexity"),
+        vis = ctx.options().vis(),
+        api = ctx.struct_name(),
     )?;
 
-    for cmd in &registry.cmds {
-        if let Some(v) = registry.aliases.get(&cmd.proto.ident) {
-            writeln!(dest, "/// Fallbacks: {}", v.join(", "))?;
+    for cmd in &ctx.registry().cmds {
+        let fallbacks = ctx.fallbacks(cmd);
+        if !fallbacks.is_empty() {
+            writeln!(dest, "/// Fallbacks: {}", fallbacks.join(", "))?;
         }
-        writeln!(dest, "pub {name}: FnPtr,", name = cmd.proto.ident)?;
+        writeln!(
+            dest,
+            "{vis} {name}: FnPtr<{fn_ty}>,",
+            vis = ctx.options().vis(),
+            name = cmd.proto.ident,
+            fn_ty = super::gen_fn_ptr_type(cmd),
+        )?;
+    }
+    for &(name, ty) in fields {
+        writeln!(dest, "{name}: {ty},")?;
     }
     writeln!(dest, "_priv: ()")?;
 
@@ -172,85 +142,104 @@ where
 }
 
 /// Creates the `impl` of the structure created by `write_struct`.
-fn write_impl<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+pub(super) fn write_impl<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    super::StructSections::write_impl(&StructGenerator, ctx, dest)
+}
+
+/// Creates the `load_with` and `load_with_report` functions of the structure created by
+/// `write_struct`, to be written inside its `impl`.
+pub(super) fn write_load_fns<W>(ctx: &GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    write_load_fns_with_fields(ctx, &[], dest)
+}
+
+/// Same as `write_load_fns`, for the structure created by `write_struct_with_fields`. The
+/// additional fields are given as `(name, initial value)`.
+pub(super) fn write_load_fns_with_fields<W>(
+    ctx: &GenContext,
+    fields: &[(&str, &str)],
+    dest: &mut W,
+) -> io::Result<()>
 where
     W: io::Write,
 {
     writeln!(dest,
-                  "impl {api} {{
+                  "
             /// Load each OpenGL symbol using a custom load function. This allows for the
             /// use of functions like `glfwGetProcAddress` or `SDL_GL_GetProcAddress`.
             ///
             /// ~~~ignore
             /// let gl = Gl::load_with(|s| glfw.get_proc_address(s));
             /// ~~~
-            #[allow(dead_code, unused_variables)]
-            pub fn load_with<F>(mut loadfn: F) -> {api} where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
+            {allow} {vis} fn load_with<F>(loadfn: F) -> {api} where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
+                {api}::load_with_report(loadfn).0
+            }}
+
+            /// Same as `load_with`, but also returns a report of the symbol that was resolved
+            /// for each command.
+            ///
+            /// ~~~ignore
+            /// let (gl, report) = Gl::load_with_report(|s| glfw.get_proc_address(s));
+            /// println!(\"{{}}\", report);
+            /// ~~~
+            {allow} {vis} fn load_with_report<F>(mut loadfn: F) -> ({api}, LoadReport)
+                where F: FnMut(&'static str) -> *const __gl_imports::raw::c_void {{
                 #[inline(never)]
                 fn do_metaloadfn(loadfn: &mut dyn FnMut(&'static str) -> *const __gl_imports::raw::c_void,
-                                 symbol: &'static str,
-                                 symbols: &[&'static str])
+                                 report: &mut LoadReport,
+                                 info: &MissingFn)
                                  -> *const __gl_imports::raw::c_void {{
-                    let mut ptr = loadfn(symbol);
+                    let mut resolved = None;
+                    let mut ptr = loadfn(info.symbol);
                     if ptr.is_null() {{
-                        for &sym in symbols {{
+                        for &sym in info.fallbacks {{
                             ptr = loadfn(sym);
-                            if !ptr.is_null() {{ break; }}
+                            if !ptr.is_null() {{
+                                resolved = Some(sym);
+                                break;
+                            }}
                         }}
+                    }} else {{
+                        resolved = Some(info.symbol);
                     }}
+                    report.push(info, resolved);
                     ptr
                 }}
-                let mut metaloadfn = |symbol: &'static str, symbols: &[&'static str]| {{
-                    do_metaloadfn(&mut loadfn, symbol, symbols)
+                let mut report = LoadReport::with_capacity({len});
+                let mut metaloadfn = |info: &MissingFn| {{
+                    do_metaloadfn(&mut loadfn, &mut report, info)
                 }};
-                {api} {{",
-                  api = super::gen_struct_name(registry.api))?;
-
-    for cmd in &registry.cmds {
+                let gl = unsafe {{
+                    {api} {{",
+                  allow = ctx.options().allow("dead_code, unused_variables"),
+                  vis = ctx.options().vis(),
+                  api = ctx.struct_name(),
+                  len = ctx.registry().cmds.len())?;
+
+    for cmd in &ctx.registry().cmds {
         writeln!(
             dest,
-            "{name}: FnPtr::new(metaloadfn(\"{symbol}\", &[{fallbacks}])),",
+            "{name}: FnPtr::new(metaloadfn(&missing::info::{name}), missing::{name}),",
             name = cmd.proto.ident,
-            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
-            fallbacks = match registry.aliases.get(&cmd.proto.ident) {
-                Some(fbs) => fbs
-                    .iter()
-                    .map(|name| format!("\"{}\"", super::gen_symbol_name(registry.api, name)))
-                    .collect::<Vec<_>>()
-                    .join(", "),
-                None => String::new(),
-            },
         )?
     }
-
+    for &(name, value) in fields {
+        writeln!(dest, "{name}: {value},")?;
+    }
     writeln!(dest, "_priv: ()")?;
 
     writeln!(
         dest,
         "}}
-        }}"
+                }};
+                (gl, report)
+            }}"
     )?;
 
-    for cmd in &registry.cmds {
-        writeln!(dest,
-            "#[allow(non_snake_case, unused_variables, dead_code)]
-            #[inline] pub unsafe fn {name}(&self, {params}) -> {return_suffix} {{ \
-                __gl_imports::mem::transmute::<_, extern \"system\" fn({typed_params}) -> {return_suffix}>\
-                    (self.{name}.f)({idents}) \
-            }}",
-            name = cmd.proto.ident,
-            params = super::gen_parameters(cmd, true, true).join(", "),
-            typed_params = super::gen_parameters(cmd, false, true).join(", "),
-            return_suffix = cmd.proto.ty,
-            idents = super::gen_parameters(cmd, true, false).join(", "),
-        )?
-    }
-
-    writeln!(
-        dest,
-        "}}
-
-        unsafe impl __gl_imports::Send for {api} {{}}",
-        api = super::gen_struct_name(registry.api)
-    )
+    Ok(())
 }
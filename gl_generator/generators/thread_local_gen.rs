@@ -0,0 +1,138 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::Registry;
+use std::io;
+
+/// Generates free functions that dispatch through a thread-local current function table.
+///
+/// The table is the same struct as the one created by `StructGenerator`, and is made current on
+/// the calling thread with `make_current`.
+#[allow(missing_copy_implementations)]
+pub struct ThreadLocalGenerator;
+
+impl super::Generator for ThreadLocalGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        super::struct_gen::write_fnptr_struct_def(dest)?;
+        super::gen_missing_fns(registry, dest)?;
+        super::gen_load_report(registry, dest)?;
+        super::struct_gen::write_struct(&ctx, dest)?;
+        super::struct_gen::write_impl(&ctx, dest)?;
+        write_current(registry, dest)?;
+        write_fns(registry, dest)?;
+        Ok(())
+    }
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::cell::RefCell",
+    "std::fmt",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::process",
+    "std::sync::{Arc, RwLock}",
+];
+
+/// Creates the thread-local current table, and the functions that change and access it.
+fn write_current<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        thread_local! {{
+            static CURRENT: __gl_imports::RefCell<Option<__gl_imports::Arc<{struct_name}>>> =
+                const {{ __gl_imports::RefCell::new(None) }};
+        }}
+
+        /// Makes `gl` the table used by the free functions on the calling thread.
+        ///
+        /// This should be called after making the matching context current on this thread.
+        ///
+        /// # Panics
+        ///
+        /// Panics if called from within a function of these bindings, for example from a debug
+        /// callback.
+        #[allow(dead_code)]
+        pub fn make_current(gl: &__gl_imports::Arc<{struct_name}>) {{
+            CURRENT.with(|current| *current.borrow_mut() = Some(gl.clone()));
+        }}
+
+        /// Removes the current table of the calling thread, and returns it.
+        #[allow(dead_code)]
+        pub fn make_not_current() -> Option<__gl_imports::Arc<{struct_name}>> {{
+            CURRENT.with(|current| current.borrow_mut().take())
+        }}
+
+        /// Returns the current table of the calling thread.
+        #[allow(dead_code)]
+        pub fn current() -> Option<__gl_imports::Arc<{struct_name}>> {{
+            CURRENT.with(|current| current.borrow().clone())
+        }}
+
+        #[cold]
+        #[inline(never)]
+        fn no_current_table(symbol: &'static str) -> ! {{
+            panic!("{api} function `{{}}` was called without a current table on this thread", symbol)
+        }}
+
+        #[inline]
+        fn with_current<F, R>(symbol: &'static str, f: F) -> R where F: FnOnce(&{struct_name}) -> R {{
+            CURRENT.with(|current| match *current.borrow() {{
+                Some(ref gl) => f(gl),
+                None => no_current_table(symbol),
+            }})
+        }}
+    "#,
+        api = registry.api,
+        struct_name = super::gen_struct_name(registry.api),
+    )
+}
+
+/// Creates the functions corresponding to the GL commands.
+///
+/// The function calls the corresponding method of the current table created by `write_current`.
+fn write_fns<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    for cmd in &registry.cmds {
+        write!(dest, "{}", super::gen_cmd_doc(registry, cmd))?;
+        writeln!(
+            dest,
+            "#[allow(non_snake_case, unused_variables, dead_code)] #[inline]
+            pub unsafe fn {name}({params}) -> {return_suffix} {{ \
+                with_current(\"{symbol}\", |gl| gl.{name}({idents})) \
+            }}",
+            name = cmd.proto.ident,
+            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+            idents = super::gen_parameters(cmd, true, false).join(", "),
+        )?;
+    }
+
+    Ok(())
+}
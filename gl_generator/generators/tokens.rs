@@ -0,0 +1,77 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bindings as a `proc_macro2::TokenStream`, and pretty-printed bindings.
+
+use proc_macro2::TokenStream;
+use registry::Registry;
+use std::io;
+use std::str::FromStr;
+
+/// Trait for a generator that builds the bindings as tokens.
+///
+/// Every `Generator` is a `TokenGenerator`, whose tokens are parsed from the code it writes.
+/// Generators that build their tokens directly, for example with `quote`, can implement this
+/// trait instead of `Generator`, and write their bindings with `Pretty`.
+pub trait TokenGenerator {
+    /// Builds the GL bindings.
+    fn generate(&self, registry: &Registry) -> io::Result<TokenStream>;
+}
+
+impl<G> TokenGenerator for G
+where
+    G: super::Generator,
+{
+    fn generate(&self, registry: &Registry) -> io::Result<TokenStream> {
+        let mut code = Vec::new();
+        self.write(registry, &mut code)?;
+        let code = String::from_utf8(code).map_err(invalid_data)?;
+        TokenStream::from_str(&code).map_err(|err| invalid_data(err.to_string()))
+    }
+}
+
+/// Writes the bindings of a `TokenGenerator` as formatted Rust code, instead of the long lines
+/// written by the generators.
+///
+/// ```no_run
+/// # use gl_generator::{Api, Fallbacks, Pretty, Profile, Registry, StructGenerator};
+/// # let mut file = Vec::new();
+/// Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, [])
+///     .write_bindings(Pretty(StructGenerator), &mut file)
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Pretty<G>(pub G);
+
+impl<G> super::Generator for Pretty<G>
+where
+    G: TokenGenerator,
+{
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let tokens = self.0.generate(registry)?;
+        let file = syn::parse2(tokens).map_err(|err| invalid_data(err.to_string()))?;
+        dest.write_all(prettyplease::unparse(&file).as_bytes())
+    }
+}
+
+/// Creates the error returned when the bindings are not valid Rust code.
+fn invalid_data<E>(err: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
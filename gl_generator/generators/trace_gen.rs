@@ -0,0 +1,831 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::{Binding, Cmd, Registry};
+use std::io;
+
+/// Generates the struct of `StructGenerator`, plus a `Recorder` that writes every call into a
+/// binary trace and a `replay` function that calls the commands of a trace on a loaded struct.
+#[allow(missing_copy_implementations)]
+pub struct TraceGenerator;
+
+impl super::Generator for TraceGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        super::struct_gen::write_fnptr_struct_def(dest)?;
+        super::gen_missing_fns(registry, dest)?;
+        super::gen_load_report(registry, dest)?;
+        super::struct_gen::write_struct(&ctx, dest)?;
+        super::struct_gen::write_impl(&ctx, dest)?;
+        write_trace_format(dest)?;
+        write_recorder(registry, dest)?;
+        write_replay(registry, dest)?;
+        Ok(())
+    }
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::cell::RefCell",
+    "std::fmt",
+    "std::io",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::process",
+    "std::ptr",
+    "std::slice",
+    "std::sync::RwLock",
+];
+
+/// How a parameter is stored in a trace.
+pub(super) enum ParamKind {
+    /// Stored as its raw bytes.
+    Scalar,
+    /// A function pointer. It is not stored. A `nullable` callback is replayed as `None`, and the
+    /// recorder refuses the others.
+    Callback { nullable: bool },
+    /// A pointer to input data of known size. The data is stored.
+    Input { elem_ty: String, len: String },
+    /// A pointer to output data of known size. The size is stored, and a zeroed buffer is
+    /// replayed.
+    Output { elem_ty: String, len: String },
+    /// A pointer to a NUL-terminated string.
+    CStr,
+    /// A pointer to `count` strings, with the `lengths` parameter giving the length of each
+    /// string when it is not null, as in `glShaderSource`. The strings are stored.
+    CStrArray {
+        count: String,
+        lengths: Option<String>,
+    },
+    /// A pointer to data of unknown size. Only its address is stored, and it is replayed as an
+    /// offset into the buffer bound when the command reads from a buffer, such as the `indices`
+    /// of `glDrawElements`.
+    Unknown,
+}
+
+/// Integer types that can be negative, and have to be clamped before being used as a length.
+const SIGNED_LEN_TYPES: &[&str] = &[
+    "types::GLsizei",
+    "types::GLint",
+    "types::GLsizeiptr",
+    "types::GLsizeiptrARB",
+    "types::GLintptr",
+];
+
+/// Converts a `len` attribute such as `"n"`, `"4"` or `"count*4"` into an expression that
+/// evaluates to the number of elements, or `None` if it can't be computed from the parameters.
+fn gen_len_expr(len: &str, cmd: &Cmd) -> Option<String> {
+    let factors = len
+        .split('*')
+        .map(str::trim)
+        .filter(|factor| *factor != "1")
+        .map(|factor| {
+            if !factor.is_empty() && factor.bytes().all(|b| b.is_ascii_digit()) {
+                return Some(factor.to_string());
+            }
+            let param = cmd
+                .params
+                .iter()
+                .find(|p| p.ident == factor || p.ident == format!("{factor}_"))?;
+            if param.ty.starts_with('*')
+                || param.ty.contains("GLfloat")
+                || param.ty.contains("GLdouble")
+            {
+                return None;
+            }
+            if SIGNED_LEN_TYPES.contains(&&param.ty[..]) {
+                Some(format!("{}.max(0) as usize", param.ident))
+            } else {
+                Some(format!("{} as usize", param.ident))
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if factors.is_empty() {
+        Some("1".to_string())
+    } else {
+        Some(factors.join(" * "))
+    }
+}
+
+/// Returns an expression that evaluates to the size in bytes of `len` elements of `elem_ty`.
+pub(super) fn gen_byte_len(elem_ty: &str, len: &str) -> String {
+    let size = format!("__gl_imports::mem::size_of::<{elem_ty}>()");
+    if len == "1" {
+        size
+    } else {
+        format!("{len} * {size}")
+    }
+}
+
+/// Returns `true` if the Rust type `ty` is a function pointer.
+fn is_fn_ptr(ty: &str) -> bool {
+    ty.starts_with("extern ") || ty.starts_with("unsafe extern ") || ty.starts_with("fn(")
+}
+
+pub(super) fn param_kind(param: &Binding, cmd: &Cmd) -> ParamKind {
+    let resolved = super::resolve_type(&param.ty);
+    if let Some(fn_ty) = resolved.strip_prefix("Option<") {
+        if is_fn_ptr(fn_ty) {
+            return ParamKind::Callback { nullable: true };
+        }
+    }
+    if is_fn_ptr(resolved) {
+        return ParamKind::Callback { nullable: false };
+    }
+    let (is_const, elem_ty) = if let Some(elem_ty) = param.ty.strip_prefix("*const ") {
+        (true, elem_ty)
+    } else if let Some(elem_ty) = param.ty.strip_prefix("*mut ") {
+        (false, elem_ty)
+    } else {
+        return ParamKind::Scalar;
+    };
+    if let Some(str_ty) = elem_ty.strip_prefix("*const ") {
+        let count = param.len.as_ref().and_then(|len| gen_len_expr(len, cmd));
+        return match count {
+            Some(count) if is_const && str_ty == "types::GLchar" => ParamKind::CStrArray {
+                count,
+                lengths: cmd
+                    .params
+                    .iter()
+                    .find(|p| p.ident == "length" && p.ty == "*const types::GLint")
+                    .map(|p| p.ident.clone()),
+            },
+            _ => ParamKind::Unknown,
+        };
+    }
+    if elem_ty.starts_with('*') {
+        return ParamKind::Unknown;
+    }
+    match param.len.as_ref().and_then(|len| gen_len_expr(len, cmd)) {
+        Some(len) if is_const => ParamKind::Input {
+            elem_ty: elem_ty.to_string(),
+            len,
+        },
+        Some(len) => ParamKind::Output {
+            elem_ty: elem_ty.to_string(),
+            len,
+        },
+        None if is_const && (elem_ty.contains("GLchar") || elem_ty.ends_with("c_char")) => {
+            ParamKind::CStr
+        },
+        None => ParamKind::Unknown,
+    }
+}
+
+/// Returns the buffer binding whose buffer `param` of `cmd` is an offset into when a buffer is
+/// bound, such as `ARRAY_BUFFER_BINDING` for the `pointer` of `glVertexAttribPointer`.
+fn offset_binding(registry: &Registry, cmd: &Cmd, param: &Binding) -> Option<&'static str> {
+    let name = &cmd.proto.ident[..];
+    if !param.ty.ends_with("c_void") || param.ty.matches('*').count() != 1 {
+        return None;
+    }
+    let binding = match &param.ident[..] {
+        "pointer" if name.ends_with("Pointer") => "ARRAY_BUFFER_BINDING",
+        "indices" if name.starts_with("Draw") => "ELEMENT_ARRAY_BUFFER_BINDING",
+        "indirect" if name.starts_with("Draw") => "DRAW_INDIRECT_BUFFER_BINDING",
+        "pixels" if name == "ReadPixels" || name.starts_with("GetTex") => {
+            "PIXEL_PACK_BUFFER_BINDING"
+        },
+        "pixels" | "data" if name.starts_with("Tex") || name.starts_with("CompressedTex") => {
+            "PIXEL_UNPACK_BUFFER_BINDING"
+        },
+        _ => return None,
+    };
+    let queryable = registry
+        .cmds
+        .iter()
+        .any(|cmd| cmd.proto.ident == "GetIntegerv")
+        && registry.enums.iter().any(|enm| enm.ident == binding);
+    if queryable {
+        Some(binding)
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if `cmd` generates object names, such as `glGenBuffers` or `glCreateShader`.
+fn creates_names(cmd: &Cmd) -> bool {
+    cmd.proto.ident.starts_with("Gen") || cmd.proto.ident.starts_with("Create")
+}
+
+/// Returns `true` if `param` receives the object names generated by `cmd`.
+fn is_names_output(param: &Binding, cmd: &Cmd) -> bool {
+    creates_names(cmd) && param.ty == "*mut types::GLuint"
+}
+
+/// Returns `true` if `cmd` returns the object name that it generates.
+fn returns_name(cmd: &Cmd) -> bool {
+    creates_names(cmd) && (cmd.proto.ty == "types::GLuint" || cmd.proto.ty == "types::GLhandleARB")
+}
+
+/// Creates the constants of the trace format, and the types that write and read it.
+fn write_trace_format<W>(dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// The first bytes of a trace written by `Recorder`.
+        ///
+        /// The magic is followed by records, each starting with a tag byte. A define record
+        /// (`0`) associates a `u32` command id with a `u16`-length-prefixed symbol name, the
+        /// first time the command is called. A call record (`1`) contains a command id, then
+        /// each parameter and the return value. Values are stored as their native bytes, so a
+        /// trace can only be replayed on a machine with the same endianness and pointer width.
+        /// Pointers to data of unknown size are stored as their address.
+        pub const TRACE_MAGIC: &[u8; 8] = b"GLTRACE1";
+
+        const TRACE_DEFINE: u8 = 0;
+        const TRACE_CALL: u8 = 1;
+
+        const POINTER_NULL: u8 = 0;
+        const POINTER_DATA: u8 = 1;
+        const POINTER_OUTPUT: u8 = 2;
+        const POINTER_UNKNOWN: u8 = 3;
+        const POINTER_STRINGS: u8 = 4;
+        const POINTER_NAMES: u8 = 5;
+
+        struct TraceWriter<W> {{
+            out: W,
+            defined: Vec<bool>,
+            error: Option<__gl_imports::io::Error>,
+        }}
+
+        #[allow(dead_code)]
+        impl<W: __gl_imports::io::Write> TraceWriter<W> {{
+            fn bytes(&mut self, bytes: &[u8]) {{
+                if self.error.is_none() {{
+                    if let Err(error) = self.out.write_all(bytes) {{
+                        self.error = Some(error);
+                    }}
+                }}
+            }}
+
+            fn value<T: Copy>(&mut self, value: &T) {{
+                let bytes = unsafe {{
+                    __gl_imports::slice::from_raw_parts(
+                        value as *const T as *const u8,
+                        __gl_imports::mem::size_of::<T>(),
+                    )
+                }};
+                self.bytes(bytes);
+            }}
+
+            fn begin_call(&mut self, id: u32, symbol: &str) {{
+                if !self.defined[id as usize] {{
+                    self.defined[id as usize] = true;
+                    self.bytes(&[TRACE_DEFINE]);
+                    self.value(&id);
+                    self.value(&(symbol.len() as u16));
+                    self.bytes(symbol.as_bytes());
+                }}
+                self.bytes(&[TRACE_CALL]);
+                self.value(&id);
+            }}
+
+            /// Stops recording with an error, because a function pointer that isn't an optional
+            /// callback was passed to `symbol` and can't be replayed.
+            fn function_pointer(&mut self, symbol: &str) {{
+                if self.error.is_none() {{
+                    self.error = Some(unreplayable(format!(
+                        "`{{symbol}}` was called with a function pointer, which can't be recorded"
+                    )));
+                }}
+            }}
+
+            unsafe fn data(&mut self, ptr: *const u8, len: usize) {{
+                if ptr.is_null() {{
+                    self.bytes(&[POINTER_NULL]);
+                }} else {{
+                    self.bytes(&[POINTER_DATA]);
+                    self.value(&(len as u32));
+                    self.bytes(__gl_imports::slice::from_raw_parts(ptr, len));
+                }}
+            }}
+
+            unsafe fn c_str(&mut self, ptr: *const u8) {{
+                let mut len = 0;
+                if !ptr.is_null() {{
+                    while *ptr.add(len) != 0 {{
+                        len += 1;
+                    }}
+                }}
+                self.data(ptr, len + 1);
+            }}
+
+            fn output(&mut self, ptr: *const u8, len: usize) {{
+                if ptr.is_null() {{
+                    self.bytes(&[POINTER_NULL]);
+                }} else {{
+                    self.bytes(&[POINTER_OUTPUT]);
+                    self.value(&(len as u32));
+                }}
+            }}
+
+            unsafe fn c_str_array(&mut self, ptr: *const *const u8, count: usize, lengths: *const i32) {{
+                if ptr.is_null() {{
+                    self.bytes(&[POINTER_NULL]);
+                    return;
+                }}
+                self.bytes(&[POINTER_STRINGS]);
+                self.value(&(count as u32));
+                for i in 0..count {{
+                    let string = *ptr.add(i);
+                    if string.is_null() {{
+                        self.value(&u32::MAX);
+                        continue;
+                    }}
+                    let len = match lengths.is_null() {{
+                        false if *lengths.add(i) >= 0 => *lengths.add(i) as usize,
+                        _ => {{
+                            let mut len = 0;
+                            while *string.add(len) != 0 {{
+                                len += 1;
+                            }}
+                            len
+                        }},
+                    }};
+                    self.value(&(len as u32));
+                    self.bytes(__gl_imports::slice::from_raw_parts(string, len));
+                }}
+            }}
+
+            /// Records the object names written by a `Gen*` or `Create*` command, so that replay
+            /// can check that it gets the same names.
+            unsafe fn names(&mut self, ptr: *const u8, len: usize) {{
+                if ptr.is_null() {{
+                    self.bytes(&[POINTER_NULL]);
+                }} else {{
+                    self.bytes(&[POINTER_NAMES]);
+                    self.value(&(len as u32));
+                    self.bytes(__gl_imports::slice::from_raw_parts(ptr, len));
+                }}
+            }}
+
+            fn unknown(&mut self, ptr: *const u8) {{
+                if ptr.is_null() {{
+                    self.bytes(&[POINTER_NULL]);
+                }} else {{
+                    self.bytes(&[POINTER_UNKNOWN]);
+                    self.value(&(ptr as usize));
+                }}
+            }}
+        }}
+
+        struct TraceReader<R> {{
+            input: R,
+            /// The local command id of each command id of the trace.
+            ids: Vec<Option<u32>>,
+            /// The buffers of the current call that receive object names, and the names that
+            /// were recorded in them.
+            names: Vec<(*const u8, Vec<u8>)>,
+            /// The recorded and replayed address of each sync object.
+            syncs: Vec<(usize, usize)>,
+        }}
+
+        #[allow(dead_code)]
+        impl<R: __gl_imports::io::Read> TraceReader<R> {{
+            fn tag(&mut self) -> __gl_imports::io::Result<Option<u8>> {{
+                let mut tag = [0u8];
+                match self.input.read(&mut tag)? {{
+                    0 => Ok(None),
+                    _ => Ok(Some(tag[0])),
+                }}
+            }}
+
+            fn value<T: Copy>(&mut self) -> __gl_imports::io::Result<T> {{
+                let mut value = __gl_imports::mem::MaybeUninit::<T>::zeroed();
+                let bytes = unsafe {{
+                    __gl_imports::slice::from_raw_parts_mut(
+                        value.as_mut_ptr() as *mut u8,
+                        __gl_imports::mem::size_of::<T>(),
+                    )
+                }};
+                self.input.read_exact(bytes)?;
+                Ok(unsafe {{ value.assume_init() }})
+            }}
+
+            /// Reads a pointer parameter of `symbol`. The data it points to is kept alive in
+            /// `buffers`. A pointer to data of unknown size is replayed as the recorded address
+            /// if `buffer_bound` returns `true`, since it is then an offset into that buffer.
+            fn pointer<T, B: FnOnce() -> bool>(
+                &mut self,
+                buffers: &mut Vec<Vec<u64>>,
+                symbol: &str,
+                buffer_bound: B,
+            ) -> __gl_imports::io::Result<*mut T> {{
+                let kind = self.value::<u8>()?;
+                match kind {{
+                    POINTER_NULL => Ok(__gl_imports::ptr::null_mut()),
+                    POINTER_DATA | POINTER_OUTPUT | POINTER_NAMES => {{
+                        let len = self.value::<u32>()? as usize;
+                        let mut buffer = vec![0u64; len.div_ceil(8)];
+                        let ptr = buffer.as_mut_ptr() as *mut u8;
+                        if kind == POINTER_DATA {{
+                            self.input.read_exact(unsafe {{ __gl_imports::slice::from_raw_parts_mut(ptr, len) }})?;
+                        }} else if kind == POINTER_NAMES {{
+                            let mut names = vec![0; len];
+                            self.input.read_exact(&mut names)?;
+                            self.names.push((ptr, names));
+                        }}
+                        buffers.push(buffer);
+                        Ok(ptr.cast())
+                    }},
+                    POINTER_STRINGS => {{
+                        let count = self.value::<u32>()? as usize;
+                        let mut table = vec![0u64; count];
+                        let strings = table.as_mut_ptr() as *mut *const u8;
+                        for i in 0..count {{
+                            let len = self.value::<u32>()?;
+                            let string = if len == u32::MAX {{
+                                __gl_imports::ptr::null()
+                            }} else {{
+                                let len = len as usize;
+                                let mut buffer = vec![0u64; (len + 1).div_ceil(8)];
+                                let ptr = buffer.as_mut_ptr() as *mut u8;
+                                self.input.read_exact(unsafe {{ __gl_imports::slice::from_raw_parts_mut(ptr, len) }})?;
+                                buffers.push(buffer);
+                                ptr as *const u8
+                            }};
+                            unsafe {{ strings.add(i).write(string) }};
+                        }}
+                        buffers.push(table);
+                        Ok(strings.cast())
+                    }},
+                    POINTER_UNKNOWN => {{
+                        let address = self.value::<usize>()?;
+                        if buffer_bound() {{
+                            Ok(address as *mut T)
+                        }} else {{
+                            Err(unreplayable(format!(
+                                "`{{symbol}}` was called with a pointer to data of unknown size"
+                            )))
+                        }}
+                    }},
+                    _ => Err(invalid_trace(format!("unknown pointer kind {{kind}}"))),
+                }}
+            }}
+
+            /// Checks that the call of `symbol` wrote the recorded object names.
+            fn check_names(&mut self, symbol: &str) -> __gl_imports::io::Result<()> {{
+                for (ptr, names) in self.names.drain(..) {{
+                    let replayed = unsafe {{ __gl_imports::slice::from_raw_parts(ptr, names.len()) }};
+                    if replayed != &names[..] {{
+                        return Err(unreplayable(format!(
+                            "`{{symbol}}` generated other object names than the recorded ones"
+                        )));
+                    }}
+                }}
+                Ok(())
+            }}
+
+            /// Reads a sync object parameter of `symbol`, and returns the replayed sync object
+            /// that corresponds to it.
+            /// Refuses a function pointer parameter of `symbol`, which the recorder never writes.
+            fn function_pointer<F>(&mut self, symbol: &str) -> __gl_imports::io::Result<F> {{
+                Err(unreplayable(format!(
+                    "`{{symbol}}` was called with a function pointer, which can't be replayed"
+                )))
+            }}
+
+            fn sync(&mut self, symbol: &str) -> __gl_imports::io::Result<usize> {{
+                let recorded = self.value::<usize>()?;
+                if recorded == 0 {{
+                    return Ok(0);
+                }}
+                match self.syncs.iter().find(|sync| sync.0 == recorded) {{
+                    Some(&(_, replayed)) => Ok(replayed),
+                    None => Err(unreplayable(format!(
+                        "`{{symbol}}` was called with a sync object that wasn't created in the trace"
+                    ))),
+                }}
+            }}
+        }}
+
+        fn invalid_trace(message: String) -> __gl_imports::io::Error {{
+            __gl_imports::io::Error::new(__gl_imports::io::ErrorKind::InvalidData, message)
+        }}
+
+        fn unreplayable(message: String) -> __gl_imports::io::Error {{
+            __gl_imports::io::Error::new(__gl_imports::io::ErrorKind::Unsupported, message)
+        }}
+    "#
+    )
+}
+
+/// Creates the `Recorder` structure, with one method per command that records the call after
+/// forwarding it to the wrapped struct.
+fn write_recorder<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// Records every call made through it into a trace, after forwarding it to the wrapped
+        /// `{api}`.
+        ///
+        /// The data that pointer parameters point to is recorded when its size is known from the
+        /// registry. Errors that happen while writing are returned by `finish`, as is an error of
+        /// kind `Unsupported` if a command was called with a function pointer that isn't an
+        /// optional callback.
+        pub struct Recorder<W: __gl_imports::io::Write> {{
+            gl: {api},
+            trace: __gl_imports::RefCell<TraceWriter<W>>,
+        }}
+
+        #[allow(dead_code)]
+        impl<W: __gl_imports::io::Write> Recorder<W> {{
+            /// Starts recording the calls made to `gl` into `out`.
+            pub fn new(gl: {api}, out: W) -> Recorder<W> {{
+                let mut trace = TraceWriter {{ out, defined: vec![false; {len}], error: None }};
+                trace.bytes(TRACE_MAGIC);
+                Recorder {{ gl, trace: __gl_imports::RefCell::new(trace) }}
+            }}
+
+            /// Returns the wrapped `{api}`. Calls made through it are not recorded.
+            pub fn gl(&self) -> &{api} {{
+                &self.gl
+            }}
+
+            /// Stops recording and returns the output, or the first error that happened while
+            /// writing to it.
+            pub fn finish(self) -> __gl_imports::io::Result<W> {{
+                let mut trace = self.trace.into_inner();
+                match trace.error {{
+                    Some(error) => Err(error),
+                    None => {{
+                        trace.out.flush()?;
+                        Ok(trace.out)
+                    }},
+                }}
+            }}
+        "#,
+        api = super::gen_struct_name(registry.api),
+        len = registry.cmds.len(),
+    )?;
+
+    for (id, cmd) in registry.cmds.iter().enumerate() {
+        let idents = super::gen_parameters(cmd, true, false).join(", ");
+        let symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident);
+        let returns = cmd.proto.ty != "()";
+
+        let mut record = String::new();
+        for param in &cmd.params {
+            let ident = &param.ident;
+            record.push_str(&match param_kind(param, cmd) {
+                ParamKind::Scalar => format!("trace.value(&{ident});"),
+                ParamKind::Callback { nullable: true } => String::new(),
+                ParamKind::Callback { nullable: false } => {
+                    format!("trace.function_pointer(\"{symbol}\");")
+                },
+                ParamKind::Input { elem_ty, len } => format!(
+                    "trace.data({ident}.cast::<u8>(), {});",
+                    gen_byte_len(&elem_ty, &len)
+                ),
+                ParamKind::Output { elem_ty, len } if is_names_output(param, cmd) => format!(
+                    "trace.names({ident}.cast::<u8>(), {});",
+                    gen_byte_len(&elem_ty, &len)
+                ),
+                ParamKind::Output { elem_ty, len } => format!(
+                    "trace.output({ident}.cast::<u8>(), {});",
+                    gen_byte_len(&elem_ty, &len)
+                ),
+                ParamKind::CStr => format!("trace.c_str({ident}.cast::<u8>());"),
+                ParamKind::CStrArray { count, lengths } => format!(
+                    "trace.c_str_array({ident}.cast::<*const u8>(), {count}, {});",
+                    lengths.map_or("__gl_imports::ptr::null()".to_string(), |lengths| format!(
+                        "{lengths}.cast::<i32>()"
+                    ))
+                ),
+                ParamKind::Unknown => format!("trace.unknown({ident}.cast::<u8>());"),
+            });
+        }
+        if returns {
+            record.push_str("trace.value(&r);");
+        }
+
+        write!(dest, "{}", super::gen_cmd_doc(registry, cmd))?;
+        writeln!(
+            dest,
+            "#[allow(non_snake_case, unused_variables, dead_code)]
+            pub unsafe fn {name}(&self, {params}) -> {return_suffix} {{
+                {call}
+                let mut trace = self.trace.borrow_mut();
+                trace.begin_call({id}, \"{symbol}\");
+                {record}
+                {result}
+            }}",
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+            call = if returns {
+                format!("let r = self.gl.{}({idents});", cmd.proto.ident)
+            } else {
+                format!("self.gl.{}({idents});", cmd.proto.ident)
+            },
+            result = if returns { "r" } else { "" },
+        )?;
+    }
+
+    writeln!(dest, "}}")
+}
+
+/// Creates the `replay` function, which reads a trace and calls each of its commands.
+fn write_replay<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// Calls each command recorded in `input` on `gl`, and returns the number of calls.
+        ///
+        /// Output pointers point to zeroed buffers of the recorded size, and callbacks are
+        /// replayed as `None`. Sync objects are mapped to the ones created during the replay.
+        /// Object names are replayed as they were recorded, so the names generated by `Gen*`
+        /// and `Create*` commands are checked against the recorded ones.
+        ///
+        /// Replay stops with an error of kind `Unsupported` at a call that it can't replay
+        /// faithfully: a call with a non-null pointer to data of unknown size that isn't an
+        /// offset into a bound buffer, a call that
+        /// generates other object names than the recorded ones, or a call with a sync object
+        /// that wasn't created in the trace.
+        ///
+        /// # Safety
+        ///
+        /// The recorded calls are made as-is, so they must be valid for `gl`.
+        #[allow(dead_code)]
+        pub unsafe fn replay<R: __gl_imports::io::Read>(gl: &{api}, input: R) -> __gl_imports::io::Result<usize> {{
+            let mut reader = TraceReader {{
+                input,
+                ids: Vec::new(),
+                names: Vec::new(),
+                syncs: Vec::new(),
+            }};
+            let magic = reader.value::<[u8; 8]>()?;
+            if &magic != TRACE_MAGIC {{
+                return Err(invalid_trace("not a trace".to_string()));
+            }}
+
+            let mut calls = 0;
+            while let Some(tag) = reader.tag()? {{
+                match tag {{
+                    TRACE_DEFINE => {{
+                        let id = reader.value::<u32>()? as usize;
+                        let len = reader.value::<u16>()? as usize;
+                        let mut symbol = vec![0; len];
+                        reader.input.read_exact(&mut symbol)?;
+                        let symbol = String::from_utf8_lossy(&symbol);
+                        let local_id = match command_id(&symbol) {{
+                            Some(local_id) => local_id,
+                            None => return Err(invalid_trace(format!("unknown command `{{symbol}}`"))),
+                        }};
+                        if reader.ids.len() <= id {{
+                            reader.ids.resize(id + 1, None);
+                        }}
+                        reader.ids[id] = Some(local_id);
+                    }},
+                    TRACE_CALL => {{
+                        let id = reader.value::<u32>()? as usize;
+                        match reader.ids.get(id).cloned().unwrap_or(None) {{
+                            Some(local_id) => replay_call(gl, local_id, &mut reader)?,
+                            None => return Err(invalid_trace(format!("undefined command id {{id}}"))),
+                        }}
+                        calls += 1;
+                    }},
+                    _ => return Err(invalid_trace(format!("unknown record {{tag}}"))),
+                }}
+            }}
+            Ok(calls)
+        }}
+
+        fn command_id(symbol: &str) -> Option<u32> {{
+            match symbol {{"#,
+        api = super::gen_struct_name(registry.api),
+    )?;
+
+    for (id, cmd) in registry.cmds.iter().enumerate() {
+        writeln!(
+            dest,
+            "\"{symbol}\" => Some({id}),",
+            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
+        )?;
+    }
+
+    writeln!(
+        dest,
+        "
+                _ => None,
+            }}
+        }}
+
+        #[allow(unused_variables, unused_mut, clippy::let_unit_value)]
+        unsafe fn replay_call<R: __gl_imports::io::Read>(
+            gl: &{api},
+            id: u32,
+            reader: &mut TraceReader<R>,
+        ) -> __gl_imports::io::Result<()> {{
+            let mut buffers: Vec<Vec<u64>> = Vec::new();
+            match id {{",
+        api = super::gen_struct_name(registry.api),
+    )?;
+
+    for (id, cmd) in registry.cmds.iter().enumerate() {
+        let symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident);
+        let mut reads = String::new();
+        let mut args = Vec::new();
+        for (i, param) in cmd.params.iter().enumerate() {
+            let ty = &param.ty;
+            let buffer_bound = match offset_binding(registry, cmd, param) {
+                Some(binding) => format!(
+                    "|| {{ let mut buffer = 0; gl.GetIntegerv({binding}, &mut buffer); buffer != 0 }}"
+                ),
+                None => "|| false".to_string(),
+            };
+            reads.push_str(&match param_kind(param, cmd) {
+                ParamKind::Scalar if ty == "types::GLsync" => {
+                    format!("let a{i} = reader.sync(\"{symbol}\")? as {ty};")
+                },
+                ParamKind::Scalar => format!("let a{i} = reader.value::<{ty}>()?;"),
+                ParamKind::Callback { nullable: true } => {
+                    format!("let a{i}: {ty} = __gl_imports::mem::zeroed();")
+                },
+                ParamKind::Callback { nullable: false } => {
+                    format!("let a{i} = reader.function_pointer::<{ty}>(\"{symbol}\")?;")
+                },
+                _ => match ty.strip_prefix("*const ") {
+                    Some(elem_ty) => format!(
+                        "let a{i} = reader.pointer::<{elem_ty}, _>(&mut buffers, \"{symbol}\", {buffer_bound})?.cast_const();"
+                    ),
+                    None => format!(
+                        "let a{i} = reader.pointer::<{}, _>(&mut buffers, \"{symbol}\", {buffer_bound})?;",
+                        &ty[5..]
+                    ),
+                },
+            });
+            args.push(format!("a{i}"));
+        }
+
+        let call = format!("gl.{}({})", cmd.proto.ident, args.join(", "));
+        let call = if returns_name(cmd) {
+            format!(
+                "let recorded = reader.value::<{ty}>()?;
+                let r = {call};
+                if r != recorded {{
+                    return Err(unreplayable(format!(
+                        \"`{symbol}` returned {{r:?}} instead of the recorded {{recorded:?}}\"
+                    )));
+                }}",
+                ty = cmd.proto.ty,
+            )
+        } else if cmd.proto.ty == "types::GLsync" {
+            format!(
+                "let recorded = reader.value::<{ty}>()?;
+                let r = {call};
+                reader.syncs.push((recorded as usize, r as usize));",
+                ty = cmd.proto.ty,
+            )
+        } else if cmd.proto.ty != "()" {
+            format!("reader.value::<{}>()?; {call};", cmd.proto.ty)
+        } else {
+            format!("{call};")
+        };
+        let check = if cmd.params.iter().any(|param| is_names_output(param, cmd)) {
+            format!("reader.check_names(\"{symbol}\")?;")
+        } else {
+            String::new()
+        };
+
+        writeln!(dest, "{id} => {{ {reads} {call} {check} }},")?;
+    }
+
+    writeln!(
+        dest,
+        "
+                _ => unreachable!(),
+            }}
+            Ok(())
+        }}"
+    )
+}
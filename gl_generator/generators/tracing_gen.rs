@@ -0,0 +1,136 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::Registry;
+use std::io;
+
+/// Generates the struct of `StructGenerator`, with each command wrapped in a `tracing` span.
+///
+/// The crate including the bindings must depend on `tracing`.
+#[allow(missing_copy_implementations)]
+pub struct TracingGenerator;
+
+impl super::Generator for TracingGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        super::struct_gen::write_fnptr_struct_def(dest)?;
+        super::gen_missing_fns(registry, dest)?;
+        super::gen_load_report(registry, dest)?;
+        super::struct_gen::write_struct(&ctx, dest)?;
+        super::gen_enum_groups(registry, dest)?;
+        write_impl(&ctx, dest)?;
+        Ok(())
+    }
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::fmt",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::process",
+    "std::sync::RwLock",
+];
+
+/// Integer and float types that are recorded as span fields as they are. Other types, and enums
+/// and bitfields of known groups, are recorded with their `Debug` implementation.
+const VALUE_TYPES: &[&str] = &[
+    "types::GLbitfield",
+    "types::GLboolean",
+    "types::GLbyte",
+    "types::GLubyte",
+    "types::GLshort",
+    "types::GLushort",
+    "types::GLint",
+    "types::GLuint",
+    "types::GLsizei",
+    "types::GLfloat",
+    "types::GLclampf",
+    "types::GLdouble",
+    "types::GLclampd",
+    "types::GLint64",
+    "types::GLuint64",
+    "types::GLintptr",
+    "types::GLsizeiptr",
+    "types::EGLint",
+    "types::EGLenum",
+];
+
+/// Creates the `impl` of the structure created by `write_struct`, where each command enters a
+/// span named after its symbol, with a field per parameter.
+fn write_impl<W>(ctx: &super::GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    let registry = ctx.registry();
+    writeln!(
+        dest,
+        "impl {api} {{",
+        api = super::gen_struct_name(registry.api)
+    )?;
+
+    super::struct_gen::write_load_fns(ctx, dest)?;
+
+    let groups = super::gen_enum_group_names(registry);
+    let prefix = super::gen_enum_symbol_name(registry.api, "");
+    for cmd in &registry.cmds {
+        let fields = cmd
+            .params
+            .iter()
+            .map(|param| {
+                let ident = &param.ident;
+                match super::gen_enum_arg(param, ident, &groups) {
+                    Some(arg) => format!("{ident} = ?{arg}.with_prefix(\"{prefix}\")"),
+                    None if VALUE_TYPES.contains(&&param.ty[..]) => format!("{ident} = {ident}"),
+                    None => format!("{ident} = ?{ident}"),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        write!(dest, "{}", super::gen_cmd_doc(registry, cmd))?;
+        writeln!(
+            dest,
+            "#[allow(non_snake_case, unused_variables, dead_code)]
+            #[inline] pub unsafe fn {name}(&self, {params}) -> {return_suffix} {{
+                let _span = ::tracing::trace_span!(\"{symbol}\"{fields}).entered();
+                (self.{name}.f)({idents})
+            }}",
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+            symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident),
+            fields = fields
+                .iter()
+                .map(|field| format!(", {field}"))
+                .collect::<String>(),
+            idents = super::gen_parameters(cmd, true, false).join(", "),
+        )?
+    }
+
+    writeln!(
+        dest,
+        "}}
+
+        unsafe impl __gl_imports::Send for {api} {{}}",
+        api = super::gen_struct_name(registry.api)
+    )
+}
@@ -0,0 +1,127 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::Registry;
+use std::io;
+
+/// Generates a trait with one method per command, implemented by the struct of
+/// `StructGenerator`.
+///
+/// Commands that are only provided by extensions get a default implementation that behaves like
+/// a command that was not loaded, so that mock implementations only need to provide the commands
+/// of the requested versions.
+#[allow(missing_copy_implementations)]
+pub struct TraitGenerator;
+
+impl super::Generator for TraitGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        super::struct_gen::write_fnptr_struct_def(dest)?;
+        super::gen_missing_fns(registry, dest)?;
+        super::gen_load_report(registry, dest)?;
+        super::struct_gen::write_struct(&ctx, dest)?;
+        super::struct_gen::write_impl(&ctx, dest)?;
+        write_trait(registry, dest)?;
+        write_trait_impl(registry, dest)?;
+        Ok(())
+    }
+}
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::fmt",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::process",
+    "std::sync::RwLock",
+];
+
+/// Creates the trait, named after the struct with an `Api` suffix.
+fn write_trait<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        "
+        /// Every command of the bindings, as methods that can be implemented by a mock or a
+        /// recording backend.
+        ///
+        /// Commands that are only provided by extensions have a default implementation that
+        /// behaves like a command that was not loaded.
+        #[allow(non_snake_case, dead_code)]
+        pub trait {api}Api {{",
+        api = super::gen_struct_name(registry.api),
+    )?;
+
+    for cmd in &registry.cmds {
+        write!(dest, "{}", super::gen_cmd_doc(registry, cmd))?;
+        let signature = format!(
+            "unsafe fn {name}(&self, {params}) -> {return_suffix}",
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+        );
+        if registry.is_extension_cmd(cmd) {
+            writeln!(
+                dest,
+                "#[allow(unused_variables)]
+                #[track_caller]
+                {signature} {{ missing_fn_called(&missing::info::{name}) }}",
+                name = cmd.proto.ident,
+            )?;
+        } else {
+            writeln!(dest, "{signature};")?;
+        }
+    }
+
+    writeln!(dest, "}}")
+}
+
+/// Creates the `impl` of the trait created by `write_trait` for the structure of the bindings.
+fn write_trait_impl<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        "#[allow(non_snake_case)]
+        impl {api}Api for {api} {{",
+        api = super::gen_struct_name(registry.api),
+    )?;
+
+    for cmd in &registry.cmds {
+        writeln!(
+            dest,
+            "#[inline] unsafe fn {name}(&self, {params}) -> {return_suffix} {{ \
+                {api}::{name}(self, {idents}) \
+            }}",
+            api = super::gen_struct_name(registry.api),
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+            idents = super::gen_parameters(cmd, true, false).join(", "),
+        )?;
+    }
+
+    writeln!(dest, "}}")
+}
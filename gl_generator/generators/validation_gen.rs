@@ -0,0 +1,186 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use registry::Registry;
+use std::io;
+
+/// Generates the struct of `StructGenerator`, where each command checks its enum arguments
+/// against the values of their group before it runs.
+///
+/// The bindings use the `enum_name` and `group_enum_name` functions of `EnumNamesGenerator`,
+/// which must be written into the same module.
+#[allow(missing_copy_implementations)]
+pub struct ValidationGenerator;
+
+impl super::Generator for ValidationGenerator {
+    fn write<W>(&self, registry: &Registry, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = super::GenContext::new(registry).with_imports(IMPORTS.iter().copied());
+        super::sections::write_header(&ctx, dest)?;
+        super::sections::write_type_aliases(&ctx, dest)?;
+        super::sections::write_enums(&ctx, dest)?;
+        write_invalid_enum(registry, dest)?;
+        super::struct_gen::write_fnptr_struct_def(dest)?;
+        super::gen_missing_fns(registry, dest)?;
+        super::gen_load_report(registry, dest)?;
+        super::struct_gen::write_struct_with_fields(&ctx, &[HANDLER_FIELD], dest)?;
+        write_impl(&ctx, dest)?;
+        Ok(())
+    }
+}
+
+/// The field of the struct that stores the function called for invalid arguments.
+const HANDLER_FIELD: (&str, &str) = (
+    "invalid_enum_handler",
+    "__gl_imports::Arc<dyn Fn(&InvalidEnum) + Send + Sync>",
+);
+
+/// The paths of the `__gl_imports` module, which contains all the external symbols that we need
+/// for the bindings.
+const IMPORTS: &[&str] = &[
+    "std::fmt",
+    "std::mem",
+    "std::marker::Send",
+    "std::os::raw",
+    "std::process",
+    "std::sync::Arc",
+    "std::sync::RwLock",
+];
+
+/// Creates the `InvalidEnum` struct passed to the handler, and the default handler.
+fn write_invalid_enum<W>(registry: &Registry, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    writeln!(
+        dest,
+        r#"
+        /// An enum argument that is not one of the values of its group.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct InvalidEnum {{
+            /// The symbol of the command, for example `"glTexParameteri"`.
+            pub command: &'static str,
+            /// The name of the parameter.
+            pub param: &'static str,
+            /// The group of the parameter in the registry, for example `"TextureParameterName"`.
+            pub group: &'static str,
+            /// The value of the argument.
+            pub value: u32,
+        }}
+
+        impl __gl_imports::fmt::Display for InvalidEnum {{
+            fn fmt(&self, f: &mut __gl_imports::fmt::Formatter) -> __gl_imports::fmt::Result {{
+                write!(f, "{{}}: {{:#06x}}", self.command, self.value)?;
+                if let Some(name) = enum_name(self.value) {{
+                    write!(f, " ({{name}})")?;
+                }}
+                write!(f, " is not a valid {{}}", self.group)
+            }}
+        }}
+
+        /// Prints an invalid argument to stderr. This is the default handler of `{api}`.
+        #[allow(dead_code)]
+        pub fn print_invalid_enum(invalid: &InvalidEnum) {{
+            eprintln!("{{invalid}}");
+        }}
+    "#,
+        api = super::gen_struct_name(registry.api),
+    )
+}
+
+/// Creates the `impl` of the structure created by `write_struct_with_fields`, where each command
+/// checks the arguments of its `GLenum` parameters that belong to a group with known values.
+fn write_impl<W>(ctx: &super::GenContext, dest: &mut W) -> io::Result<()>
+where
+    W: io::Write,
+{
+    let registry = ctx.registry();
+    writeln!(
+        dest,
+        "impl {api} {{",
+        api = super::gen_struct_name(registry.api)
+    )?;
+
+    super::struct_gen::write_load_fns_with_fields(
+        ctx,
+        &[(
+            HANDLER_FIELD.0,
+            "__gl_imports::Arc::new(print_invalid_enum)",
+        )],
+        dest,
+    )?;
+
+    writeln!(
+        dest,
+        "/// Replaces the function that is called with each invalid argument, before the command
+        /// runs. The default handler is `print_invalid_enum`.
+        #[allow(dead_code)]
+        pub fn set_invalid_enum_handler<F>(&mut self, handler: F)
+            where F: Fn(&InvalidEnum) + Send + Sync + 'static {{
+            self.invalid_enum_handler = __gl_imports::Arc::new(handler);
+        }}
+
+        #[allow(dead_code)]
+        #[inline] fn check_enum(&self, command: &'static str, param: &'static str, group: &'static str, value: u32) {{
+            if group_enum_name(group, value).is_none() {{
+                (self.invalid_enum_handler)(&InvalidEnum {{ command, param, group, value }});
+            }}
+        }}"
+    )?;
+
+    let enums = super::enums_by_group_name(registry);
+    for cmd in &registry.cmds {
+        let symbol = super::gen_symbol_name(registry.api, &cmd.proto.ident);
+        let checks = cmd
+            .params
+            .iter()
+            .filter(|param| param.ty == "types::GLenum")
+            .filter_map(|param| {
+                let group = registry.groups.get(param.group.as_ref()?)?;
+                if super::group_values(registry, group, &enums).is_empty() {
+                    return None;
+                }
+                Some(format!(
+                    "self.check_enum(\"{symbol}\", \"{param}\", \"{group}\", {param});",
+                    param = param.ident,
+                    group = group.ident,
+                ))
+            })
+            .collect::<String>();
+
+        write!(dest, "{}", super::gen_cmd_doc(registry, cmd))?;
+        writeln!(
+            dest,
+            "#[allow(non_snake_case, unused_variables, dead_code)]
+            #[inline] pub unsafe fn {name}(&self, {params}) -> {return_suffix} {{ \
+                {checks} \
+                (self.{name}.f)({idents}) \
+            }}",
+            name = cmd.proto.ident,
+            params = super::gen_parameters(cmd, true, true).join(", "),
+            return_suffix = cmd.proto.ty,
+            idents = super::gen_parameters(cmd, true, false).join(", "),
+        )?
+    }
+
+    writeln!(
+        dest,
+        "}}
+
+        unsafe impl __gl_imports::Send for {api} {{}}",
+        api = super::gen_struct_name(registry.api)
+    )
+}
@@ -62,20 +62,42 @@
 
 #[macro_use]
 extern crate log;
+#[cfg(feature = "tokens")]
+extern crate prettyplease;
+#[cfg(feature = "tokens")]
+extern crate proc_macro2;
+#[cfg(feature = "tokens")]
+extern crate syn;
 extern crate xml;
 
-#[cfg(feature = "unstable_generator_utils")]
 pub mod generators;
-#[cfg(not(feature = "unstable_generator_utils"))]
-mod generators;
 
 mod registry;
 
-pub use generators::debug_struct_gen::DebugStructGenerator;
+pub use generators::apitrace_gen::ApitraceGenerator;
+pub use generators::checked_struct_gen::{CheckedStructGenerator, UncheckedStructGenerator};
+pub use generators::debug_struct_gen::{DebugStructGenerator, LogDebugStructGenerator};
+pub use generators::enum_names_gen::EnumNamesGenerator;
 pub use generators::global_gen::GlobalGenerator;
+pub use generators::glx_protocol_gen::GlxProtocolGenerator;
+pub use generators::handle_struct_gen::HandleStructGenerator;
+pub use generators::lazy_gen::LazyGenerator;
+pub use generators::null_gen::NullGenerator;
+pub use generators::object_tracking_gen::ObjectTrackingGenerator;
+pub use generators::slice_struct_gen::SliceStructGenerator;
 pub use generators::static_gen::StaticGenerator;
 pub use generators::static_struct_gen::StaticStructGenerator;
 pub use generators::struct_gen::StructGenerator;
-pub use generators::Generator;
+pub use generators::thread_local_gen::ThreadLocalGenerator;
+#[cfg(feature = "tokens")]
+pub use generators::tokens::{Pretty, TokenGenerator};
+pub use generators::trace_gen::TraceGenerator;
+pub use generators::tracing_gen::TracingGenerator;
+pub use generators::trait_gen::TraitGenerator;
+pub use generators::validation_gen::ValidationGenerator;
+pub use generators::{
+    GenContext, Generator, GeneratorOptions, InlinePolicy, NameKind, Naming, StructGeneratorWith,
+    StructSections, Visibility,
+};
 
 pub use registry::*;
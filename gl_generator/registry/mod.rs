@@ -21,10 +21,12 @@ use std::hash::{Hash, Hasher};
 use std::io;
 use std::ops::{Add, AddAssign};
 
-use Generator;
+use {Generator, GeneratorOptions};
 
 mod parse;
 
+pub(crate) use self::parse::underscore_keyword;
+
 const BYTE_ORDER_MARK: &[u8] = &[0xef, 0xbb, 0xbf];
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -81,11 +83,37 @@ impl Hash for Enum {
     }
 }
 
+/// A parameter or the return value of a command.
+///
+/// This struct is `#[non_exhaustive]`, so that the registry can describe more of each binding
+/// without breaking other crates. Create it with `Binding::new`, then set the other fields.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
 pub struct Binding {
     pub ident: String,
     pub ty: Cow<'static, str>,
     pub group: Option<String>,
+    /// The number of elements a pointer parameter points to, as written in the registry, for
+    /// example `"n"`, `"count*4"` or `"COMPSIZE(pname)"`.
+    pub len: Option<String>,
+    /// The class of the object named by the binding, for example `"buffer"` or `"texture"`.
+    pub class: Option<String>,
+}
+
+impl Binding {
+    /// Creates a binding without a group, length or class.
+    pub fn new<T>(ident: &str, ty: T) -> Binding
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Binding {
+            ident: ident.to_string(),
+            ty: ty.into(),
+            group: None,
+            len: None,
+            class: None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -110,22 +138,71 @@ impl Hash for Cmd {
     }
 }
 
+/// The GLX protocol request of a command.
+///
+/// This struct is `#[non_exhaustive]`. Create it with `GlxOpcode::new`.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
 pub struct GlxOpcode {
+    /// The kind of GLX request that encodes the command: `"render"`, `"single"` or `"vendor"`.
+    pub ty: String,
     pub opcode: String,
     pub name: Option<String>,
 }
 
+impl GlxOpcode {
+    /// Creates the opcode of a request of kind `ty`, without a name.
+    pub fn new(ty: &str, opcode: &str) -> GlxOpcode {
+        GlxOpcode {
+            ty: ty.to_string(),
+            opcode: opcode.to_string(),
+            name: None,
+        }
+    }
+}
+
+/// The feature or extension that provides a command.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Origin {
+    /// A version of the API, for example `"3.0"`.
+    Version(String),
+    /// An extension, for example `"GL_ARB_debug_output"`.
+    Extension(String),
+}
+
+/// The commands, enums and groups of an API.
+///
+/// This struct is `#[non_exhaustive]`, so that the registry can record more of the XML without
+/// breaking other crates. Create it with `Registry::new`, or with `Registry::empty` to fill it
+/// yourself.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Registry {
     pub api: Api,
     pub enums: BTreeSet<Enum>,
     pub cmds: BTreeSet<Cmd>,
     pub aliases: BTreeMap<String, Vec<String>>,
     pub groups: BTreeMap<String, Group>,
+    /// The requested versions and extensions that provide each command, by command ident.
+    pub cmd_origins: BTreeMap<String, BTreeSet<Origin>>,
+    /// The requested versions and extensions that provide each enum, by enum ident.
+    pub enum_origins: BTreeMap<String, BTreeSet<Origin>>,
 }
 
 impl Registry {
+    /// Creates a registry of `api` without any command, enum or group.
+    pub fn empty(api: Api) -> Registry {
+        Registry {
+            api,
+            enums: BTreeSet::new(),
+            cmds: BTreeSet::new(),
+            aliases: BTreeMap::new(),
+            groups: BTreeMap::new(),
+            cmd_origins: BTreeMap::new(),
+            enum_origins: BTreeMap::new(),
+        }
+    }
+
     pub fn new<'a, Exts>(
         api: Api,
         version: (u8, u8),
@@ -182,6 +259,20 @@ impl Registry {
         generator.write(self, output)
     }
 
+    /// Same as `write_bindings`, with options for the items written by the generator.
+    pub fn write_bindings_with_options<W, G>(
+        &self,
+        generator: G,
+        options: &GeneratorOptions,
+        output: &mut W,
+    ) -> io::Result<()>
+    where
+        G: Generator,
+        W: io::Write,
+    {
+        generator.write_with_options(self, options, output)
+    }
+
     /// Returns a set of all the types used in the supplied registry. This is useful
     /// for working out what conversions are needed for the specific registry.
     pub fn get_tys(&self) -> BTreeSet<&str> {
@@ -194,6 +285,18 @@ impl Registry {
         }
         tys
     }
+
+    /// Returns `true` if the command is only provided by extensions, and not by any of the
+    /// requested versions.
+    pub fn is_extension_cmd(&self, cmd: &Cmd) -> bool {
+        match self.cmd_origins.get(&cmd.proto.ident) {
+            Some(origins) => !origins.iter().any(|origin| match *origin {
+                Origin::Version(_) => true,
+                Origin::Extension(_) => false,
+            }),
+            None => false,
+        }
+    }
 }
 
 impl Add for Registry {
@@ -210,5 +313,11 @@ impl AddAssign for Registry {
         self.enums.extend(other.enums);
         self.cmds.extend(other.cmds);
         self.aliases.extend(other.aliases);
+        for (ident, origins) in other.cmd_origins {
+            self.cmd_origins.entry(ident).or_default().extend(origins);
+        }
+        for (ident, origins) in other.enum_origins {
+            self.enum_origins.entry(ident).or_default().extend(origins);
+        }
     }
 }
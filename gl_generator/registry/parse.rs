@@ -22,7 +22,7 @@ use xml::attribute::OwnedAttribute;
 use xml::reader::XmlEvent;
 use xml::EventReader as XmlEventReader;
 
-use registry::{Binding, Cmd, Enum, GlxOpcode, Group, Registry};
+use registry::{Binding, Cmd, Enum, GlxOpcode, Group, Origin, Registry};
 use {Api, Fallbacks, Profile};
 
 pub fn from_xml<R: io::Read>(src: R, filter: &Filter, require_feature: bool) -> Registry {
@@ -116,12 +116,20 @@ fn underscore_numeric_prefix(src: &str) -> String {
     }
 }
 
-fn underscore_keyword(ident: String) -> String {
-    match ident.as_ref() {
-        "in" => "in_".to_string(),
-        "ref" => "ref_".to_string(),
-        "type" => "type_".to_string(),
-        _ => ident,
+/// The keywords of every Rust edition, including the reserved ones.
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Appends an underscore to identifiers that are Rust keywords, for example `type_` for `type`.
+pub(crate) fn underscore_keyword(ident: String) -> String {
+    match KEYWORDS.contains(&&ident[..]) {
+        true => ident + "_",
+        false => ident,
     }
 }
 
@@ -347,6 +355,8 @@ trait Parse: Sized + Iterator<Item = ParseEvent> {
 
         let mut desired_enums = BTreeSet::new();
         let mut desired_cmds = BTreeSet::new();
+        let mut cmd_origins: BTreeMap<String, BTreeSet<Origin>> = BTreeMap::new();
+        let mut enum_origins: BTreeMap<String, BTreeSet<Origin>> = BTreeMap::new();
 
         // find the features we want
         let mut found_feature = false;
@@ -356,6 +366,14 @@ trait Parse: Sized + Iterator<Item = ParseEvent> {
                 for require in &feature.requires {
                     desired_enums.extend(require.enums.iter().cloned());
                     desired_cmds.extend(require.commands.iter().cloned());
+                    for cmd in &require.commands {
+                        let origin = Origin::Version(feature.number.clone());
+                        cmd_origins.entry(cmd.clone()).or_default().insert(origin);
+                    }
+                    for enm in &require.enums {
+                        let origin = Origin::Version(feature.number.clone());
+                        enum_origins.entry(enm.clone()).or_default().insert(origin);
+                    }
                 }
 
                 for remove in &feature.removes {
@@ -363,10 +381,12 @@ trait Parse: Sized + Iterator<Item = ParseEvent> {
                         for enm in &remove.enums {
                             debug!("Removing {enm}");
                             desired_enums.remove(enm);
+                            enum_origins.remove(enm);
                         }
                         for cmd in &remove.commands {
                             debug!("Removing {cmd}");
                             desired_cmds.remove(cmd);
+                            cmd_origins.remove(cmd);
                         }
                     }
                 }
@@ -391,6 +411,14 @@ trait Parse: Sized + Iterator<Item = ParseEvent> {
                 for require in &extension.requires {
                     desired_enums.extend(require.enums.iter().cloned());
                     desired_cmds.extend(require.commands.iter().cloned());
+                    for cmd in &require.commands {
+                        let origin = Origin::Extension(extension.name.clone());
+                        cmd_origins.entry(cmd.clone()).or_default().insert(origin);
+                    }
+                    for enm in &require.enums {
+                        let origin = Origin::Extension(extension.name.clone());
+                        enum_origins.entry(enm.clone()).or_default().insert(origin);
+                    }
                 }
             }
         }
@@ -409,16 +437,42 @@ trait Parse: Sized + Iterator<Item = ParseEvent> {
                 || desired_cmds.contains(&("egl".to_string() + &c.proto.ident))
         };
 
+        let cmds: BTreeSet<Cmd> = cmds.into_iter().filter(is_desired_cmd).collect();
+        let cmd_origins = cmds
+            .iter()
+            .filter_map(|c| {
+                ["gl", "wgl", "glX", "egl"]
+                    .iter()
+                    .filter_map(|prefix| cmd_origins.remove(&(prefix.to_string() + &c.proto.ident)))
+                    .next()
+                    .map(|origins| (c.proto.ident.clone(), origins))
+            })
+            .collect();
+
+        let enums: BTreeSet<Enum> = enums.into_iter().filter(is_desired_enum).collect();
+        let enum_origins = enums
+            .iter()
+            .filter_map(|e| {
+                ["GL_", "WGL_", "GLX_", "EGL_"]
+                    .iter()
+                    .filter_map(|prefix| enum_origins.remove(&(prefix.to_string() + &e.ident)))
+                    .next()
+                    .map(|origins| (e.ident.clone(), origins))
+            })
+            .collect();
+
         Registry {
             api: filter.api,
-            enums: enums.into_iter().filter(is_desired_enum).collect(),
-            cmds: cmds.into_iter().filter(is_desired_cmd).collect(),
+            enums,
+            cmds,
             aliases: if filter.fallbacks == Fallbacks::None {
                 BTreeMap::new()
             } else {
                 aliases
             },
             groups,
+            cmd_origins,
+            enum_origins,
         }
     }
 
@@ -615,8 +669,8 @@ trait Parse: Sized + Iterator<Item = ParseEvent> {
 
     fn consume_cmd(&mut self, api: Api) -> Cmd {
         // consume command prototype
-        self.consume_start_element("proto");
-        let mut proto = self.consume_binding("proto", &[]);
+        let attributes = self.consume_start_element("proto");
+        let mut proto = self.consume_binding("proto", &attributes);
         proto.ident = trim_cmd_prefix(&proto.ident, api).to_string();
 
         let mut params = Vec::new();
@@ -634,14 +688,20 @@ trait Parse: Sized + Iterator<Item = ParseEvent> {
                     self.consume_end_element("alias");
                 },
                 ParseEvent::Start(ref name, ref attributes) if name == "vecequiv" => {
-                    vecequiv = get_attribute(attributes, "vecequiv");
+                    vecequiv = get_attribute(attributes, "name");
+                    vecequiv = vecequiv.map(|t| trim_cmd_prefix(&t, api).to_string());
                     self.consume_end_element("vecequiv");
                 },
                 ParseEvent::Start(ref name, ref attributes) if name == "glx" => {
-                    glx = Some(GlxOpcode {
-                        opcode: get_attribute(attributes, "opcode").unwrap(),
-                        name: get_attribute(attributes, "name"),
-                    });
+                    // the other opcodes of a command are variants, like the PBO protocol of
+                    // `glBitmap`
+                    if glx.is_none() {
+                        glx = Some(GlxOpcode {
+                            ty: get_attribute(attributes, "type").unwrap(),
+                            opcode: get_attribute(attributes, "opcode").unwrap(),
+                            name: get_attribute(attributes, "name"),
+                        });
+                    }
                     self.consume_end_element("glx");
                 },
                 ParseEvent::End(ref name) if name == "command" => break,
@@ -688,6 +748,8 @@ trait Parse: Sized + Iterator<Item = ParseEvent> {
             ident,
             ty: to_rust_ty(ty),
             group: get_attribute(attributes, "group"),
+            len: get_attribute(attributes, "len"),
+            class: get_attribute(attributes, "class"),
         }
     }
 }
@@ -1095,6 +1157,265 @@ pub fn to_rust_ty<T: AsRef<str>>(ty: T) -> Cow<'static, str> {
 
 #[cfg(test)]
 mod tests {
+    mod from_xml {
+        use registry::parse::{self, Filter};
+        use registry::{GlxOpcode, Origin};
+        use std::collections::BTreeSet;
+        use {Api, Fallbacks, Profile};
+
+        const XML: &str = r#"
+            <registry>
+                <enums namespace="GL">
+                    <enum value="0x00004000" name="GL_COLOR_BUFFER_BIT"/>
+                    <enum value="0x8B30" name="GL_FRAGMENT_SHADER"/>
+                    <enum value="0x1" name="GL_FOO_EXT"/>
+                </enums>
+                <commands namespace="GL">
+                    <command><proto>void <name>glClear</name></proto></command>
+                    <command>
+                        <proto>void <name>glFlush</name></proto>
+                        <glx type="single" opcode="142"/>
+                        <glx type="render" opcode="999" name="glFlushPBO" comment="PBO protocol"/>
+                    </command>
+                    <command>
+                        <proto group="ErrorCode"><ptype>GLenum</ptype> <name>glGetError</name></proto>
+                    </command>
+                    <command>
+                        <proto>void <name>glFooEXT</name></proto>
+                        <param><ptype>GLsizei</ptype> <name>n</name></param>
+                        <param len="n">const <ptype>GLuint</ptype> *<name>ids</name></param>
+                    </command>
+                    <command><proto>void <name>glBarEXT</name></proto></command>
+                    <command>
+                        <proto>void <name>glBindBuffer</name></proto>
+                        <param group="BufferTargetARB"><ptype>GLenum</ptype> <name>target</name></param>
+                        <param class="buffer"><ptype>GLuint</ptype> <name>buffer</name></param>
+                    </command>
+                    <command>
+                        <proto class="program"><ptype>GLuint</ptype> <name>glCreateProgram</name></proto>
+                    </command>
+                    <command>
+                        <proto>void <name>glColor3f</name></proto>
+                        <param><ptype>GLfloat</ptype> <name>red</name></param>
+                        <param><ptype>GLfloat</ptype> <name>green</name></param>
+                        <param><ptype>GLfloat</ptype> <name>blue</name></param>
+                        <vecequiv name="glColor3fv"/>
+                    </command>
+                    <command>
+                        <proto>void <name>glColor3fv</name></proto>
+                        <param len="3">const <ptype>GLfloat</ptype> *<name>v</name></param>
+                    </command>
+                </commands>
+                <feature api="gl" name="GL_VERSION_1_0" number="1.0">
+                    <require>
+                        <command name="glClear"/>
+                        <command name="glGetError"/>
+                        <command name="glBindBuffer"/>
+                        <command name="glCreateProgram"/>
+                        <command name="glColor3f"/>
+                        <command name="glColor3fv"/>
+                        <enum name="GL_COLOR_BUFFER_BIT"/>
+                    </require>
+                </feature>
+                <feature api="gl" name="GL_VERSION_1_1" number="1.1">
+                    <require><command name="glFlush"/></require>
+                </feature>
+                <extensions>
+                    <extension name="GL_EXT_foo" supported="gl">
+                        <require>
+                            <command name="glClear"/>
+                            <command name="glFooEXT"/>
+                            <enum name="GL_COLOR_BUFFER_BIT"/>
+                            <enum name="GL_FOO_EXT"/>
+                        </require>
+                    </extension>
+                    <extension name="GL_EXT_bar" supported="gl">
+                        <require><command name="glBarEXT"/></require>
+                    </extension>
+                </extensions>
+            </registry>
+        "#;
+
+        #[test]
+        fn test_cmd_origins() {
+            let filter = Filter {
+                api: Api::Gl,
+                fallbacks: Fallbacks::All,
+                extensions: vec!["GL_EXT_foo".to_string()].into_iter().collect(),
+                profile: Profile::Core,
+                version: "1.1".to_string(),
+            };
+            let registry = parse::from_xml(XML.as_bytes(), &filter, true);
+
+            let origins = |ident: &str| registry.cmd_origins[ident].clone();
+            let expected: BTreeSet<_> = vec![
+                Origin::Version("1.0".to_string()),
+                Origin::Extension("GL_EXT_foo".to_string()),
+            ]
+            .into_iter()
+            .collect();
+            assert_eq!(origins("Clear"), expected);
+            let expected: BTreeSet<_> = vec![Origin::Version("1.1".to_string())]
+                .into_iter()
+                .collect();
+            assert_eq!(origins("Flush"), expected);
+            let expected: BTreeSet<_> = vec![Origin::Extension("GL_EXT_foo".to_string())]
+                .into_iter()
+                .collect();
+            assert_eq!(origins("FooEXT"), expected);
+            assert!(!registry.cmd_origins.contains_key("BarEXT"));
+
+            let is_extension_cmd = |ident: &str| {
+                let cmd = registry.cmds.iter().find(|c| c.proto.ident == ident);
+                registry.is_extension_cmd(cmd.unwrap())
+            };
+            assert!(!is_extension_cmd("Clear"));
+            assert!(!is_extension_cmd("Flush"));
+            assert!(is_extension_cmd("FooEXT"));
+        }
+
+        #[test]
+        fn test_enum_origins() {
+            let filter = Filter {
+                api: Api::Gl,
+                fallbacks: Fallbacks::All,
+                extensions: vec!["GL_EXT_foo".to_string()].into_iter().collect(),
+                profile: Profile::Core,
+                version: "1.1".to_string(),
+            };
+            let registry = parse::from_xml(XML.as_bytes(), &filter, true);
+
+            let origins = |ident: &str| registry.enum_origins[ident].clone();
+            let expected: BTreeSet<_> = vec![
+                Origin::Version("1.0".to_string()),
+                Origin::Extension("GL_EXT_foo".to_string()),
+            ]
+            .into_iter()
+            .collect();
+            assert_eq!(origins("COLOR_BUFFER_BIT"), expected);
+            let expected: BTreeSet<_> = vec![Origin::Extension("GL_EXT_foo".to_string())]
+                .into_iter()
+                .collect();
+            assert_eq!(origins("FOO_EXT"), expected);
+            assert!(!registry.enum_origins.contains_key("FRAGMENT_SHADER"));
+        }
+
+        #[test]
+        fn test_param_len() {
+            let filter = Filter {
+                api: Api::Gl,
+                fallbacks: Fallbacks::All,
+                extensions: vec!["GL_EXT_foo".to_string()].into_iter().collect(),
+                profile: Profile::Core,
+                version: "1.0".to_string(),
+            };
+            let registry = parse::from_xml(XML.as_bytes(), &filter, true);
+
+            let cmd = registry.cmds.iter().find(|c| c.proto.ident == "FooEXT");
+            let params = &cmd.unwrap().params;
+            assert_eq!(params[0].len, None);
+            assert_eq!(params[1].ident, "ids");
+            assert_eq!(params[1].len, Some("n".to_string()));
+        }
+
+        #[test]
+        fn test_proto_group() {
+            let filter = Filter {
+                api: Api::Gl,
+                fallbacks: Fallbacks::All,
+                extensions: BTreeSet::new(),
+                profile: Profile::Core,
+                version: "1.0".to_string(),
+            };
+            let registry = parse::from_xml(XML.as_bytes(), &filter, true);
+
+            let proto = |ident: &str| {
+                let cmd = registry.cmds.iter().find(|c| c.proto.ident == ident);
+                cmd.unwrap().proto.clone()
+            };
+            assert_eq!(proto("GetError").group, Some("ErrorCode".to_string()));
+            assert_eq!(proto("Clear").group, None);
+        }
+
+        #[test]
+        fn test_class() {
+            let filter = Filter {
+                api: Api::Gl,
+                fallbacks: Fallbacks::All,
+                extensions: BTreeSet::new(),
+                profile: Profile::Core,
+                version: "1.0".to_string(),
+            };
+            let registry = parse::from_xml(XML.as_bytes(), &filter, true);
+
+            let cmd = |ident: &str| {
+                registry
+                    .cmds
+                    .iter()
+                    .find(|c| c.proto.ident == ident)
+                    .unwrap()
+            };
+            let params = &cmd("BindBuffer").params;
+            assert_eq!(params[0].class, None);
+            assert_eq!(params[1].class, Some("buffer".to_string()));
+            assert_eq!(
+                cmd("CreateProgram").proto.class,
+                Some("program".to_string())
+            );
+        }
+
+        #[test]
+        fn test_vecequiv() {
+            let filter = Filter {
+                api: Api::Gl,
+                fallbacks: Fallbacks::All,
+                extensions: BTreeSet::new(),
+                profile: Profile::Core,
+                version: "1.0".to_string(),
+            };
+            let registry = parse::from_xml(XML.as_bytes(), &filter, true);
+
+            let cmd = |ident: &str| {
+                registry
+                    .cmds
+                    .iter()
+                    .find(|c| c.proto.ident == ident)
+                    .unwrap()
+            };
+            assert_eq!(cmd("Color3f").vecequiv, Some("Color3fv".to_string()));
+            assert_eq!(cmd("Color3fv").vecequiv, None);
+        }
+
+        #[test]
+        fn test_glx() {
+            let filter = Filter {
+                api: Api::Gl,
+                fallbacks: Fallbacks::All,
+                extensions: BTreeSet::new(),
+                profile: Profile::Core,
+                version: "1.1".to_string(),
+            };
+            let registry = parse::from_xml(XML.as_bytes(), &filter, true);
+
+            let cmd = |ident: &str| {
+                registry
+                    .cmds
+                    .iter()
+                    .find(|c| c.proto.ident == ident)
+                    .unwrap()
+            };
+            assert_eq!(
+                cmd("Flush").glx,
+                Some(GlxOpcode {
+                    ty: "single".to_string(),
+                    opcode: "142".to_string(),
+                    name: None,
+                })
+            );
+            assert_eq!(cmd("Clear").glx, None);
+        }
+    }
+
     mod underscore_numeric_prefix {
         use registry::parse;
 
@@ -1120,6 +1441,9 @@ mod tests {
             assert_eq!(parse::underscore_keyword("in".to_string()), "in_");
             assert_eq!(parse::underscore_keyword("ref".to_string()), "ref_");
             assert_eq!(parse::underscore_keyword("type".to_string()), "type_");
+            assert_eq!(parse::underscore_keyword("fn".to_string()), "fn_");
+            assert_eq!(parse::underscore_keyword("async".to_string()), "async_");
+            assert_eq!(parse::underscore_keyword("Self".to_string()), "Self_");
         }
 
         #[test]
@@ -29,4 +29,21 @@ fn main() {
     (registry0 + registry1)
         .write_bindings(GlobalGenerator, &mut file)
         .unwrap();
+
+    // a registry filled by hand, with a command that is not in gl.xml
+    let mut file = File::create(Path::new(&dest).join("test_add_registries_custom.rs")).unwrap();
+    let mut registry = Registry::empty(Api::Gl);
+    let mut param = Binding::new("count", "types::GLsizei");
+    param.len = Some("1".to_string());
+    registry.cmds.insert(Cmd {
+        proto: Binding::new("DrawCustomMESA", "()"),
+        params: vec![param],
+        alias: None,
+        vecequiv: None,
+        glx: Some(GlxOpcode::new("render", "4096")),
+    });
+
+    (Registry::new(Api::Gl, (1, 0), Profile::Core, Fallbacks::All, []) + registry)
+        .write_bindings(GlobalGenerator, &mut file)
+        .unwrap();
 }
@@ -15,9 +15,25 @@
 pub mod gl {
     #![allow(
         clippy::missing_safety_doc,
-        clippy::missing_transmute_annotations,
         clippy::too_many_arguments,
         clippy::unused_unit
     )]
     include!(concat!(env!("OUT_DIR"), "/test_add_registries.rs"));
 }
+
+pub mod custom_gl {
+    #![allow(
+        clippy::missing_safety_doc,
+        clippy::too_many_arguments,
+        clippy::unused_unit
+    )]
+    include!(concat!(env!("OUT_DIR"), "/test_add_registries_custom.rs"));
+}
+
+#[test]
+fn test_custom_registry() {
+    let _: unsafe fn(custom_gl::types::GLsizei) = custom_gl::DrawCustomMESA;
+    custom_gl::load_with(|_| std::ptr::null());
+    assert!(!custom_gl::DrawCustomMESA::is_loaded());
+    assert!(!custom_gl::Clear::is_loaded());
+}
@@ -0,0 +1,315 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_apitrace.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{gl, null_gl};
+    use std::collections::HashMap;
+    use std::fmt::Write;
+
+    /// Returns the uncompressed data of a trace made of literal-only snappy chunks.
+    fn decompress(trace: &[u8]) -> Vec<u8> {
+        assert_eq!(&trace[..2], b"at");
+        let mut data = Vec::new();
+        let mut rest = &trace[2..];
+        while !rest.is_empty() {
+            let len = u32::from_le_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+            let mut chunk = &rest[4..4 + len];
+            rest = &rest[4 + len..];
+
+            let expected = varuint(&mut chunk) as usize;
+            assert!(expected <= 1024 * 1024);
+            let tag = chunk[0] as usize >> 2;
+            let (literal_len, skip) = match tag {
+                0..=59 => (tag + 1, 1),
+                60 => (chunk[1] as usize + 1, 2),
+                61 => (u16::from_le_bytes([chunk[1], chunk[2]]) as usize + 1, 3),
+                _ => (
+                    u32::from_le_bytes([chunk[1], chunk[2], chunk[3], 0]) as usize + 1,
+                    4,
+                ),
+            };
+            assert_eq!(literal_len, expected);
+            assert_eq!(chunk.len(), skip + literal_len);
+            data.extend_from_slice(&chunk[skip..]);
+        }
+        data
+    }
+
+    fn varuint(data: &mut &[u8]) -> u64 {
+        let mut value = 0;
+        let mut shift = 0;
+        loop {
+            let byte = data[0];
+            *data = &data[1..];
+            value |= ((byte & 0x7f) as u64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return value;
+            }
+        }
+    }
+
+    fn string(data: &mut &[u8]) -> String {
+        let len = varuint(data) as usize;
+        let string = String::from_utf8_lossy(&data[..len]).into_owned();
+        *data = &data[len..];
+        string
+    }
+
+    fn value(data: &mut &[u8]) -> String {
+        let ty = data[0];
+        *data = &data[1..];
+        match ty {
+            0 => "NULL".to_string(),
+            3 => format!("-{}", varuint(data)),
+            4 => varuint(data).to_string(),
+            5 => {
+                let value = f32::from_le_bytes([data[0], data[1], data[2], data[3]]);
+                *data = &data[4..];
+                value.to_string()
+            },
+            7 => format!("{:?}", string(data)),
+            8 => {
+                let len = varuint(data) as usize;
+                *data = &data[len..];
+                format!("blob({len})")
+            },
+            11 => {
+                let len = varuint(data);
+                let values = (0..len).map(|_| value(data)).collect::<Vec<_>>();
+                format!("[{}]", values.join(", "))
+            },
+            13 => {
+                varuint(data);
+                "opaque".to_string()
+            },
+            _ => panic!("unexpected type {}", ty),
+        }
+    }
+
+    /// Returns the version of the trace and a description of each call.
+    fn decode(trace: &[u8]) -> (u64, Vec<String>) {
+        let data = decompress(trace);
+        let mut data = &data[..];
+        let version = varuint(&mut data);
+        let mut sigs: HashMap<u64, (String, Vec<String>)> = HashMap::new();
+        let mut calls: Vec<String> = Vec::new();
+        let mut call_sigs = Vec::new();
+        while !data.is_empty() {
+            let event = data[0];
+            data = &data[1..];
+            let call = if event == 0 {
+                assert_eq!(varuint(&mut data), 0);
+                let id = varuint(&mut data);
+                sigs.entry(id).or_insert_with(|| {
+                    let name = string(&mut data);
+                    let args = (0..varuint(&mut data)).map(|_| string(&mut data)).collect();
+                    (name, args)
+                });
+                calls.push(format!("{}(", sigs[&id].0));
+                call_sigs.push(id);
+                calls.len() - 1
+            } else {
+                assert_eq!(event, 1);
+                varuint(&mut data) as usize
+            };
+            let mut first = calls[call].ends_with('(');
+            loop {
+                let detail = data[0];
+                data = &data[1..];
+                match detail {
+                    0 => break,
+                    1 => {
+                        let index = varuint(&mut data) as usize;
+                        let name = &sigs[&call_sigs[call]].1[index];
+                        let value = value(&mut data);
+                        let separator = if first { "" } else { ", " };
+                        write!(calls[call], "{separator}{name}={value}").unwrap();
+                        first = false;
+                    },
+                    2 => {
+                        let value = value(&mut data);
+                        write!(calls[call], ") = {value}").unwrap();
+                    },
+                    _ => panic!("unexpected call detail {}", detail),
+                }
+            }
+            if event == 1 && !calls[call].contains(") = ") {
+                calls[call].push(')');
+            }
+        }
+        (version, calls)
+    }
+
+    #[test]
+    fn test_calls() {
+        let recorder = gl::Recorder::new(gl::Gl::load_with(null_gl::load), Vec::new());
+        let mut buffers = [0; 2];
+        unsafe {
+            recorder.Clear(gl::COLOR_BUFFER_BIT);
+            recorder.GenBuffers(2, buffers.as_mut_ptr());
+            recorder.BufferData(
+                gl::ARRAY_BUFFER,
+                3,
+                [1u8, 2, 3].as_ptr() as *const _,
+                gl::STATIC_DRAW,
+            );
+            recorder.Uniform1f(-1, 0.5);
+            recorder.GetUniformLocation(buffers[0], b"color\0".as_ptr() as *const _);
+            recorder.GetString(gl::VERSION);
+        }
+        let (version, calls) = decode(&recorder.finish().unwrap());
+        assert_eq!(version, gl::APITRACE_VERSION);
+        assert_eq!(
+            calls,
+            [
+                "glClear(mask=16384)".to_string(),
+                format!(
+                    "glGenBuffers(n=2, buffers=[{}, {}])",
+                    buffers[0], buffers[1]
+                ),
+                "glBufferData(target=34962, size=3, data=blob(3), usage=35044)".to_string(),
+                "glUniform1f(location=-1, v0=0.5)".to_string(),
+                format!(
+                    "glGetUniformLocation(program={}, name=\"color\") = 0",
+                    buffers[0]
+                ),
+                "glGetString(name=7938) = \"\"".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_large_calls_are_split_into_chunks() {
+        let recorder = gl::Recorder::new(gl::Gl::load_with(null_gl::load), Vec::new());
+        let data = vec![7u8; 3 * 1024 * 1024];
+        unsafe {
+            recorder.BufferData(
+                gl::ARRAY_BUFFER,
+                data.len() as isize,
+                data.as_ptr() as *const _,
+                gl::STATIC_DRAW,
+            );
+            recorder.Flush();
+        }
+        let (_, calls) = decode(&recorder.finish().unwrap());
+        assert_eq!(
+            calls,
+            [
+                "glBufferData(target=34962, size=3145728, data=blob(3145728), usage=35044)",
+                "glFlush()",
+            ]
+        );
+    }
+
+    /// A trace of the calls made by `test_golden_trace`, encoded by hand following
+    /// `lib/trace/trace_format.hpp` and `lib/trace/trace_writer.cpp` of the apitrace sources
+    /// rather than by `Recorder`.
+    const GOLDEN: &[&[u8]] = &[
+        // snappy file signature
+        b"at",
+        // chunk: 219 compressed bytes, holding 215 bytes as a single 215-byte literal
+        &[0xdb, 0x00, 0x00, 0x00],
+        &[0xd7, 0x01, 60 << 2, 214],
+        // version 5
+        &[5],
+        // enter, thread 0, signature 46: glClear(mask)
+        &[0, 0, 46, 7],
+        b"glClear",
+        &[1, 4],
+        b"mask",
+        // mask = uint 0x4000, end
+        &[1, 0, 4, 0x80, 0x80, 0x01, 0],
+        // leave call 0, end
+        &[1, 0, 0],
+        // enter, thread 0, signature 40: glBufferData(target, size, data, usage)
+        &[0, 0, 40, 12],
+        b"glBufferData",
+        &[4, 6],
+        b"target",
+        &[4],
+        b"size",
+        &[4],
+        b"data",
+        &[5],
+        b"usage",
+        // target = uint 0x8892, size = uint 3, data = blob [1, 2, 3], usage = uint 0x88e4, end
+        &[1, 0, 4, 0x92, 0x91, 0x02],
+        &[1, 1, 4, 3],
+        &[1, 2, 8, 3, 1, 2, 3],
+        &[1, 3, 4, 0xe4, 0x91, 0x02, 0],
+        // leave call 1, end
+        &[1, 1, 0],
+        // enter, thread 0, signature 543: glUniform1f(location, v0)
+        &[0, 0, 0x9f, 0x04, 11],
+        b"glUniform1f",
+        &[2, 8],
+        b"location",
+        &[2],
+        b"v0",
+        // location = sint -1, v0 = float 0.5, end
+        &[1, 0, 3, 1],
+        &[1, 1, 5, 0x00, 0x00, 0x00, 0x3f, 0],
+        // leave call 2, end
+        &[1, 2, 0],
+        // enter, thread 0, signature 292: glGetUniformLocation(program, name)
+        &[0, 0, 0xa4, 0x02, 20],
+        b"glGetUniformLocation",
+        &[2, 7],
+        b"program",
+        &[4],
+        b"name",
+        // program = uint 1, name = string "color", end
+        &[1, 0, 4, 1],
+        &[1, 1, 7, 5],
+        b"color",
+        &[0],
+        // leave call 3, return = uint 0, end
+        &[1, 3, 2, 4, 0, 0],
+        // enter, thread 0, signature 164: glFlush(), end
+        &[0, 0, 0xa4, 0x01, 7],
+        b"glFlush",
+        &[0, 0],
+        // leave call 4, end
+        &[1, 4, 0],
+    ];
+
+    #[test]
+    fn test_golden_trace() {
+        let recorder = gl::Recorder::new(gl::Gl::load_with(null_gl::load), Vec::new());
+        unsafe {
+            recorder.Clear(gl::COLOR_BUFFER_BIT);
+            recorder.BufferData(
+                gl::ARRAY_BUFFER,
+                3,
+                [1u8, 2, 3].as_ptr() as *const _,
+                gl::STATIC_DRAW,
+            );
+            recorder.Uniform1f(-1, 0.5);
+            recorder.GetUniformLocation(1, b"color\0".as_ptr() as *const _);
+            recorder.Flush();
+        }
+        assert_eq!(recorder.finish().unwrap(), GOLDEN.concat());
+    }
+}
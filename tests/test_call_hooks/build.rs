@@ -0,0 +1,79 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate gl_generator;
+
+use gl_generator::*;
+use std::env;
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::*;
+
+fn main() {
+    let dest = env::var("OUT_DIR").unwrap();
+    let mut file = File::create(Path::new(&dest).join("test_call_hooks.rs")).unwrap();
+
+    let registry = Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, []);
+
+    writeln!(&mut file, "pub mod gl {{").unwrap();
+    registry
+        .write_bindings(DebugStructGenerator.with_log_hook(), &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "pub mod null_gl {{").unwrap();
+    registry.write_bindings(NullGenerator, &mut file).unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    // two names for the same value, where the core name has a vendor suffix
+    let mut registry = Registry::empty(Api::Gl);
+    for (ident, origin) in [
+        ("FROB_FAST_MESA", Origin::Version("1.0".to_string())),
+        ("FROB_FAST", Origin::Extension("GL_EXT_frob".to_string())),
+    ] {
+        registry.enums.insert(Enum {
+            ident: ident.to_string(),
+            value: "0x1".to_string(),
+            cast: false,
+            alias: None,
+            ty: "GLenum".into(),
+        });
+        registry
+            .enum_origins
+            .insert(ident.to_string(), Some(origin).into_iter().collect());
+    }
+    registry.groups.insert(
+        "FrobMode".to_string(),
+        Group {
+            ident: "FrobMode".to_string(),
+            enums_type: None,
+            enums: vec!["FROB_FAST".to_string(), "FROB_FAST_MESA".to_string()],
+        },
+    );
+    let mut mode = Binding::new("mode", "types::GLenum");
+    mode.group = Some("FrobMode".to_string());
+    registry.cmds.insert(Cmd {
+        proto: Binding::new("Frob", "()"),
+        params: vec![mode],
+        alias: None,
+        vecequiv: None,
+        glx: None,
+    });
+
+    writeln!(&mut file, "pub mod frob_gl {{").unwrap();
+    registry
+        .write_bindings(DebugStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+}
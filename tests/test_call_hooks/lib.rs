@@ -0,0 +1,174 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+extern crate log;
+
+include!(concat!(env!("OUT_DIR"), "/test_call_hooks.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{frob_gl, gl, null_gl};
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+
+    struct RecordingHook(Arc<Mutex<Vec<String>>>);
+
+    impl gl::CallHook for RecordingHook {
+        fn before_call(&self, name: &str, args: &[&dyn fmt::Debug]) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("before {name} {args:?}"));
+        }
+
+        fn after_call(&self, name: &str, result: &dyn fmt::Debug, error: Option<u32>) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("after {name} {result:?} {error:?}"));
+        }
+    }
+
+    impl frob_gl::CallHook for RecordingHook {
+        fn before_call(&self, name: &str, args: &[&dyn fmt::Debug]) {
+            gl::CallHook::before_call(self, name, args);
+        }
+    }
+
+    #[test]
+    fn test_custom_hook() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut gl = gl::Gl::load_with(null_gl::load);
+        gl.set_call_hook(RecordingHook(calls.clone()));
+
+        unsafe {
+            gl.Viewport(0, 0, 640, 480);
+            null_gl::GetError::set(|| gl::INVALID_VALUE);
+            let name = gl.CreateProgram();
+            null_gl::GetError::reset();
+            assert_eq!(gl.GetError(), gl::NO_ERROR);
+            gl.DeleteProgram(name);
+        }
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                "before Viewport [0, 0, 640, 480]".to_string(),
+                "after Viewport () None".to_string(),
+                "before CreateProgram []".to_string(),
+                "after CreateProgram 1 Some(1281)".to_string(),
+                "before GetError []".to_string(),
+                "after GetError 0 None".to_string(),
+                "before DeleteProgram [1]".to_string(),
+                "after DeleteProgram () None".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_names() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut gl = gl::Gl::load_with(null_gl::load);
+        gl.set_call_hook(RecordingHook(calls.clone()));
+
+        unsafe {
+            gl.BindBuffer(gl::ARRAY_BUFFER, 5);
+            gl.BindBuffer(0x1234, 5);
+            gl.Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+            gl.Clear(gl::STENCIL_BUFFER_BIT | 0x1);
+            gl.Clear(0);
+        }
+
+        let calls = calls.lock().unwrap();
+        let before = calls
+            .iter()
+            .filter(|call| call.starts_with("before"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            before,
+            [
+                "before BindBuffer [ARRAY_BUFFER, 5]",
+                "before BindBuffer [0x1234, 5]",
+                "before Clear [COLOR_BUFFER_BIT | DEPTH_BUFFER_BIT]",
+                "before Clear [STENCIL_BUFFER_BIT | 0x1]",
+                "before Clear [0]",
+            ]
+        );
+
+        assert_eq!(
+            gl::error_name(gl::INVALID_OPERATION),
+            Some("INVALID_OPERATION")
+        );
+        assert_eq!(
+            gl::error_name(gl::INVALID_FRAMEBUFFER_OPERATION),
+            Some("INVALID_FRAMEBUFFER_OPERATION")
+        );
+        assert_eq!(gl::error_name(gl::NO_ERROR), Some("NO_ERROR"));
+        assert_eq!(gl::error_name(0x1234), None);
+    }
+
+    #[test]
+    fn test_core_names_are_preferred() {
+        extern "system" fn frob(_: gl::types::GLenum) {}
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut gl = frob_gl::Gl::load_with(|_| frob as *const _);
+        gl.set_call_hook(RecordingHook(calls.clone()));
+        unsafe { gl.Frob(0x1) };
+
+        assert_eq!(calls.lock().unwrap()[0], "before Frob [FROB_FAST_MESA]");
+    }
+
+    #[test]
+    fn test_log_hook() {
+        use log::{Level, Log, Metadata, Record};
+
+        struct TestLogger(Mutex<Vec<String>>);
+
+        impl Log for TestLogger {
+            fn enabled(&self, _: &Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &Record) {
+                let message = format!("{} {}", record.level(), record.args());
+                self.0.lock().unwrap().push(message);
+            }
+
+            fn flush(&self) {}
+        }
+
+        static LOGGER: TestLogger = TestLogger(Mutex::new(Vec::new()));
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(Level::Trace.to_level_filter());
+
+        let mut gl = gl::Gl::load_with(null_gl::load);
+        gl.set_call_hook(gl::LogHook);
+        unsafe {
+            gl.ClearColor(0.0, 0.5, 1.0, 1.0);
+        }
+
+        assert_eq!(
+            *LOGGER.0.lock().unwrap(),
+            ["TRACE ClearColor(0.0, 0.5, 1.0, 1.0)"]
+        );
+    }
+}
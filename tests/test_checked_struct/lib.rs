@@ -0,0 +1,86 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_checked_struct.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{gl, null_gl, unchecked_gl};
+    use std::sync::{Mutex, MutexGuard};
+
+    /// Held by the tests that override the global `null_gl::GetError`.
+    static GET_ERROR: Mutex<()> = Mutex::new(());
+
+    fn lock_get_error() -> MutexGuard<'static, ()> {
+        GET_ERROR
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    #[test]
+    fn test_checked() {
+        let _guard = lock_get_error();
+        let gl = gl::Gl::load_with(null_gl::load);
+
+        unsafe {
+            assert_eq!(gl.Viewport(0, 0, 640, 480), Ok(()));
+            assert!(gl.CreateProgram().unwrap() > 0);
+
+            null_gl::GetError::set(|| gl::INVALID_VALUE);
+            assert_eq!(gl.CreateProgram(), Err(gl::GlError::InvalidValue));
+            assert_eq!(gl.GetError(), gl::INVALID_VALUE);
+            assert_eq!(gl.check_error(), Err(gl::GlError::InvalidValue));
+
+            null_gl::GetError::set(|| 0x1234);
+            assert_eq!(gl.Flush(), Err(gl::GlError::Other(0x1234)));
+            null_gl::GetError::reset();
+            assert_eq!(gl.Flush(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_unchecked() {
+        let _guard = lock_get_error();
+        let gl = unchecked_gl::Gl::load_with(null_gl::load);
+
+        unsafe {
+            null_gl::GetError::set(|| gl::INVALID_VALUE);
+            assert_eq!(gl.Viewport(0, 0, 640, 480), Ok(()));
+            assert!(gl.CreateProgram().unwrap() > 0);
+            assert_eq!(gl.check_error(), Err(unchecked_gl::GlError::InvalidValue));
+            null_gl::GetError::reset();
+        }
+    }
+
+    #[test]
+    fn test_error() {
+        let error = gl::GlError::from_raw(gl::INVALID_FRAMEBUFFER_OPERATION);
+        assert_eq!(error, gl::GlError::InvalidFramebufferOperation);
+        assert_eq!(error.to_raw(), gl::INVALID_FRAMEBUFFER_OPERATION);
+        assert_eq!(error.name(), Some("INVALID_FRAMEBUFFER_OPERATION"));
+        assert_eq!(error.to_string(), "INVALID_FRAMEBUFFER_OPERATION");
+
+        let error = gl::GlError::from_raw(0x1234);
+        assert_eq!(error.to_raw(), 0x1234);
+        assert_eq!(error.name(), None);
+        assert_eq!(error.to_string(), "0x1234");
+    }
+}
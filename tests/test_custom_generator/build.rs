@@ -0,0 +1,78 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate gl_generator;
+
+use gl_generator::*;
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::path::*;
+
+/// Counts the calls to each command, by replacing only the bodies of the methods of the struct.
+struct CountingGenerator;
+
+impl StructSections for CountingGenerator {
+    fn write_header<W>(&self, ctx: &GenContext, dest: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let ctx = ctx
+            .clone()
+            .with_import("std::sync::atomic::{AtomicUsize, Ordering}");
+        generators::sections::write_header(&ctx, dest)?;
+        writeln!(
+            dest,
+            "pub static CALLS: [__gl_imports::AtomicUsize; {len}] = \
+                [const {{ __gl_imports::AtomicUsize::new(0) }}; {len}];",
+            len = ctx.registry().cmds.len(),
+        )
+    }
+
+    fn gen_body(&self, ctx: &GenContext, cmd: &Cmd) -> String {
+        let index = ctx.registry().cmds.iter().position(|c| c == cmd).unwrap();
+        format!(
+            "CALLS[{index}].fetch_add(1, __gl_imports::Ordering::Relaxed); {body}",
+            body = StructSections::gen_body(&StructGenerator, ctx, cmd),
+        )
+    }
+}
+
+fn main() {
+    let dest = env::var("OUT_DIR").unwrap();
+    let mut file = File::create(Path::new(&dest).join("test_custom_generator.rs")).unwrap();
+
+    let registry = Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, []);
+
+    writeln!(&mut file, "pub mod gl {{").unwrap();
+    registry
+        .write_bindings(StructGeneratorWith(CountingGenerator), &mut file)
+        .unwrap();
+    writeln!(
+        &mut file,
+        "pub const COMMANDS: &[&str] = &{:?};",
+        registry
+            .cmds
+            .iter()
+            .map(|cmd| &cmd.proto.ident[..])
+            .collect::<Vec<_>>()
+    )
+    .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "pub mod null_gl {{").unwrap();
+    registry.write_bindings(NullGenerator, &mut file).unwrap();
+    writeln!(&mut file, "}}").unwrap();
+}
@@ -0,0 +1,48 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_custom_generator.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{gl, null_gl};
+    use std::sync::atomic::Ordering;
+
+    fn calls(command: &str) -> usize {
+        let index = gl::COMMANDS.iter().position(|&c| c == command).unwrap();
+        gl::CALLS[index].load(Ordering::Relaxed)
+    }
+
+    #[test]
+    fn test_counting() {
+        let gl = gl::Gl::load_with(null_gl::load);
+
+        unsafe {
+            gl.Clear(gl::COLOR_BUFFER_BIT);
+            gl.Clear(gl::DEPTH_BUFFER_BIT);
+            gl.Flush();
+        }
+
+        assert_eq!(calls("Clear"), 2);
+        assert_eq!(calls("Flush"), 1);
+        assert_eq!(calls("Finish"), 0);
+    }
+}
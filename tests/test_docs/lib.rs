@@ -0,0 +1,106 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_docs.rs"));
+
+#[cfg(test)]
+const CODE: &str = include_str!(concat!(env!("OUT_DIR"), "/test_docs.rs"));
+
+/// Returns the documentation written before `item`, without the `///`.
+#[cfg(test)]
+fn doc_of(item: &str) -> Vec<&str> {
+    let end = CODE.find(item).unwrap();
+    let mut lines = CODE[..end]
+        .lines()
+        .rev()
+        .skip(1)
+        .take_while(|line| line.starts_with("///"))
+        .map(|line| line.trim_start_matches("///").trim())
+        .collect::<Vec<_>>();
+    lines.reverse();
+    lines
+}
+
+#[test]
+fn test_cmd_doc() {
+    assert_eq!(
+        doc_of("pub unsafe fn BindBuffer("),
+        [
+            "`glBindBuffer`",
+            "",
+            "Provided by OpenGL 1.5.",
+            "",
+            "Parameter groups:",
+            "",
+            "- `target`: `BufferTargetARB`",
+            "",
+            "Aliases: `glBindBufferARB`.",
+            "",
+            "Reference page: <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glBindBuffer.xhtml>",
+        ]
+    );
+}
+
+#[test]
+fn test_extension_cmd_doc() {
+    let doc = doc_of("pub unsafe fn BindBufferARB(");
+    assert_eq!(doc[0], "`glBindBufferARB`");
+    assert_eq!(doc[2], "Provided by `GL_ARB_vertex_buffer_object`.");
+    assert!(doc.contains(&"Aliases: `glBindBuffer`."));
+    assert!(!doc.iter().any(|line| line.starts_with("Reference page")));
+}
+
+#[test]
+fn test_ref_pages() {
+    assert!(doc_of("pub unsafe fn Uniform4fv(").contains(
+        &"Reference page: <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glUniform.xhtml>"
+    ));
+    assert!(doc_of("pub unsafe fn GetIntegerv(").contains(
+        &"Reference page: <https://registry.khronos.org/OpenGL-Refpages/gl4/html/glGet.xhtml>"
+    ));
+    // compatibility profile commands have no page
+    assert!(!doc_of("pub unsafe fn Begin(")
+        .iter()
+        .any(|line| line.starts_with("Reference page")));
+}
+
+#[test]
+fn test_return_group_doc() {
+    assert!(
+        doc_of("pub unsafe fn GetError(").contains(&"Returns a value of the `ErrorCode` group.")
+    );
+}
+
+#[test]
+fn test_enum_doc() {
+    let doc = doc_of("pub const COLOR_BUFFER_BIT:");
+    assert_eq!(
+        doc[..3],
+        ["`GL_COLOR_BUFFER_BIT`", "", "Provided by OpenGL 1.0."]
+    );
+    assert!(doc[4].contains("`ClearBufferMask`"));
+
+    let doc = doc_of("pub const ARRAY_BUFFER_ARB:");
+    assert_eq!(doc[2], "Provided by `GL_ARB_vertex_buffer_object`.");
+
+    let doc = doc_of("pub const SMOOTH_POINT_SIZE_RANGE:");
+    assert_eq!(doc[doc.len() - 1], "Alias of `GL_POINT_SIZE_RANGE`.");
+}
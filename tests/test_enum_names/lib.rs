@@ -0,0 +1,64 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_enum_names.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::gl;
+
+    #[test]
+    fn test_enum_name() {
+        assert_eq!(gl::enum_name(gl::ARRAY_BUFFER), Some("ARRAY_BUFFER"));
+        assert_eq!(
+            gl::enum_name(gl::INVALID_OPERATION),
+            Some("INVALID_OPERATION")
+        );
+        assert_eq!(gl::enum_name(0xdead_beef), None);
+    }
+
+    #[test]
+    fn test_aliases() {
+        // the extension adds ARRAY_BUFFER_ARB, with the same value
+        assert_eq!(gl::ARRAY_BUFFER_ARB, gl::ARRAY_BUFFER);
+        assert_eq!(gl::enum_name(gl::ARRAY_BUFFER_ARB), Some("ARRAY_BUFFER"));
+        // names added by a core version come first, then in alphabetical order
+        assert_eq!(gl::enum_name(gl::ONE), Some("CONTEXT_CORE_PROFILE_BIT"));
+    }
+
+    #[test]
+    fn test_group_enum_name() {
+        assert_eq!(
+            gl::group_enum_name("BufferTargetARB", gl::ARRAY_BUFFER),
+            Some("ARRAY_BUFFER")
+        );
+        assert_eq!(gl::group_enum_name("PrimitiveType", gl::ONE), Some("LINES"));
+        assert_eq!(
+            gl::group_enum_name("ClearBufferMask", gl::COLOR_BUFFER_BIT),
+            Some("COLOR_BUFFER_BIT")
+        );
+        assert_eq!(
+            gl::group_enum_name("BufferTargetARB", gl::COLOR_BUFFER_BIT),
+            None
+        );
+        assert_eq!(gl::group_enum_name("NotAGroup", gl::ARRAY_BUFFER), None);
+    }
+}
@@ -13,7 +13,6 @@
 // limitations under the License.
 
 #![allow(
-    clippy::missing_transmute_annotations,
     clippy::too_many_arguments,
     clippy::unused_unit,
     clippy::upper_case_acronyms
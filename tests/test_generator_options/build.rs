@@ -0,0 +1,62 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate gl_generator;
+
+use gl_generator::*;
+use std::env;
+use std::fs::File;
+use std::path::*;
+
+fn main() {
+    let dest = env::var("OUT_DIR").unwrap();
+    let dest = Path::new(&dest);
+    let registry = Registry::new(Api::Gles2, (2, 0), Profile::Core, Fallbacks::All, []);
+
+    let options = GeneratorOptions::new()
+        .visibility(Visibility::Crate)
+        .inline(InlinePolicy::Never)
+        .fn_attr("cold")
+        .const_attr("doc(hidden)")
+        .doc_alias(true);
+
+    let mut file = File::create(dest.join("global.rs")).unwrap();
+    registry
+        .write_bindings_with_options(GlobalGenerator, &options, &mut file)
+        .unwrap();
+
+    let mut file = File::create(dest.join("static.rs")).unwrap();
+    registry
+        .write_bindings_with_options(StaticGenerator, &options, &mut file)
+        .unwrap();
+
+    let mut file = File::create(dest.join("static_struct.rs")).unwrap();
+    registry
+        .write_bindings_with_options(StaticStructGenerator, &options, &mut file)
+        .unwrap();
+
+    let mut file = File::create(dest.join("debug_struct.rs")).unwrap();
+    registry
+        .write_bindings_with_options(DebugStructGenerator, &options, &mut file)
+        .unwrap();
+
+    let options = GeneratorOptions::new()
+        .inline(InlinePolicy::Always)
+        .lint_allows(false);
+
+    let mut file = File::create(dest.join("struct.rs")).unwrap();
+    registry
+        .write_bindings_with_options(StructGenerator, &options, &mut file)
+        .unwrap();
+}
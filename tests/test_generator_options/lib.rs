@@ -0,0 +1,88 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod gl_global {
+    include!(concat!(env!("OUT_DIR"), "/global.rs"));
+}
+
+mod gl_static {
+    include!(concat!(env!("OUT_DIR"), "/static.rs"));
+}
+
+mod gl_static_struct {
+    include!(concat!(env!("OUT_DIR"), "/static_struct.rs"));
+}
+
+mod gl_debug_struct {
+    include!(concat!(env!("OUT_DIR"), "/debug_struct.rs"));
+}
+
+#[allow(
+    non_camel_case_types,
+    non_snake_case,
+    non_upper_case_globals,
+    dead_code,
+    unused_variables,
+    missing_copy_implementations,
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::unused_unit,
+    clippy::upper_case_acronyms
+)]
+mod gl_struct {
+    include!(concat!(env!("OUT_DIR"), "/struct.rs"));
+}
+
+#[test]
+fn test_attributes() {
+    for code in &[
+        include_str!(concat!(env!("OUT_DIR"), "/global.rs")),
+        include_str!(concat!(env!("OUT_DIR"), "/static_struct.rs")),
+        include_str!(concat!(env!("OUT_DIR"), "/debug_struct.rs")),
+    ] {
+        assert!(code.contains("#[doc(alias = \"glClear\")]#[cold] #[inline(never)]"));
+        assert!(code.contains("pub(crate) unsafe fn Clear("));
+        assert!(code.contains("#[doc(hidden)] pub(crate) const COLOR_BUFFER_BIT:"));
+        assert!(!code.contains("#[inline] pub"));
+    }
+
+    let code = include_str!(concat!(env!("OUT_DIR"), "/static.rs"));
+    assert!(code.contains("#[doc(alias = \"glClear\")]#[cold] #[link_name=\"glClear\"]"));
+    assert!(code.contains("pub(crate) fn Clear("));
+}
+
+#[test]
+fn test_lint_allows() {
+    let code = include_str!(concat!(env!("OUT_DIR"), "/struct.rs"));
+    assert!(code.contains("#[inline(always)] pub unsafe fn Clear("));
+    assert!(code.contains(" pub const COLOR_BUFFER_BIT:"));
+    assert!(!code.contains("#[allow(dead_code, non_upper_case_globals)]"));
+    assert!(!code.contains("clippy::missing_safety_doc"));
+}
+
+#[test]
+fn test_bindings() {
+    let gl = gl_struct::Gles2::load_with(|_| std::ptr::null());
+    assert!(!gl.Clear.is_loaded());
+
+    let gl = gl_debug_struct::Gles2::load_with(|_| std::ptr::null());
+    assert!(!gl.Clear.is_loaded());
+
+    gl_global::load_with(|_| std::ptr::null());
+    assert!(!gl_global::Clear::is_loaded());
+
+    let _ = gl_static_struct::Gles2::load_with(|_| std::ptr::null());
+    assert_eq!(gl_global::COLOR_BUFFER_BIT, 0x4000);
+}
@@ -0,0 +1,132 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::unused_unit, clippy::manual_non_exhaustive)]
+
+include!(concat!(env!("OUT_DIR"), "/test_glx_protocol.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::glx_protocol as glx;
+
+    /// Encodes a render command, with the words of its arguments.
+    fn command(len: u16, opcode: u16, words: &[u32]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&len.to_ne_bytes());
+        bytes.extend_from_slice(&opcode.to_ne_bytes());
+        for word in words {
+            bytes.extend_from_slice(&word.to_ne_bytes());
+        }
+        bytes
+    }
+
+    /// Encodes a request to the GLX extension with the major opcode 150.
+    fn request(minor_opcode: u8, len: u16, words: &[u32]) -> Vec<u8> {
+        let mut bytes = vec![150, minor_opcode];
+        bytes.extend_from_slice(&len.to_ne_bytes());
+        for word in words {
+            bytes.extend_from_slice(&word.to_ne_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_render() {
+        let mut commands = glx::RenderCommands::new();
+        assert!(commands.is_empty());
+        commands
+            .Color3fv(&[1.0, 0.5, 0.25])
+            .BindFramebuffer(glx::FRAMEBUFFER, 7);
+
+        let mut expected = command(16, 8, &[]);
+        for value in [1.0f32, 0.5, 0.25].iter() {
+            expected.extend_from_slice(&value.to_ne_bytes());
+        }
+        expected.extend(command(12, 236, &[glx::FRAMEBUFFER, 7]));
+        assert_eq!(commands.as_bytes(), &expected[..]);
+
+        let mut request = request(glx::X_GLX_RENDER, 2 + 7, &[42]);
+        request.extend(expected);
+        assert_eq!(commands.request(150, 42), request);
+
+        commands.clear();
+        assert!(commands.is_empty());
+    }
+
+    #[test]
+    fn test_scalar_form() {
+        let mut vector = glx::RenderCommands::new();
+        vector.Color3fv(&[1.0, 0.5, 0.25]);
+        let mut scalar = glx::RenderCommands::new();
+        scalar.Color3f(1.0, 0.5, 0.25);
+        assert_eq!(scalar, vector);
+    }
+
+    #[test]
+    fn test_padding() {
+        let mut commands = glx::RenderCommands::new();
+        commands.ColorMask(1, 0, 1, 0).EdgeFlag(1);
+
+        let mut expected = command(8, 134, &[]);
+        expected.extend_from_slice(&[1, 0, 1, 0]);
+        expected.extend(command(8, 22, &[]));
+        expected.extend_from_slice(&[1, 0, 0, 0]);
+        assert_eq!(commands.as_bytes(), &expected[..]);
+    }
+
+    #[test]
+    fn test_doubles_first() {
+        let mut commands = glx::RenderCommands::new();
+        commands.TexGend(glx::S, glx::TEXTURE_GEN_MODE, 2.0);
+
+        let mut expected = command(20, 115, &[]);
+        expected.extend_from_slice(&2.0f64.to_ne_bytes());
+        expected.extend_from_slice(&glx::S.to_ne_bytes());
+        expected.extend_from_slice(&glx::TEXTURE_GEN_MODE.to_ne_bytes());
+        assert_eq!(commands.as_bytes(), &expected[..]);
+    }
+
+    #[test]
+    fn test_single() {
+        assert_eq!(glx::GetError(150, 42), request(115, 2, &[42]));
+        assert_eq!(
+            glx::DeleteTextures(150, 42, &[3, 4]),
+            request(144, 5, &[42, 2, 3, 4])
+        );
+        // the names are returned in the reply
+        assert_eq!(glx::GenTextures(150, 42, 2), request(145, 3, &[42, 2]));
+    }
+
+    #[test]
+    fn test_pixel_store() {
+        let mut expected = request(111, 9, &[42, 1, 2, 3, 4, glx::RGBA, glx::UNSIGNED_BYTE]);
+        expected.extend_from_slice(&[1, 0, 0, 0]);
+        assert_eq!(
+            glx::ReadPixels(150, 42, 1, 2, 3, 4, glx::RGBA, glx::UNSIGNED_BYTE, 1, 0),
+            expected
+        );
+
+        let mut expected = request(128, 3, &[42]);
+        expected.extend_from_slice(&[1, 0, 0, 0]);
+        assert_eq!(glx::GetPolygonStipple(150, 42, 1), expected);
+    }
+
+    #[test]
+    fn test_vendor() {
+        assert_eq!(
+            glx::IsRenderbuffer(150, 42, 5),
+            request(glx::X_GLX_VENDOR_PRIVATE_WITH_REPLY, 4, &[1422, 42, 5])
+        );
+    }
+}
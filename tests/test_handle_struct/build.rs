@@ -0,0 +1,62 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate gl_generator;
+
+use gl_generator::*;
+use std::env;
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::*;
+
+/// Sets the classes that newer versions of `gl.xml` give to the object names of a few commands.
+fn with_classes(cmd: &Cmd) -> Cmd {
+    let mut cmd = cmd.clone();
+    let (class, param) = match &cmd.proto.ident[..] {
+        "BindBuffer" => ("buffer", "buffer"),
+        "GenBuffers" | "DeleteBuffers" => ("buffer", "buffers"),
+        "BindTexture" => ("texture", "texture"),
+        "GenTextures" | "DeleteTextures" => ("texture", "textures"),
+        "UseProgram" | "DeleteProgram" => ("program", "program"),
+        "CreateProgram" => {
+            cmd.proto.class = Some("program".to_string());
+            return cmd;
+        },
+        _ => return cmd,
+    };
+    for binding in &mut cmd.params {
+        if binding.ident == param {
+            binding.class = Some(class.to_string());
+        }
+    }
+    cmd
+}
+
+fn main() {
+    let dest = env::var("OUT_DIR").unwrap();
+    let mut file = File::create(Path::new(&dest).join("test_handle_struct.rs")).unwrap();
+
+    let mut registry = Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, []);
+    registry.cmds = registry.cmds.iter().map(with_classes).collect();
+
+    writeln!(&mut file, "pub mod gl {{").unwrap();
+    registry
+        .write_bindings(HandleStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "pub mod null_gl {{").unwrap();
+    registry.write_bindings(NullGenerator, &mut file).unwrap();
+    writeln!(&mut file, "}}").unwrap();
+}
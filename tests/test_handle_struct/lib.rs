@@ -0,0 +1,73 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+//! Object names of different classes can't be mixed up:
+//!
+//! ```compile_fail
+//! # extern crate test_handle_struct;
+//! # use test_handle_struct::gl;
+//! # fn main() {
+//! let gl = gl::Gl::load_with(test_handle_struct::null_gl::load);
+//! let mut textures = [gl::types::Texture(0)];
+//! unsafe {
+//!     gl.GenTextures(1, textures.as_mut_ptr());
+//!     gl.BindBuffer(gl::ARRAY_BUFFER, textures[0]);
+//! }
+//! # }
+//! ```
+
+include!(concat!(env!("OUT_DIR"), "/test_handle_struct.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{gl, null_gl};
+    use std::mem;
+
+    #[test]
+    fn test_handles() {
+        let gl = gl::Gl::load_with(null_gl::load);
+
+        let mut buffers = [gl::types::Buffer(0); 2];
+        let mut textures = [gl::types::Texture(0)];
+        unsafe {
+            gl.GenBuffers(2, buffers.as_mut_ptr());
+            gl.GenTextures(1, textures.as_mut_ptr());
+            gl.BindBuffer(gl::ARRAY_BUFFER, buffers[0]);
+            gl.BindTexture(gl::TEXTURE_2D, textures[0]);
+            gl.DeleteBuffers(2, buffers.as_ptr());
+            gl.DeleteTextures(1, textures.as_ptr());
+
+            let program: gl::types::Program = gl.CreateProgram();
+            gl.UseProgram(program);
+            gl.DeleteProgram(program);
+        }
+        assert!(buffers[0].0 > 0 && buffers[1].0 > 0);
+        assert!(textures[0].0 > 0);
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(
+            mem::size_of::<gl::types::Buffer>(),
+            mem::size_of::<gl::types::GLuint>()
+        );
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod gl {
+    #![allow(
+        clippy::missing_safety_doc,
+        clippy::too_many_arguments,
+        clippy::unused_unit
+    )]
+
+    include!(concat!(env!("OUT_DIR"), "/test_lazy.rs"));
+}
+
+// Everything runs in a single test because the bindings share global state.
+#[test]
+fn test_symbols_are_resolved_on_first_call() {
+    use std::os::raw;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    static LOOKUPS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+    static CLEAR_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    extern "system" fn fake_clear(_mask: gl::types::GLbitfield) {
+        CLEAR_CALLS.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn counting_loader(name: &'static str) -> *const raw::c_void {
+        LOOKUPS.lock().unwrap().push(name);
+        match name {
+            "glClear" => fake_clear as *const raw::c_void,
+            _ => std::ptr::null(),
+        }
+    }
+
+    gl::load_with(counting_loader);
+    assert!(LOOKUPS.lock().unwrap().is_empty());
+
+    unsafe {
+        gl::Clear(gl::COLOR_BUFFER_BIT);
+        gl::Clear(gl::COLOR_BUFFER_BIT);
+    }
+    assert_eq!(CLEAR_CALLS.load(Ordering::SeqCst), 2);
+    assert_eq!(*LOOKUPS.lock().unwrap(), ["glClear"]);
+
+    assert!(!gl::Viewport::is_loaded());
+    assert!(!gl::Viewport::is_loaded());
+    assert_eq!(*LOOKUPS.lock().unwrap(), ["glClear", "glViewport"]);
+
+    let result = std::panic::catch_unwind(|| unsafe { gl::Viewport(0, 0, 0, 0) });
+    assert!(result.is_err());
+    assert_eq!(LOOKUPS.lock().unwrap().len(), 2);
+
+    // Setting a new loader forgets the resolved pointers.
+    gl::load_with(counting_loader);
+    assert!(gl::Clear::is_loaded());
+    assert_eq!(
+        *LOOKUPS.lock().unwrap(),
+        ["glClear", "glViewport", "glClear"]
+    );
+
+    // A loader that borrows its context can load a single function eagerly.
+    let context = ["glViewport"];
+    let mut lookups = Vec::new();
+    gl::Viewport::load_with(|name| {
+        lookups.push(name);
+        assert!(context.contains(&name));
+        std::ptr::null()
+    });
+    assert_eq!(lookups, ["glViewport"]);
+    assert!(!gl::Viewport::is_loaded());
+
+    // Every function is looked up to build the report.
+    let report = gl::load_with_report(counting_loader);
+    assert_eq!(report.loaded_count(), 1);
+    assert_eq!(report.missing_count(), report.commands().len() - 1);
+    let lookups = LOOKUPS.lock().unwrap().len();
+    assert!(gl::Clear::is_loaded());
+    assert_eq!(LOOKUPS.lock().unwrap().len(), lookups);
+    unsafe { gl::Clear(gl::COLOR_BUFFER_BIT) };
+    assert_eq!(CLEAR_CALLS.load(Ordering::SeqCst), 3);
+}
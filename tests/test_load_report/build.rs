@@ -0,0 +1,37 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate gl_generator;
+
+use gl_generator::*;
+use std::env;
+use std::fs::File;
+use std::path::*;
+
+fn main() {
+    let dest = env::var("OUT_DIR").unwrap();
+    let dest = Path::new(&dest);
+    let registry = Registry::new(Api::Gl, (3, 3), Profile::Core, Fallbacks::All, []);
+
+    let mut file = File::create(dest.join("test_load_report.rs")).unwrap();
+    registry.write_bindings(StructGenerator, &mut file).unwrap();
+
+    let mut file = File::create(dest.join("global.rs")).unwrap();
+    registry.write_bindings(GlobalGenerator, &mut file).unwrap();
+
+    let mut file = File::create(dest.join("debug_struct.rs")).unwrap();
+    registry
+        .write_bindings(DebugStructGenerator, &mut file)
+        .unwrap();
+}
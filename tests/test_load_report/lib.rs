@@ -0,0 +1,146 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod gl {
+    #![allow(
+        clippy::missing_safety_doc,
+        clippy::too_many_arguments,
+        clippy::unused_unit,
+        clippy::manual_non_exhaustive
+    )]
+
+    include!(concat!(env!("OUT_DIR"), "/test_load_report.rs"));
+}
+
+pub mod gl_global {
+    #![allow(
+        clippy::missing_safety_doc,
+        clippy::too_many_arguments,
+        clippy::unused_unit,
+        clippy::manual_non_exhaustive
+    )]
+
+    include!(concat!(env!("OUT_DIR"), "/global.rs"));
+}
+
+pub mod gl_debug_struct {
+    #![allow(
+        clippy::missing_safety_doc,
+        clippy::too_many_arguments,
+        clippy::unused_unit,
+        clippy::manual_non_exhaustive
+    )]
+
+    include!(concat!(env!("OUT_DIR"), "/debug_struct.rs"));
+}
+
+#[cfg(test)]
+fn loader(name: &str) -> *const std::os::raw::c_void {
+    match name {
+        "glClear" | "glGenFramebuffersEXT" => 42 as *const std::os::raw::c_void,
+        _ => std::ptr::null(),
+    }
+}
+
+#[test]
+fn test_report_lists_resolved_symbols() {
+    let (gl, report) = gl::Gl::load_with_report(loader);
+    assert!(gl.Clear.is_loaded());
+    assert!(gl.GenFramebuffers.is_loaded());
+    assert!(!gl.Viewport.is_loaded());
+
+    let clear = report
+        .commands()
+        .iter()
+        .find(|c| c.symbol == "glClear")
+        .unwrap();
+    assert_eq!(clear.resolved, Some("glClear"));
+    assert!(!clear.used_fallback());
+
+    let gen_framebuffers = report
+        .commands()
+        .iter()
+        .find(|c| c.symbol == "glGenFramebuffers")
+        .unwrap();
+    assert_eq!(gen_framebuffers.resolved, Some("glGenFramebuffersEXT"));
+    assert!(gen_framebuffers.used_fallback());
+
+    assert!(report.missing().any(|c| c.symbol == "glViewport"));
+    assert_eq!(report.loaded_count(), 2);
+    assert_eq!(report.fallback_count(), 1);
+    assert_eq!(
+        report.missing_count(),
+        report.commands().len() - report.loaded_count()
+    );
+}
+
+#[test]
+fn test_report_display() {
+    let (_, report) = gl::Gl::load_with_report(loader);
+    let text = report.to_string();
+    assert!(text.starts_with(&format!(
+        "loaded 2 of {} gl functions (1 through fallbacks)",
+        report.commands().len()
+    )));
+    assert!(text.contains("\nfallback: glGenFramebuffers -> glGenFramebuffersEXT"));
+    assert!(text.contains("\nmissing: glViewport"));
+    assert!(!text.lines().any(|line| line.ends_with(" glClear")));
+}
+
+#[test]
+fn test_global_report_lists_resolved_symbols() {
+    let report = gl_global::load_with_report(loader);
+    assert!(gl_global::Clear::is_loaded());
+    assert!(gl_global::GenFramebuffers::is_loaded());
+    assert!(!gl_global::Viewport::is_loaded());
+
+    let gen_framebuffers = report
+        .commands()
+        .iter()
+        .find(|c| c.symbol == "glGenFramebuffers")
+        .unwrap();
+    assert_eq!(gen_framebuffers.resolved, Some("glGenFramebuffersEXT"));
+    assert!(gen_framebuffers.used_fallback());
+
+    assert!(report.missing().any(|c| c.symbol == "glViewport"));
+    assert_eq!(report.loaded_count(), 2);
+    assert_eq!(report.fallback_count(), 1);
+    assert_eq!(
+        report.missing_count(),
+        report.commands().len() - report.loaded_count()
+    );
+}
+
+#[test]
+fn test_debug_struct_report_lists_resolved_symbols() {
+    let (gl, report) = gl_debug_struct::Gl::load_with_report(loader);
+    assert!(gl.Clear.is_loaded());
+    assert!(gl.GenFramebuffers.is_loaded());
+    assert!(!gl.Viewport.is_loaded());
+
+    let clear = report
+        .commands()
+        .iter()
+        .find(|c| c.symbol == "glClear")
+        .unwrap();
+    assert_eq!(clear.resolved, Some("glClear"));
+    assert!(!clear.used_fallback());
+
+    assert!(report.missing().any(|c| c.symbol == "glViewport"));
+    assert_eq!(report.loaded_count(), 2);
+    assert_eq!(report.fallback_count(), 1);
+    assert!(report
+        .to_string()
+        .contains("\nfallback: glGenFramebuffers -> glGenFramebuffersEXT"));
+}
@@ -0,0 +1,84 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate gl_generator;
+
+use gl_generator::*;
+use std::env;
+use std::fs::File;
+use std::path::*;
+
+fn rename(kind: NameKind, name: &str) -> String {
+    match (kind, name) {
+        (NameKind::Command, "clear") => "clear_buffers".to_string(),
+        (NameKind::Enum, "COLOR_BUFFER_BIT") => "COLOR_BIT".to_string(),
+        _ => name.to_string(),
+    }
+}
+
+fn main() {
+    let dest = env::var("OUT_DIR").unwrap();
+    let dest = Path::new(&dest);
+    let registry = Registry::new(
+        Api::Gl,
+        (2, 0),
+        Profile::Core,
+        Fallbacks::All,
+        [
+            "GL_ARB_texture_compression",
+            "GL_ARB_vertex_buffer_object",
+            "GL_EXT_copy_texture",
+        ],
+    );
+
+    let options = GeneratorOptions::new().naming(Naming::SnakeCase);
+
+    let mut file = File::create(dest.join("struct.rs")).unwrap();
+    registry
+        .write_bindings_with_options(StructGenerator, &options, &mut file)
+        .unwrap();
+
+    let options = GeneratorOptions::new()
+        .naming(Naming::SnakeCase)
+        .strip_cmd_prefix(false)
+        .strip_enum_prefix(false);
+
+    let mut file = File::create(dest.join("global.rs")).unwrap();
+    registry
+        .write_bindings_with_options(GlobalGenerator, &options, &mut file)
+        .unwrap();
+
+    let options = GeneratorOptions::new()
+        .naming(Naming::SnakeCase)
+        .rename(rename);
+
+    let mut file = File::create(dest.join("debug_struct.rs")).unwrap();
+    registry
+        .write_bindings_with_options(DebugStructGenerator, &options, &mut file)
+        .unwrap();
+
+    let gles_registry = Registry::new(
+        Api::Gles2,
+        (2, 0),
+        Profile::Core,
+        Fallbacks::All,
+        ["GL_OES_texture_3D"],
+    );
+    let options = GeneratorOptions::new().naming(Naming::SnakeCase);
+
+    let mut file = File::create(dest.join("gles_struct.rs")).unwrap();
+    gles_registry
+        .write_bindings_with_options(StructGenerator, &options, &mut file)
+        .unwrap();
+}
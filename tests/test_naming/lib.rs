@@ -0,0 +1,76 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(unused_imports)]
+
+mod gl_struct {
+    include!(concat!(env!("OUT_DIR"), "/struct.rs"));
+}
+
+mod gl_global {
+    include!(concat!(env!("OUT_DIR"), "/global.rs"));
+}
+
+mod gl_debug_struct {
+    include!(concat!(env!("OUT_DIR"), "/debug_struct.rs"));
+}
+
+use gl_struct::types::*;
+
+#[test]
+fn test_snake_case() {
+    let _: unsafe fn(&gl_struct::Gl, GLbitfield) = gl_struct::Gl::clear;
+    let _: unsafe fn(&gl_struct::Gl, GLenum, GLuint) = gl_struct::Gl::bind_buffer;
+    let _: unsafe fn(&gl_struct::Gl, GLenum, GLuint) = gl_struct::Gl::bind_buffer_arb;
+    let _: unsafe fn(&gl_struct::Gl, GLint, GLsizei, *const GLfloat) = gl_struct::Gl::uniform4fv;
+    let _: unsafe fn(&gl_struct::Gl, GLuint, GLubyte, GLubyte, GLubyte, GLubyte) =
+        gl_struct::Gl::vertex_attrib_4nub;
+
+    let code = include_str!(concat!(env!("OUT_DIR"), "/struct.rs"));
+    assert!(code.contains("pub unsafe fn tex_image_2d("));
+    assert!(code.contains("pub unsafe fn get_vertex_attrib_pointerv("));
+    assert!(code.contains("pub unsafe fn copy_tex_image_2d_ext("));
+    assert!(code.contains("pub unsafe fn compressed_tex_image_1d_arb("));
+
+    let code = include_str!(concat!(env!("OUT_DIR"), "/gles_struct.rs"));
+    assert!(code.contains("pub unsafe fn tex_image_3d_oes("));
+    assert_eq!(gl_struct::COLOR_BUFFER_BIT, 0x4000);
+}
+
+#[test]
+fn test_prefixes() {
+    let _: unsafe fn(GLbitfield) = gl_global::gl_clear;
+    let _: unsafe fn(GLenum, GLuint) = gl_global::gl_bind_buffer_arb;
+    assert_eq!(gl_global::GL_COLOR_BUFFER_BIT, 0x4000);
+
+    gl_global::load_with(|_| std::ptr::null());
+    assert!(!gl_global::gl_clear::is_loaded());
+}
+
+#[test]
+fn test_rename() {
+    let _: unsafe fn(&gl_debug_struct::Gl, GLbitfield) = gl_debug_struct::Gl::clear_buffers;
+    let _: unsafe fn(&gl_debug_struct::Gl, GLenum, GLuint) = gl_debug_struct::Gl::bind_buffer;
+    assert_eq!(gl_debug_struct::COLOR_BIT, 0x4000);
+    assert_eq!(gl_debug_struct::DEPTH_BUFFER_BIT, 0x100);
+}
+
+#[test]
+fn test_bindings() {
+    let gl = gl_struct::Gl::load_with(|_| std::ptr::null());
+    assert!(!gl.BindBuffer.is_loaded());
+
+    let gl = gl_debug_struct::Gl::load_with(|_| std::ptr::null());
+    assert!(!gl.Clear.is_loaded());
+}
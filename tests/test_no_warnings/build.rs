@@ -34,6 +34,12 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod gl_lazy {{").unwrap();
+    gl_registry
+        .write_bindings(LazyGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     writeln!(&mut file, "mod gl_static {{").unwrap();
     gl_registry
         .write_bindings(StaticGenerator, &mut file)
@@ -58,6 +64,93 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod gl_thread_local {{").unwrap();
+    gl_registry
+        .write_bindings(ThreadLocalGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_trait {{").unwrap();
+    gl_registry
+        .write_bindings(TraitGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_null {{").unwrap();
+    gl_registry
+        .write_bindings(NullGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_trace {{").unwrap();
+    gl_registry
+        .write_bindings(TraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_tracing {{").unwrap();
+    gl_registry
+        .write_bindings(TracingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_apitrace {{").unwrap();
+    gl_registry
+        .write_bindings(ApitraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_enum_names {{").unwrap();
+    gl_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_checked_struct {{").unwrap();
+    gl_registry
+        .write_bindings(CheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_unchecked_struct {{").unwrap();
+    gl_registry
+        .write_bindings(UncheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_validation {{").unwrap();
+    gl_registry
+        .write_bindings(ValidationGenerator, &mut file)
+        .unwrap();
+    gl_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_slice_struct {{").unwrap();
+    gl_registry
+        .write_bindings(SliceStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_handle_struct {{").unwrap();
+    gl_registry
+        .write_bindings(HandleStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_object_tracking {{").unwrap();
+    gl_registry
+        .write_bindings(ObjectTrackingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gl_glx_protocol {{").unwrap();
+    gl_registry
+        .write_bindings(GlxProtocolGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     // Glx
 
     let glx_registry = Registry::new(Api::Glx, (1, 4), Profile::Core, Fallbacks::All, []);
@@ -68,6 +161,12 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod glx_lazy {{").unwrap();
+    glx_registry
+        .write_bindings(LazyGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     writeln!(&mut file, "mod glx_static {{").unwrap();
     glx_registry
         .write_bindings(StaticGenerator, &mut file)
@@ -92,6 +191,93 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod glx_thread_local {{").unwrap();
+    glx_registry
+        .write_bindings(ThreadLocalGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_trait {{").unwrap();
+    glx_registry
+        .write_bindings(TraitGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_null {{").unwrap();
+    glx_registry
+        .write_bindings(NullGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_trace {{").unwrap();
+    glx_registry
+        .write_bindings(TraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_tracing {{").unwrap();
+    glx_registry
+        .write_bindings(TracingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_apitrace {{").unwrap();
+    glx_registry
+        .write_bindings(ApitraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_enum_names {{").unwrap();
+    glx_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_checked_struct {{").unwrap();
+    glx_registry
+        .write_bindings(CheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_unchecked_struct {{").unwrap();
+    glx_registry
+        .write_bindings(UncheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_validation {{").unwrap();
+    glx_registry
+        .write_bindings(ValidationGenerator, &mut file)
+        .unwrap();
+    glx_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_slice_struct {{").unwrap();
+    glx_registry
+        .write_bindings(SliceStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_handle_struct {{").unwrap();
+    glx_registry
+        .write_bindings(HandleStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_object_tracking {{").unwrap();
+    glx_registry
+        .write_bindings(ObjectTrackingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod glx_glx_protocol {{").unwrap();
+    glx_registry
+        .write_bindings(GlxProtocolGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     // Wgl
 
     let wgl_registry = Registry::new(Api::Wgl, (1, 0), Profile::Core, Fallbacks::All, []);
@@ -102,6 +288,12 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod wgl_lazy {{").unwrap();
+    wgl_registry
+        .write_bindings(LazyGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     writeln!(&mut file, "mod wgl_static {{").unwrap();
     wgl_registry
         .write_bindings(StaticGenerator, &mut file)
@@ -126,6 +318,93 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod wgl_thread_local {{").unwrap();
+    wgl_registry
+        .write_bindings(ThreadLocalGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_trait {{").unwrap();
+    wgl_registry
+        .write_bindings(TraitGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_null {{").unwrap();
+    wgl_registry
+        .write_bindings(NullGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_trace {{").unwrap();
+    wgl_registry
+        .write_bindings(TraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_tracing {{").unwrap();
+    wgl_registry
+        .write_bindings(TracingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_apitrace {{").unwrap();
+    wgl_registry
+        .write_bindings(ApitraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_enum_names {{").unwrap();
+    wgl_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_checked_struct {{").unwrap();
+    wgl_registry
+        .write_bindings(CheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_unchecked_struct {{").unwrap();
+    wgl_registry
+        .write_bindings(UncheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_validation {{").unwrap();
+    wgl_registry
+        .write_bindings(ValidationGenerator, &mut file)
+        .unwrap();
+    wgl_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_slice_struct {{").unwrap();
+    wgl_registry
+        .write_bindings(SliceStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_handle_struct {{").unwrap();
+    wgl_registry
+        .write_bindings(HandleStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_object_tracking {{").unwrap();
+    wgl_registry
+        .write_bindings(ObjectTrackingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod wgl_glx_protocol {{").unwrap();
+    wgl_registry
+        .write_bindings(GlxProtocolGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     // Gles1
 
     let gles1_registry = Registry::new(Api::Gles1, (1, 1), Profile::Core, Fallbacks::All, []);
@@ -136,6 +415,12 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod gles1_lazy {{").unwrap();
+    gles1_registry
+        .write_bindings(LazyGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     writeln!(&mut file, "mod gles1_static {{").unwrap();
     gles1_registry
         .write_bindings(StaticGenerator, &mut file)
@@ -160,6 +445,93 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod gles1_thread_local {{").unwrap();
+    gles1_registry
+        .write_bindings(ThreadLocalGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_trait {{").unwrap();
+    gles1_registry
+        .write_bindings(TraitGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_null {{").unwrap();
+    gles1_registry
+        .write_bindings(NullGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_trace {{").unwrap();
+    gles1_registry
+        .write_bindings(TraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_tracing {{").unwrap();
+    gles1_registry
+        .write_bindings(TracingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_apitrace {{").unwrap();
+    gles1_registry
+        .write_bindings(ApitraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_enum_names {{").unwrap();
+    gles1_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_checked_struct {{").unwrap();
+    gles1_registry
+        .write_bindings(CheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_unchecked_struct {{").unwrap();
+    gles1_registry
+        .write_bindings(UncheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_validation {{").unwrap();
+    gles1_registry
+        .write_bindings(ValidationGenerator, &mut file)
+        .unwrap();
+    gles1_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_slice_struct {{").unwrap();
+    gles1_registry
+        .write_bindings(SliceStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_handle_struct {{").unwrap();
+    gles1_registry
+        .write_bindings(HandleStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_object_tracking {{").unwrap();
+    gles1_registry
+        .write_bindings(ObjectTrackingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles1_glx_protocol {{").unwrap();
+    gles1_registry
+        .write_bindings(GlxProtocolGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     // Gles2
 
     let gles2_registry = Registry::new(Api::Gles2, (3, 1), Profile::Core, Fallbacks::All, []);
@@ -170,6 +542,12 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod gles2_lazy {{").unwrap();
+    gles2_registry
+        .write_bindings(LazyGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     writeln!(&mut file, "mod gles2_static {{").unwrap();
     gles2_registry
         .write_bindings(StaticGenerator, &mut file)
@@ -194,6 +572,93 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod gles2_thread_local {{").unwrap();
+    gles2_registry
+        .write_bindings(ThreadLocalGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_trait {{").unwrap();
+    gles2_registry
+        .write_bindings(TraitGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_null {{").unwrap();
+    gles2_registry
+        .write_bindings(NullGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_trace {{").unwrap();
+    gles2_registry
+        .write_bindings(TraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_tracing {{").unwrap();
+    gles2_registry
+        .write_bindings(TracingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_apitrace {{").unwrap();
+    gles2_registry
+        .write_bindings(ApitraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_enum_names {{").unwrap();
+    gles2_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_checked_struct {{").unwrap();
+    gles2_registry
+        .write_bindings(CheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_unchecked_struct {{").unwrap();
+    gles2_registry
+        .write_bindings(UncheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_validation {{").unwrap();
+    gles2_registry
+        .write_bindings(ValidationGenerator, &mut file)
+        .unwrap();
+    gles2_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_slice_struct {{").unwrap();
+    gles2_registry
+        .write_bindings(SliceStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_handle_struct {{").unwrap();
+    gles2_registry
+        .write_bindings(HandleStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_object_tracking {{").unwrap();
+    gles2_registry
+        .write_bindings(ObjectTrackingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod gles2_glx_protocol {{").unwrap();
+    gles2_registry
+        .write_bindings(GlxProtocolGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     // Egl
 
     let egl_registry = Registry::new(Api::Egl, (1, 5), Profile::Core, Fallbacks::All, []);
@@ -204,6 +669,12 @@ fn main() {
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
 
+    writeln!(&mut file, "mod egl_lazy {{ {}", build_egl_symbols()).unwrap();
+    egl_registry
+        .write_bindings(LazyGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
     writeln!(&mut file, "mod egl_static {{ {}", build_egl_symbols()).unwrap();
     egl_registry
         .write_bindings(StaticGenerator, &mut file)
@@ -232,6 +703,96 @@ fn main() {
         .write_bindings(DebugStructGenerator, &mut file)
         .unwrap();
     writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod egl_thread_local {{ {}", build_egl_symbols()).unwrap();
+    egl_registry
+        .write_bindings(ThreadLocalGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod egl_trait {{ {}", build_egl_symbols()).unwrap();
+    egl_registry
+        .write_bindings(TraitGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod egl_null {{ {}", build_egl_symbols()).unwrap();
+    egl_registry
+        .write_bindings(NullGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod egl_trace {{ {}", build_egl_symbols()).unwrap();
+    egl_registry
+        .write_bindings(TraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod egl_tracing {{ {}", build_egl_symbols()).unwrap();
+    egl_registry
+        .write_bindings(TracingGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod egl_apitrace {{ {}", build_egl_symbols()).unwrap();
+    egl_registry
+        .write_bindings(ApitraceGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod egl_enum_names {{").unwrap();
+    egl_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(
+        &mut file,
+        "mod egl_checked_struct {{ {}",
+        build_egl_symbols()
+    )
+    .unwrap();
+    egl_registry
+        .write_bindings(CheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(
+        &mut file,
+        "mod egl_unchecked_struct {{ {}",
+        build_egl_symbols()
+    )
+    .unwrap();
+    egl_registry
+        .write_bindings(UncheckedStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod egl_validation {{ {}", build_egl_symbols()).unwrap();
+    egl_registry
+        .write_bindings(ValidationGenerator, &mut file)
+        .unwrap();
+    egl_registry
+        .write_bindings(EnumNamesGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(&mut file, "mod egl_slice_struct {{ {}", build_egl_symbols()).unwrap();
+    egl_registry
+        .write_bindings(SliceStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
+
+    writeln!(
+        &mut file,
+        "mod egl_handle_struct {{ {}",
+        build_egl_symbols()
+    )
+    .unwrap();
+    egl_registry
+        .write_bindings(HandleStructGenerator, &mut file)
+        .unwrap();
+    writeln!(&mut file, "}}").unwrap();
 }
 
 fn build_egl_symbols() -> &'static str {
@@ -22,10 +22,11 @@
     unused_imports,
     clippy::let_and_return,
     clippy::let_unit_value,
-    clippy::missing_transmute_annotations,
     clippy::too_many_arguments,
     clippy::unused_unit,
     clippy::upper_case_acronyms,
 )]
 
+extern crate tracing;
+
 include!(concat!(env!("OUT_DIR"), "/test_no_warnings.rs"));
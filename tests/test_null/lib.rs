@@ -0,0 +1,87 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_null.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{gl, null_gl};
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_every_command_is_provided() {
+        let (_, report) = gl::Gl::load_with_report(null_gl::load);
+        assert_eq!(report.missing_count(), 0);
+        assert!(null_gl::load("glNotACommand").is_null());
+    }
+
+    #[test]
+    fn test_default_results() {
+        let gl = gl::Gl::load_with(null_gl::load);
+        unsafe {
+            gl.Clear(gl::COLOR_BUFFER_BIT);
+            assert_eq!(gl.GetError(), gl::NO_ERROR);
+            assert_eq!(
+                gl.CheckFramebufferStatus(gl::FRAMEBUFFER),
+                gl::FRAMEBUFFER_COMPLETE
+            );
+            let version = gl.GetString(gl::VERSION);
+            assert!(!version.is_null());
+            assert_eq!(CStr::from_ptr(version as *const _).to_bytes(), b"");
+        }
+    }
+
+    #[test]
+    fn test_object_names_are_unique() {
+        let gl = gl::Gl::load_with(null_gl::load);
+        let mut buffers = [0; 3];
+        let mut textures = [0; 2];
+        let program;
+        unsafe {
+            gl.GenBuffers(3, buffers.as_mut_ptr());
+            gl.CreateTextures(gl::TEXTURE_2D, 2, textures.as_mut_ptr());
+            program = gl.CreateProgram();
+        }
+
+        let mut names = buffers.to_vec();
+        names.extend_from_slice(&textures);
+        names.push(program);
+        assert!(names.iter().all(|&name| name != 0));
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), 6);
+    }
+
+    #[test]
+    fn test_override() {
+        let gl = gl::Gl::load_with(null_gl::load);
+        null_gl::IsEnabled::set(|cap| (cap == gl::BLEND) as gl::types::GLboolean);
+        unsafe {
+            assert_eq!(gl.IsEnabled(gl::BLEND), gl::TRUE);
+            assert_eq!(gl.IsEnabled(gl::DEPTH_TEST), gl::FALSE);
+        }
+
+        null_gl::IsEnabled::reset();
+        unsafe {
+            assert_eq!(gl.IsEnabled(gl::BLEND), gl::FALSE);
+        }
+    }
+}
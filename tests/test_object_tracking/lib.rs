@@ -0,0 +1,138 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_object_tracking.rs"));
+#[cfg(test)]
+mod tests {
+    use super::{gl, null_gl};
+    use std::panic::Location;
+    use std::sync::{Arc, Mutex};
+
+    fn load_recording() -> (gl::Gl, Arc<Mutex<Vec<gl::ObjectError>>>) {
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let mut gl = gl::Gl::load_with(null_gl::load);
+        let recorded = errors.clone();
+        gl.set_object_error_handler(move |error: &gl::ObjectError| {
+            recorded.lock().unwrap().push(*error)
+        });
+        (gl, errors)
+    }
+
+    fn location_of(error: &gl::ObjectError) -> &'static Location<'static> {
+        match *error {
+            gl::ObjectError::DoubleDelete { location, .. }
+            | gl::ObjectError::UseAfterDelete { location, .. } => location,
+        }
+    }
+
+    #[test]
+    fn test_live_objects() {
+        let (gl, errors) = load_recording();
+        let mut buffers = [0; 2];
+        let line = line!() + 2;
+        let program = unsafe {
+            gl.GenBuffers(2, buffers.as_mut_ptr());
+            gl.CreateProgram()
+        };
+
+        let live = gl.live_objects();
+        assert_eq!(live.len(), 3);
+        let buffer = live.iter().find(|o| o.name == buffers[0]).unwrap();
+        assert_eq!(buffer.class, "buffer");
+        assert_eq!(buffer.command, "glGenBuffers");
+        assert_eq!(buffer.location.file(), file!());
+        assert_eq!(buffer.location.line(), line);
+        assert_eq!(
+            buffer.to_string(),
+            format!(
+                "buffer {} created by glGenBuffers at {}",
+                buffers[0], buffer.location
+            )
+        );
+        let program = live.iter().find(|o| o.name == program).unwrap();
+        assert_eq!(program.class, "program");
+        assert_eq!(program.command, "glCreateProgram");
+
+        unsafe {
+            gl.DeleteBuffers(2, buffers.as_ptr());
+            gl.DeleteProgram(program.name);
+        }
+        assert!(gl.live_objects().is_empty());
+        assert!(errors.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_double_delete() {
+        let (gl, errors) = load_recording();
+        let mut texture = 0;
+        unsafe {
+            gl.GenTextures(1, &mut texture);
+            gl.DeleteTextures(1, &texture);
+            gl.DeleteTextures(1, &texture);
+        }
+
+        let errors = errors.lock().unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0],
+            gl::ObjectError::DoubleDelete {
+                class: "texture",
+                name: texture,
+                command: "glDeleteTextures",
+                location: location_of(&errors[0]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_use_after_delete() {
+        let (gl, errors) = load_recording();
+        let mut buffer = 0;
+        let line = line!() + 6;
+        unsafe {
+            gl.GenBuffers(1, &mut buffer);
+            gl.BindBuffer(gl::ARRAY_BUFFER, buffer);
+            gl.DeleteBuffers(1, &buffer);
+            gl.BindBuffer(gl::ARRAY_BUFFER, 0);
+            gl.BindBuffer(gl::ARRAY_BUFFER, buffer);
+        }
+
+        let errors = errors.lock().unwrap();
+        assert_eq!(errors.len(), 1);
+        let location = location_of(&errors[0]);
+        assert_eq!(location.line(), line);
+        assert_eq!(
+            errors[0].to_string(),
+            format!("glBindBuffer at {location}: buffer {buffer} was deleted")
+        );
+    }
+
+    #[test]
+    fn test_deleting_zero() {
+        let (gl, errors) = load_recording();
+        unsafe {
+            gl.DeleteBuffers(1, &0);
+            gl.DeleteBuffers(1, &0);
+            gl.DeleteShader(0);
+        }
+        assert!(errors.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_slice_struct.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{gl, null_gl};
+    use std::ffi::CStr;
+    use std::slice;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_slices() {
+        let gl = gl::Gl::load_with(null_gl::load);
+
+        let mut buffers = [0; 3];
+        gl.gen_buffers(&mut buffers);
+        assert!(buffers.iter().all(|&buffer| buffer > 0));
+        assert!(buffers[0] != buffers[1] && buffers[1] != buffers[2]);
+
+        unsafe { gl.BindBuffer(gl::ARRAY_BUFFER, buffers[0]) };
+        gl.buffer_data(gl::ARRAY_BUFFER, &[1, 2, 3, 4], gl::STATIC_DRAW);
+        gl.delete_buffers(&buffers);
+    }
+
+    #[test]
+    fn test_strs() {
+        let gl = gl::Gl::load_with(null_gl::load);
+        let sources = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = sources.clone();
+        null_gl::ShaderSource::set(move |_, count, string, length| unsafe {
+            let strings = slice::from_raw_parts(string, count as usize);
+            let lengths = slice::from_raw_parts(length, count as usize);
+            for (&s, &len) in strings.iter().zip(lengths) {
+                let bytes = slice::from_raw_parts(s as *const u8, len as usize);
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8(bytes.to_vec()).unwrap());
+            }
+        });
+        let shader = unsafe { gl.CreateShader(gl::VERTEX_SHADER) };
+        gl.shader_source(shader, &["#version 330\n", "void main() {}"]);
+        null_gl::ShaderSource::reset();
+
+        assert_eq!(
+            *sources.lock().unwrap(),
+            ["#version 330\n", "void main() {}"]
+        );
+    }
+
+    #[test]
+    fn test_strs_without_lengths() {
+        let gl = gl::Gl::load_with(null_gl::load);
+        let varyings = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = varyings.clone();
+        null_gl::TransformFeedbackVaryings::set(move |_, count, names, _| unsafe {
+            for &name in slice::from_raw_parts(names, count as usize) {
+                let name = CStr::from_ptr(name).to_str().unwrap();
+                recorded.lock().unwrap().push(name.to_string());
+            }
+        });
+        gl.transform_feedback_varyings(1, &["position", "normal"], gl::INTERLEAVED_ATTRIBS);
+        null_gl::TransformFeedbackVaryings::reset();
+
+        assert_eq!(*varyings.lock().unwrap(), ["position", "normal"]);
+    }
+
+    #[test]
+    fn test_references() {
+        let gl = gl::Gl::load_with(null_gl::load);
+
+        null_gl::GetShaderInfoLog::set(|_, buf_size, length, info_log| unsafe {
+            assert_eq!(buf_size, 16);
+            *length = 2;
+            *info_log = b'o' as _;
+            *info_log.add(1) = b'k' as _;
+        });
+        let mut length = 0;
+        let mut log = [0; 16];
+        let shader = unsafe { gl.CreateShader(gl::VERTEX_SHADER) };
+        gl.get_shader_info_log(shader, &mut length, &mut log);
+        null_gl::GetShaderInfoLog::reset();
+
+        assert_eq!(length, 2);
+        assert_eq!(log[..2], [b'o' as _, b'k' as _]);
+    }
+
+    #[test]
+    fn test_arrays() {
+        let gl = gl::Gl::load_with(null_gl::load);
+        let values = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = values.clone();
+        null_gl::Uniform4fv::set(move |location, count, value| unsafe {
+            assert_eq!(location, 3);
+            let value = slice::from_raw_parts(value, count as usize * 4);
+            recorded.lock().unwrap().extend_from_slice(value);
+        });
+        let recorded = values.clone();
+        null_gl::VertexAttrib1fv::set(move |index, v| unsafe {
+            assert_eq!(index, 1);
+            recorded.lock().unwrap().push(*v);
+        });
+        gl.uniform4fv(3, &[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]);
+        gl.vertex_attrib1fv(1, &[9.0]);
+        null_gl::Uniform4fv::reset();
+        null_gl::VertexAttrib1fv::reset();
+
+        assert_eq!(
+            *values.lock().unwrap(),
+            [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`offsets` and `buffers` must have the same length")]
+    fn test_different_lengths() {
+        let gl = gl::Gl::load_with(null_gl::load);
+
+        gl.bind_buffers_range(gl::UNIFORM_BUFFER, 0, &[1, 2], &[0], &[16, 16]);
+    }
+
+    #[test]
+    fn test_pointer_values_stay_unsafe() {
+        let gl = gl::Gl::load_with(null_gl::load);
+
+        let mut length = 0;
+        let mut values = [0; 1];
+        unsafe {
+            let sync = gl.FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0);
+            // the sync object is a pointer, so the method stays unsafe
+            gl.get_synciv(sync, gl::SYNC_STATUS, &mut length, &mut values);
+        }
+        assert_eq!(length, 0);
+    }
+}
@@ -26,4 +26,9 @@ fn main() {
     Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, [])
         .write_bindings(GlobalGenerator, &mut file)
         .unwrap();
+
+    let mut file = File::create(Path::new(&dest).join("test_symbols_debug.rs")).unwrap();
+    Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, [])
+        .write_bindings(DebugStructGenerator, &mut file)
+        .unwrap();
 }
@@ -17,7 +17,6 @@ use std::os::raw;
 pub mod gl {
     #![allow(
         clippy::missing_safety_doc,
-        clippy::missing_transmute_annotations,
         clippy::too_many_arguments,
         clippy::unused_unit
     )]
@@ -25,6 +24,19 @@ pub mod gl {
     include!(concat!(env!("OUT_DIR"), "/test_symbols.rs"));
 }
 
+pub mod gl_debug {
+    #![allow(
+        clippy::let_and_return,
+        clippy::let_unit_value,
+        clippy::manual_non_exhaustive,
+        clippy::missing_safety_doc,
+        clippy::too_many_arguments,
+        clippy::unused_unit
+    )]
+
+    include!(concat!(env!("OUT_DIR"), "/test_symbols_debug.rs"));
+}
+
 pub fn compile_test_symbols_exist() {
     unsafe {
         gl::Clear(gl::COLOR_BUFFER_BIT);
@@ -52,3 +64,38 @@ fn test_fallback_works() {
     gl::GenFramebuffers::load_with(loader);
     assert!(gl::GenFramebuffers::is_loaded());
 }
+
+#[test]
+#[should_panic(expected = "gl function `glViewport` was not loaded")]
+fn test_missing_fn_panics() {
+    unsafe { gl::Viewport(0, 0, 0, 0) };
+}
+
+#[test]
+#[should_panic(expected = "gl function `glBeginTransformFeedback` was not loaded \
+                           (also tried `glBeginTransformFeedbackEXT`, `glBeginTransformFeedbackNV`)")]
+fn test_missing_fn_panic_names_fallbacks() {
+    unsafe { gl::BeginTransformFeedback(gl::POINTS) };
+}
+
+#[test]
+fn test_missing_fn_panic_points_at_caller() {
+    use std::cell::RefCell;
+
+    thread_local! {
+        static LOCATION: RefCell<Option<(String, u32)>> = const { RefCell::new(None) };
+    }
+
+    std::panic::set_hook(Box::new(|info| {
+        let location = info.location().unwrap();
+        LOCATION.with(|l| *l.borrow_mut() = Some((location.file().to_string(), location.line())));
+    }));
+    let gl = gl_debug::Gl::load_with(|_| std::ptr::null());
+    let viewport = || unsafe { gl.Viewport(0, 0, 0, 0) };
+    let line = line!() - 1;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(viewport));
+    let _ = std::panic::take_hook();
+
+    assert!(result.is_err());
+    LOCATION.with(|l| assert_eq!(*l.borrow(), Some((file!().to_string(), line))));
+}
@@ -0,0 +1,93 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod gl {
+    #![allow(
+        clippy::missing_safety_doc,
+        clippy::too_many_arguments,
+        clippy::unused_unit,
+        clippy::manual_non_exhaustive
+    )]
+
+    include!(concat!(env!("OUT_DIR"), "/test_thread_local.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gl;
+    use std::os::raw;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    static CLEAR_A: AtomicUsize = AtomicUsize::new(0);
+    static CLEAR_B: AtomicUsize = AtomicUsize::new(0);
+
+    extern "system" fn clear_a(_mask: gl::types::GLbitfield) {
+        CLEAR_A.fetch_add(1, Ordering::SeqCst);
+    }
+
+    extern "system" fn clear_b(_mask: gl::types::GLbitfield) {
+        CLEAR_B.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn load_table(clear: extern "system" fn(gl::types::GLbitfield)) -> Arc<gl::Gl> {
+        Arc::new(gl::Gl::load_with(|name| match name {
+            "glClear" => clear as *const raw::c_void,
+            _ => std::ptr::null(),
+        }))
+    }
+
+    #[test]
+    fn test_each_thread_uses_its_current_table() {
+        let a = load_table(clear_a);
+        let b = load_table(clear_b);
+
+        let thread_a = thread::spawn(move || {
+            gl::make_current(&a);
+            unsafe { gl::Clear(gl::COLOR_BUFFER_BIT) };
+        });
+        let thread_b = thread::spawn(move || {
+            gl::make_current(&b);
+            unsafe {
+                gl::Clear(gl::COLOR_BUFFER_BIT);
+                gl::Clear(gl::COLOR_BUFFER_BIT);
+            }
+        });
+        thread_a.join().unwrap();
+        thread_b.join().unwrap();
+
+        assert_eq!(CLEAR_A.load(Ordering::SeqCst), 1);
+        assert_eq!(CLEAR_B.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_make_not_current() {
+        let a = load_table(clear_a);
+        assert!(gl::current().is_none());
+        gl::make_current(&a);
+        assert!(Arc::ptr_eq(&gl::current().unwrap(), &a));
+        assert!(gl::current().unwrap().Clear.is_loaded());
+        assert!(gl::make_not_current().is_some());
+        assert!(gl::current().is_none());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "gl function `glClear` was called without a current table on this thread"
+    )]
+    fn test_no_current_table_panics() {
+        unsafe { gl::Clear(gl::COLOR_BUFFER_BIT) };
+    }
+}
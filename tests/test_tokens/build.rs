@@ -0,0 +1,47 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate gl_generator;
+extern crate proc_macro2;
+
+use gl_generator::*;
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use std::env;
+use std::fs::File;
+use std::io;
+use std::path::*;
+
+/// Wraps the tokens of `StructGenerator` in a `gl` module.
+struct ModuleGenerator;
+
+impl TokenGenerator for ModuleGenerator {
+    fn generate(&self, registry: &Registry) -> io::Result<TokenStream> {
+        let bindings = StructGenerator.generate(registry)?;
+        let mut module = "pub mod gl".parse::<TokenStream>().unwrap();
+        module.extend(Some(TokenTree::Group(Group::new(
+            Delimiter::Brace,
+            bindings,
+        ))));
+        Ok(module)
+    }
+}
+
+fn main() {
+    let dest = env::var("OUT_DIR").unwrap();
+    let mut file = File::create(Path::new(&dest).join("test_tokens.rs")).unwrap();
+
+    Registry::new(Api::Gl, (4, 6), Profile::Core, Fallbacks::All, [])
+        .write_bindings(Pretty(ModuleGenerator), &mut file)
+        .unwrap();
+}
@@ -0,0 +1,38 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_tokens.rs"));
+
+#[test]
+fn test_pretty() {
+    let code = include_str!(concat!(env!("OUT_DIR"), "/test_tokens.rs"));
+    assert!(code.starts_with("pub mod gl {\n"));
+    assert!(code.contains("\n    impl Gl {\n"));
+    assert!(
+        code.contains("\n        pub unsafe fn Clear(&self, mask: types::GLbitfield) -> () {\n")
+    );
+}
+
+#[test]
+fn test_bindings() {
+    let gl = gl::Gl::load_with(|_| std::ptr::null());
+    assert!(!gl.Clear.is_loaded());
+}
@@ -0,0 +1,375 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_trace.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{egl, gl, null_gl};
+    use std::cell::RefCell;
+    use std::ffi::CStr;
+    use std::io;
+    use std::os::raw::c_void;
+    use std::ptr;
+
+    thread_local! {
+        static CALLS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn log(call: String) {
+        CALLS.with(|calls| calls.borrow_mut().push(call));
+    }
+
+    extern "system-unwind" fn clear(mask: gl::types::GLbitfield) {
+        log(format!("Clear({mask:#x})"));
+    }
+
+    extern "system-unwind" fn buffer_data(
+        target: gl::types::GLenum,
+        size: gl::types::GLsizeiptr,
+        data: *const c_void,
+        usage: gl::types::GLenum,
+    ) {
+        let data = unsafe { std::slice::from_raw_parts(data as *const u8, size as usize) };
+        log(format!("BufferData({target:#x}, {data:?}, {usage:#x})"));
+    }
+
+    extern "system-unwind" fn bind_attrib_location(
+        program: gl::types::GLuint,
+        index: gl::types::GLuint,
+        name: *const gl::types::GLchar,
+    ) {
+        let name = unsafe { std::ffi::CStr::from_ptr(name) }.to_str().unwrap();
+        log(format!("BindAttribLocation({program}, {index}, {name:?})"));
+    }
+
+    extern "system-unwind" fn get_buffer_sub_data(
+        _target: gl::types::GLenum,
+        offset: gl::types::GLintptr,
+        size: gl::types::GLsizeiptr,
+        data: *mut c_void,
+    ) {
+        log(format!(
+            "GetBufferSubData({offset}, {size}, {})",
+            !data.is_null()
+        ));
+    }
+
+    extern "system-unwind" fn gen_buffers(n: gl::types::GLsizei, buffers: *mut gl::types::GLuint) {
+        for i in 0..n {
+            unsafe { *buffers.offset(i as isize) = i as u32 + 1 };
+        }
+    }
+
+    extern "system-unwind" fn gen_other_buffers(
+        n: gl::types::GLsizei,
+        buffers: *mut gl::types::GLuint,
+    ) {
+        for i in 0..n {
+            unsafe { *buffers.offset(i as isize) = i as u32 + 100 };
+        }
+    }
+
+    extern "system-unwind" fn shader_source(
+        shader: gl::types::GLuint,
+        count: gl::types::GLsizei,
+        strings: *const *const gl::types::GLchar,
+        lengths: *const gl::types::GLint,
+    ) {
+        let sources = (0..count as usize)
+            .map(|i| unsafe {
+                let string = *strings.add(i);
+                match lengths.is_null() {
+                    false if *lengths.add(i) >= 0 => {
+                        let len = *lengths.add(i) as usize;
+                        let bytes = std::slice::from_raw_parts(string as *const u8, len);
+                        String::from_utf8_lossy(bytes).into_owned()
+                    },
+                    _ => CStr::from_ptr(string).to_string_lossy().into_owned(),
+                }
+            })
+            .collect::<Vec<_>>();
+        log(format!("ShaderSource({shader}, {sources:?})"));
+    }
+
+    extern "system-unwind" fn fence_sync(
+        _condition: gl::types::GLenum,
+        _flags: gl::types::GLbitfield,
+    ) -> gl::types::GLsync {
+        0x10 as gl::types::GLsync
+    }
+
+    extern "system-unwind" fn other_fence_sync(
+        _condition: gl::types::GLenum,
+        _flags: gl::types::GLbitfield,
+    ) -> gl::types::GLsync {
+        0x20 as gl::types::GLsync
+    }
+
+    extern "system-unwind" fn delete_sync(sync: gl::types::GLsync) {
+        log(format!("DeleteSync({:#x})", sync as usize));
+    }
+
+    extern "system-unwind" fn get_integerv(_pname: gl::types::GLenum, data: *mut gl::types::GLint) {
+        unsafe { *data = 1 };
+    }
+
+    extern "system-unwind" fn vertex_attrib_pointer(
+        index: gl::types::GLuint,
+        _size: gl::types::GLint,
+        _type: gl::types::GLenum,
+        _normalized: gl::types::GLboolean,
+        _stride: gl::types::GLsizei,
+        pointer: *const c_void,
+    ) {
+        log(format!(
+            "VertexAttribPointer({index}, {})",
+            pointer as usize
+        ));
+    }
+
+    extern "system-unwind" fn draw_elements(
+        _mode: gl::types::GLenum,
+        count: gl::types::GLsizei,
+        _type: gl::types::GLenum,
+        indices: *const c_void,
+    ) {
+        log(format!("DrawElements({count}, {})", indices as usize));
+    }
+
+    fn logging_gl() -> gl::Gl {
+        gl::Gl::load_with(|symbol| match symbol {
+            "glClear" => clear as *const c_void,
+            "glBufferData" => buffer_data as *const c_void,
+            "glBindAttribLocation" => bind_attrib_location as *const c_void,
+            "glGetBufferSubData" => get_buffer_sub_data as *const c_void,
+            "glGenBuffers" => gen_buffers as *const c_void,
+            "glShaderSource" => shader_source as *const c_void,
+            "glFenceSync" => fence_sync as *const c_void,
+            "glDeleteSync" => delete_sync as *const c_void,
+            _ => null_gl::load(symbol),
+        })
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let recorder = gl::Recorder::new(logging_gl(), Vec::new());
+        let mut buffers = [0; 2];
+        let mut readback = [0u8; 3];
+        unsafe {
+            recorder.Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+            recorder.GenBuffers(2, buffers.as_mut_ptr());
+            recorder.BufferData(
+                gl::ARRAY_BUFFER,
+                3,
+                [1u8, 2, 3].as_ptr() as *const _,
+                gl::STATIC_DRAW,
+            );
+            recorder.GetBufferSubData(gl::ARRAY_BUFFER, 1, 2, readback.as_mut_ptr() as *mut _);
+            recorder.BindAttribLocation(1, 0, b"position\0".as_ptr() as *const _);
+            recorder.Clear(gl::COLOR_BUFFER_BIT);
+        }
+        assert_ne!(buffers, [0, 0]);
+        let trace = recorder.finish().unwrap();
+        assert!(trace.starts_with(gl::TRACE_MAGIC));
+
+        CALLS.with(|calls| calls.borrow_mut().clear());
+        let calls = unsafe { gl::replay(&logging_gl(), &trace[..]) }.unwrap();
+        assert_eq!(calls, 6);
+        CALLS.with(|calls| {
+            assert_eq!(
+                *calls.borrow(),
+                [
+                    "Clear(0x4100)",
+                    "BufferData(0x8892, [1, 2, 3], 0x88e4)",
+                    "GetBufferSubData(1, 2, true)",
+                    "BindAttribLocation(1, 0, \"position\")",
+                    "Clear(0x4000)",
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn test_null_pointers_are_replayed_as_null() {
+        let recorder = gl::Recorder::new(gl::Gl::load_with(null_gl::load), Vec::new());
+        unsafe {
+            recorder.GetBufferSubData(gl::ARRAY_BUFFER, 0, 4, ptr::null_mut());
+        }
+        let trace = recorder.finish().unwrap();
+
+        CALLS.with(|calls| calls.borrow_mut().clear());
+        unsafe { gl::replay(&logging_gl(), &trace[..]) }.unwrap();
+        CALLS.with(|calls| assert_eq!(*calls.borrow(), ["GetBufferSubData(0, 4, false)"]));
+    }
+
+    #[test]
+    fn test_invalid_trace() {
+        let gl = gl::Gl::load_with(null_gl::load);
+        let error = unsafe { gl::replay(&gl, &b"not a trace"[..]) }.unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let mut trace = gl::TRACE_MAGIC.to_vec();
+        trace.push(0);
+        trace.extend_from_slice(&0u32.to_ne_bytes());
+        trace.extend_from_slice(&11u16.to_ne_bytes());
+        trace.extend_from_slice(b"glNotAFnEXT");
+        let error = unsafe { gl::replay(&gl, &trace[..]) }.unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("glNotAFnEXT"));
+    }
+
+    #[test]
+    fn test_shader_sources_are_replayed() {
+        let recorder = gl::Recorder::new(gl::Gl::load_with(null_gl::load), Vec::new());
+        let strings = [b"void main()garbage".as_ptr(), b" {}\0".as_ptr()];
+        unsafe {
+            recorder.ShaderSource(1, 2, strings.as_ptr() as *const _, [11, -1].as_ptr());
+            recorder.ShaderSource(2, 1, strings[1..].as_ptr() as *const _, ptr::null());
+        }
+        let trace = recorder.finish().unwrap();
+
+        CALLS.with(|calls| calls.borrow_mut().clear());
+        unsafe { gl::replay(&logging_gl(), &trace[..]) }.unwrap();
+        CALLS.with(|calls| {
+            assert_eq!(
+                *calls.borrow(),
+                [
+                    r#"ShaderSource(1, ["void main()", " {}"])"#,
+                    r#"ShaderSource(2, [" {}"])"#,
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn test_other_object_names_are_refused() {
+        let recording_gl = gl::Gl::load_with(|symbol| match symbol {
+            "glGenBuffers" => gen_other_buffers as *const c_void,
+            _ => null_gl::load(symbol),
+        });
+        let recorder = gl::Recorder::new(recording_gl, Vec::new());
+        let mut buffers = [0; 2];
+        unsafe { recorder.GenBuffers(2, buffers.as_mut_ptr()) };
+        let trace = recorder.finish().unwrap();
+
+        let error = unsafe { gl::replay(&logging_gl(), &trace[..]) }.unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+        assert!(error.to_string().contains("glGenBuffers"));
+    }
+
+    #[test]
+    fn test_pointers_of_unknown_size_are_refused() {
+        let recorder = gl::Recorder::new(gl::Gl::load_with(null_gl::load), Vec::new());
+        let mut viewport = [0; 4];
+        unsafe { recorder.GetIntegerv(gl::VIEWPORT, viewport.as_mut_ptr()) };
+        let trace = recorder.finish().unwrap();
+
+        let error = unsafe { gl::replay(&logging_gl(), &trace[..]) }.unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+        assert!(error.to_string().contains("glGetIntegerv"));
+    }
+
+    #[test]
+    fn test_buffer_offsets_are_replayed() {
+        let recorder = gl::Recorder::new(gl::Gl::load_with(null_gl::load), Vec::new());
+        unsafe {
+            recorder.VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, 0, 16 as *const c_void);
+            recorder.DrawElements(gl::TRIANGLES, 6, gl::UNSIGNED_SHORT, 4 as *const c_void);
+        }
+        let trace = recorder.finish().unwrap();
+
+        let bound_gl = gl::Gl::load_with(|symbol| match symbol {
+            "glGetIntegerv" => get_integerv as *const c_void,
+            "glVertexAttribPointer" => vertex_attrib_pointer as *const c_void,
+            "glDrawElements" => draw_elements as *const c_void,
+            _ => null_gl::load(symbol),
+        });
+        CALLS.with(|calls| calls.borrow_mut().clear());
+        assert_eq!(unsafe { gl::replay(&bound_gl, &trace[..]) }.unwrap(), 2);
+        CALLS.with(|calls| {
+            assert_eq!(
+                *calls.borrow(),
+                ["VertexAttribPointer(0, 16)", "DrawElements(6, 4)"]
+            )
+        });
+
+        let error = unsafe { gl::replay(&logging_gl(), &trace[..]) }.unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+        assert!(error.to_string().contains("glVertexAttribPointer"));
+    }
+
+    #[test]
+    fn test_sync_objects_are_remapped() {
+        let recording_gl = gl::Gl::load_with(|symbol| match symbol {
+            "glFenceSync" => other_fence_sync as *const c_void,
+            _ => null_gl::load(symbol),
+        });
+        let recorder = gl::Recorder::new(recording_gl, Vec::new());
+        unsafe {
+            let sync = recorder.FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0);
+            recorder.DeleteSync(sync);
+        }
+        let trace = recorder.finish().unwrap();
+
+        CALLS.with(|calls| calls.borrow_mut().clear());
+        unsafe { gl::replay(&logging_gl(), &trace[..]) }.unwrap();
+        CALLS.with(|calls| assert_eq!(*calls.borrow(), ["DeleteSync(0x10)"]));
+    }
+
+    extern "system-unwind" fn set_blob_cache_funcs(
+        _display: egl::types::EGLDisplay,
+        _set: egl::types::EGLSetBlobFuncANDROID,
+        _get: egl::types::EGLGetBlobFuncANDROID,
+    ) {
+    }
+
+    extern "system" fn set_blob(
+        _key: *const c_void,
+        _key_size: egl::types::EGLsizeiANDROID,
+        _value: *const c_void,
+        _value_size: egl::types::EGLsizeiANDROID,
+    ) {
+    }
+
+    extern "system" fn get_blob(
+        _key: *const c_void,
+        _key_size: egl::types::EGLsizeiANDROID,
+        _value: *mut c_void,
+        _value_size: egl::types::EGLsizeiANDROID,
+    ) -> egl::types::EGLsizeiANDROID {
+        0
+    }
+
+    #[test]
+    fn test_function_pointers_are_refused() {
+        let recording_egl = egl::Egl::load_with(|symbol| match symbol {
+            "eglSetBlobCacheFuncsANDROID" => set_blob_cache_funcs as *const c_void,
+            _ => ptr::null(),
+        });
+        let recorder = egl::Recorder::new(recording_egl, Vec::new());
+        unsafe { recorder.SetBlobCacheFuncsANDROID(ptr::null_mut(), set_blob, get_blob) };
+
+        let error = recorder.finish().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+        assert!(error.to_string().contains("eglSetBlobCacheFuncsANDROID"));
+    }
+}
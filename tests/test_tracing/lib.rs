@@ -0,0 +1,133 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+extern crate tracing;
+
+include!(concat!(env!("OUT_DIR"), "/test_tracing.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{gl, null_gl};
+    use std::fmt::{self, Write};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Records the name and fields of every span, and when spans are entered and exited.
+    struct RecordingSubscriber {
+        next_id: AtomicU64,
+        spans: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct FieldsVisitor(String);
+
+    impl Visit for FieldsVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            write!(self.0, " {}={:?}", field.name(), value).unwrap();
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes) -> Id {
+            let mut visitor = FieldsVisitor(span.metadata().name().to_string());
+            span.record(&mut visitor);
+            self.spans.lock().unwrap().push(visitor.0);
+            Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed))
+        }
+
+        fn record(&self, _: &Id, _: &Record) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event) {}
+
+        fn enter(&self, _: &Id) {
+            self.spans.lock().unwrap().push("enter".to_string());
+        }
+
+        fn exit(&self, _: &Id) {
+            self.spans.lock().unwrap().push("exit".to_string());
+        }
+    }
+
+    #[test]
+    fn test_spans() {
+        let spans = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = RecordingSubscriber {
+            next_id: AtomicU64::new(1),
+            spans: spans.clone(),
+        };
+        let gl = gl::Gl::load_with(null_gl::load);
+        tracing::subscriber::with_default(subscriber, || unsafe {
+            gl.DrawArrays(gl::TRIANGLES, 0, 3);
+            gl.BlendFunc(gl::SRC_ALPHA, 0x1234);
+            gl.ClearColor(0.0, 0.5, 1.0, 1.0);
+        });
+
+        assert_eq!(
+            *spans.lock().unwrap(),
+            [
+                "glDrawArrays mode=GL_TRIANGLES first=0 count=3",
+                "enter",
+                "exit",
+                "glBlendFunc sfactor=GL_SRC_ALPHA dfactor=0x1234",
+                "enter",
+                "exit",
+                "glClearColor red=0.0 green=0.5 blue=1.0 alpha=1.0",
+                "enter",
+                "exit",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bitfield_spans() {
+        let spans = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = RecordingSubscriber {
+            next_id: AtomicU64::new(1),
+            spans: spans.clone(),
+        };
+        let gl = gl::Gl::load_with(null_gl::load);
+        tracing::subscriber::with_default(subscriber, || unsafe {
+            gl.Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+            gl.Clear(gl::STENCIL_BUFFER_BIT | 0x1);
+        });
+
+        let spans = spans.lock().unwrap();
+        let names = spans
+            .iter()
+            .filter(|span| span.starts_with("gl"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "glClear mask=GL_COLOR_BUFFER_BIT | GL_DEPTH_BUFFER_BIT",
+                "glClear mask=GL_STENCIL_BUFFER_BIT | 0x1",
+            ]
+        );
+    }
+}
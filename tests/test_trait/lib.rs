@@ -0,0 +1,99 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod gl {
+    #![allow(
+        clippy::missing_safety_doc,
+        clippy::too_many_arguments,
+        clippy::unused_unit,
+        clippy::manual_non_exhaustive
+    )]
+
+    include!(concat!(env!("OUT_DIR"), "/test_trait.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gl;
+    use super::gl::GlApi;
+    use std::cell::RefCell;
+    use std::os::raw;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct RecordingGl {
+        calls: RefCell<Vec<String>>,
+    }
+
+    #[allow(non_snake_case)]
+    impl GlApi for RecordingGl {
+        unsafe fn Clear(&self, mask: gl::types::GLbitfield) {
+            self.calls.borrow_mut().push(format!("Clear({mask:#x})"));
+        }
+
+        unsafe fn GetError(&self) -> gl::types::GLenum {
+            self.calls.borrow_mut().push("GetError()".to_string());
+            gl::NO_ERROR
+        }
+    }
+
+    fn clear_screen<G: GlApi + ?Sized>(gl: &G) -> gl::types::GLenum {
+        unsafe {
+            gl.Clear(gl::COLOR_BUFFER_BIT);
+            gl.GetError()
+        }
+    }
+
+    #[test]
+    fn test_mock_records_calls() {
+        let mock = RecordingGl::default();
+        assert_eq!(clear_screen(&mock), gl::NO_ERROR);
+        assert_eq!(clear_screen(&mock as &dyn GlApi), gl::NO_ERROR);
+        assert_eq!(
+            *mock.calls.borrow(),
+            ["Clear(0x4000)", "GetError()", "Clear(0x4000)", "GetError()"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "gl function `glDebugMessageInsert` was not loaded")]
+    fn test_extension_cmd_has_default() {
+        let mock = RecordingGl::default();
+        unsafe {
+            mock.DebugMessageInsert(0, 0, 0, 0, 0, std::ptr::null());
+        }
+    }
+
+    #[test]
+    fn test_loaded_struct_implements_trait() {
+        static CLEARS: AtomicUsize = AtomicUsize::new(0);
+
+        extern "system" fn clear(_mask: gl::types::GLbitfield) {
+            CLEARS.fetch_add(1, Ordering::SeqCst);
+        }
+
+        extern "system" fn get_error() -> gl::types::GLenum {
+            gl::NO_ERROR
+        }
+
+        let loaded = gl::Gl::load_with(|name| match name {
+            "glClear" => clear as *const raw::c_void,
+            "glGetError" => get_error as *const raw::c_void,
+            _ => std::ptr::null(),
+        });
+        let backend: &dyn GlApi = &loaded;
+        assert_eq!(clear_screen(backend), gl::NO_ERROR);
+        assert_eq!(CLEARS.load(Ordering::SeqCst), 1);
+    }
+}
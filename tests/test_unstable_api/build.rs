@@ -21,5 +21,18 @@ fn main() {
     let _ = generators::gen_enum_item::<Vec<u8>>;
     let _ = generators::gen_types::<Vec<u8>>;
     let _ = generators::gen_parameters;
+    let _ = generators::gen_fn_ptr_type;
+    let _ = generators::gen_missing_fns::<Vec<u8>>;
+    let _ = generators::gen_load_report::<Vec<u8>>;
     let _ = generators::gen_symbol_name;
+    let _ = generators::gen_enum_groups::<Vec<u8>>;
+    let _ = generators::gen_enum_group_names;
+    let _ = generators::gen_enum_arg;
+    let _ = generators::GenContext::new;
+    let _ = generators::sections::write_header::<Vec<u8>>;
+    let _ = generators::sections::write_type_aliases::<Vec<u8>>;
+    let _ = generators::sections::write_enums::<Vec<u8>>;
+    let _ = generators::sections::write_fn_ptr_struct::<Vec<u8>>;
+    let _ = generators::sections::write_struct::<Vec<u8>>;
+    let _ = generators::sections::write_load_fns::<Vec<u8>>;
 }
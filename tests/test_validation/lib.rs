@@ -0,0 +1,81 @@
+// Copyright 2015 Brendan Zabarauskas and the gl-rs developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::unused_unit,
+    clippy::manual_non_exhaustive
+)]
+
+include!(concat!(env!("OUT_DIR"), "/test_validation.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{gl, null_gl};
+    use std::sync::{Arc, Mutex};
+
+    fn load_recording() -> (gl::Gl, Arc<Mutex<Vec<gl::InvalidEnum>>>) {
+        let invalid = Arc::new(Mutex::new(Vec::new()));
+        let mut gl = gl::Gl::load_with(null_gl::load);
+        let recorded = invalid.clone();
+        gl.set_invalid_enum_handler(move |arg: &gl::InvalidEnum| {
+            recorded.lock().unwrap().push(*arg)
+        });
+        (gl, invalid)
+    }
+
+    #[test]
+    fn test_valid() {
+        let (gl, invalid) = load_recording();
+
+        unsafe {
+            gl.BindBuffer(gl::ARRAY_BUFFER, 1);
+            gl.TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as _);
+            gl.Viewport(0, 0, 640, 480);
+        }
+
+        assert!(invalid.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let (gl, invalid) = load_recording();
+
+        unsafe {
+            gl.TexParameteri(gl::TEXTURE_2D, gl::FLOAT, 0);
+            gl.BindBuffer(0x1234, 1);
+        }
+
+        let invalid = invalid.lock().unwrap();
+        assert_eq!(
+            invalid[0],
+            gl::InvalidEnum {
+                command: "glTexParameteri",
+                param: "pname",
+                group: "TextureParameterName",
+                value: gl::FLOAT,
+            }
+        );
+        assert_eq!(
+            invalid[0].to_string(),
+            "glTexParameteri: 0x1406 (FLOAT) is not a valid TextureParameterName"
+        );
+        assert_eq!(
+            invalid[1].to_string(),
+            "glBindBuffer: 0x1234 is not a valid BufferTargetARB"
+        );
+        assert_eq!(invalid.len(), 2);
+    }
+}
@@ -15,7 +15,6 @@
 pub mod gl {
     #![allow(
         clippy::missing_safety_doc,
-        clippy::missing_transmute_annotations,
         clippy::too_many_arguments,
         clippy::unused_unit
     )]